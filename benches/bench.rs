@@ -80,3 +80,81 @@ fn bench_get_vec(b: &mut Bencher) {
         assert_eq!(sum, 250500);
     });
 }
+
+// Pseudo-random indices into a deque of length 1001, so which stack holds an element is not
+// predictable.
+fn random_indices() -> Vec<usize> {
+    let mut x = 0x2545_F491_u32;
+    (0..1001).map(|_| {
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        x as usize % 1001
+    }).collect()
+}
+
+#[bench]
+fn bench_get_random_altdeque(b: &mut Bencher) {
+    let mut deque = (500..1001).collect::<AltDeque<_>>();
+    for i in (0..500).rev() {
+        deque.push_front(i);
+    }
+    let indices = random_indices();
+    let expected = indices.iter().sum::<usize>();
+    b.iter(|| {
+        let mut sum = 0;
+        for &i in &indices {
+            sum += deque[i];
+        }
+        assert_eq!(sum, expected);
+    });
+}
+
+#[bench]
+fn bench_get_random_vecdeque(b: &mut Bencher) {
+    let mut deque = (500..1001).collect::<VecDeque<_>>();
+    for i in (0..500).rev() {
+        deque.push_front(i);
+    }
+    let indices = random_indices();
+    let expected = indices.iter().sum::<usize>();
+    b.iter(|| {
+        let mut sum = 0;
+        for &i in &indices {
+            sum += deque[i];
+        }
+        assert_eq!(sum, expected);
+    });
+}
+
+// Pops alternate between both ends, so the stacks keep running dry on alternating sides.
+fn ping_pong<D>(deque: &mut D, push_back: fn(&mut D, i32), pop_front: fn(&mut D) -> Option<i32>, pop_back: fn(&mut D) -> Option<i32>) -> i32 {
+    let mut sum = 0;
+    for i in 0..1000 {
+        push_back(deque, i);
+        push_back(deque, i);
+        sum += if i % 2 == 0 { pop_front(deque) } else { pop_back(deque) }.unwrap();
+    }
+    while let (Some(a), Some(b)) = (pop_front(deque), pop_back(deque)) {
+        sum += a + b;
+    }
+    sum
+}
+
+#[bench]
+fn bench_ping_pong_altdeque(b: &mut Bencher) {
+    b.iter(|| {
+        let mut deque = AltDeque::new();
+        let sum = ping_pong(&mut deque, AltDeque::push_back, AltDeque::pop_front, AltDeque::pop_back);
+        assert_eq!(sum, 999000);
+    });
+}
+
+#[bench]
+fn bench_ping_pong_vecdeque(b: &mut Bencher) {
+    b.iter(|| {
+        let mut deque = VecDeque::new();
+        let sum = ping_pong(&mut deque, VecDeque::push_back, VecDeque::pop_front, VecDeque::pop_back);
+        assert_eq!(sum, 999000);
+    });
+}
@@ -0,0 +1,177 @@
+//! Benchmarks of `AltDeque` against `VecDeque` that run on stable, including workloads that
+//! are adversarial for a deque made of two stacks. Run them with `cargo bench --bench workloads`.
+
+use std::collections::VecDeque;
+use std::hint::black_box;
+
+use altdeque::AltDeque;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+const LEN: usize = 10_000;
+
+/// Benchmarks `$body` once for an `AltDeque` and once for a `VecDeque`. Before every iteration,
+/// an empty deque is bound to `$deque` and prepared by `$setup`, which is not measured.
+macro_rules! compare {
+    ($c:expr, $name:expr, |$deque:ident| $setup:expr, $body:expr) => {{
+        let mut group = $c.benchmark_group($name);
+        group.bench_function("AltDeque", |b| {
+            b.iter_batched_ref(
+                || {
+                    #[allow(unused_mut)]
+                    let mut $deque = AltDeque::new();
+                    let () = $setup;
+                    $deque
+                },
+                |$deque| black_box($body),
+                BatchSize::SmallInput,
+            )
+        });
+        group.bench_function("VecDeque", |b| {
+            b.iter_batched_ref(
+                || {
+                    #[allow(unused_mut)]
+                    let mut $deque = VecDeque::new();
+                    let () = $setup;
+                    $deque
+                },
+                |$deque| black_box($body),
+                BatchSize::SmallInput,
+            )
+        });
+        group.finish();
+    }};
+}
+
+/// A small xorshift generator, so the workloads are the same in every run.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a number in `0..max`.
+    fn below(&mut self, max: usize) -> usize {
+        (self.next() % max as u64) as usize
+    }
+}
+
+fn push_back_pop_front(c: &mut Criterion) {
+    compare!(c, "push_back_pop_front", |deque| (), {
+        for i in 0..LEN {
+            deque.push_back(i);
+        }
+        let mut sum = 0;
+        while let Some(value) = deque.pop_front() {
+            sum += value;
+        }
+        sum
+    });
+}
+
+fn alternating_pops(c: &mut Criterion) {
+    // every pop from the end that ran dry splits the remaining elements between the stacks
+    compare!(c, "alternating_pops", |deque| deque.extend(0..LEN), {
+        let mut sum = 0;
+        while let (Some(a), Some(b)) = (deque.pop_front(), deque.pop_back()) {
+            sum += a + b;
+        }
+        sum
+    });
+}
+
+fn sliding_window(c: &mut Criterion) {
+    // a queue that is refilled from the front and drained from the back in bursts
+    compare!(c, "sliding_window", |deque| deque.extend(0..LEN), {
+        let mut sum = 0;
+        for i in 0..LEN / 100 {
+            for j in 0..100 {
+                deque.push_front(i + j);
+            }
+            for _ in 0..100 {
+                sum += deque.pop_back().unwrap();
+            }
+        }
+        sum
+    });
+}
+
+fn random_access(c: &mut Criterion) {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    let indices: Vec<_> = (0..LEN).map(|_| rng.below(LEN)).collect();
+    compare!(
+        c,
+        "random_access",
+        |deque| for i in 0..LEN {
+            if i % 2 == 0 {
+                deque.push_front(i);
+            } else {
+                deque.push_back(i);
+            }
+        },
+        indices.iter().map(|&i| deque[i]).sum::<usize>()
+    );
+}
+
+fn insert_remove_middle(c: &mut Criterion) {
+    compare!(c, "insert_remove_middle", |deque| deque.extend(0..LEN), {
+        for i in 0..100 {
+            deque.insert(LEN / 2, i);
+            deque.remove(LEN / 3);
+        }
+        deque.len()
+    });
+}
+
+fn drain(c: &mut Criterion) {
+    compare!(c, "drain", |deque| deque.extend(0..LEN), deque.drain(LEN / 4..LEN * 3 / 4).sum::<usize>());
+}
+
+fn make_contiguous(c: &mut Criterion) {
+    compare!(
+        c,
+        "make_contiguous",
+        |deque| for i in 0..LEN {
+            if i % 2 == 0 {
+                deque.push_front(i);
+            } else {
+                deque.push_back(i);
+            }
+        },
+        deque.make_contiguous().len()
+    );
+}
+
+fn mixed(c: &mut Criterion) {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    let ops: Vec<_> = (0..LEN).map(|_| rng.below(6)).collect();
+    compare!(c, "mixed", |deque| deque.extend(0..LEN / 10), {
+        let mut sum = 0;
+        for (i, &op) in ops.iter().enumerate() {
+            match op {
+                0 => deque.push_front(i),
+                1 => deque.push_back(i),
+                2 => sum += deque.pop_front().unwrap_or(0),
+                3 => sum += deque.pop_back().unwrap_or(0),
+                _ => sum += deque.get(i % (deque.len() + 1)).copied().unwrap_or(0),
+            }
+        }
+        sum
+    });
+}
+
+criterion_group!(
+    benches,
+    push_back_pop_front,
+    alternating_pops,
+    sliding_window,
+    random_access,
+    insert_remove_middle,
+    drain,
+    make_contiguous,
+    mixed
+);
+criterion_main!(benches);
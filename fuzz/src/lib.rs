@@ -0,0 +1,258 @@
+//! Shared code of the fuzz targets: decoding an operation stream from raw bytes and running it
+//! against an `AltDeque`.
+
+use std::cell::{Cell, RefCell};
+use std::collections::{HashSet, VecDeque};
+use std::panic::{self, AssertUnwindSafe};
+
+use altdeque::AltDeque;
+
+/// An operation on a deque. Indices and lengths are taken modulo the current length when the
+/// operation is applied, so every decoded operation is valid.
+#[derive(Clone, Copy, Debug)]
+pub enum Op {
+    PushFront,
+    PushBack,
+    PopFront,
+    PopBack,
+    Insert(usize),
+    Remove(usize),
+    Drain { start: usize, len: usize, take: usize, from_back: bool },
+    Truncate(usize),
+    MakeContiguous,
+    SplitOff { at: usize, append: bool },
+    RotateLeft(usize),
+    RotateRight(usize),
+    Retain(u8),
+    Clear,
+    ShrinkToFit,
+    Reserve(usize),
+}
+
+/// Decodes operations from a byte stream until it runs out.
+pub struct Ops<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Ops<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    fn byte(&mut self) -> Option<u8> {
+        let (&first, rest) = self.data.split_first()?;
+        self.data = rest;
+        Some(first)
+    }
+
+    fn num(&mut self) -> Option<usize> {
+        Some(usize::from(self.byte()?) | usize::from(self.byte()?) << 8)
+    }
+}
+
+impl Iterator for Ops<'_> {
+    type Item = Op;
+
+    fn next(&mut self) -> Option<Op> {
+        let op = match self.byte()? % 16 {
+            0 => Op::PushFront,
+            1 => Op::PushBack,
+            2 => Op::PopFront,
+            3 => Op::PopBack,
+            4 => Op::Insert(self.num()?),
+            5 => Op::Remove(self.num()?),
+            6 => Op::Drain {
+                start: self.num()?,
+                len: self.num()?,
+                take: self.num()?,
+                from_back: self.byte()? % 2 == 1,
+            },
+            7 => Op::Truncate(self.num()?),
+            8 => Op::MakeContiguous,
+            9 => Op::SplitOff { at: self.num()?, append: self.byte()? % 2 == 1 },
+            10 => Op::RotateLeft(self.num()?),
+            11 => Op::RotateRight(self.num()?),
+            12 => Op::Retain(self.byte()?),
+            13 => Op::Clear,
+            14 => Op::ShrinkToFit,
+            _ => Op::Reserve(usize::from(self.byte()?)),
+        };
+        Some(op)
+    }
+}
+
+/// Runs the operations against an `AltDeque` and a `VecDeque` and checks that both always
+/// contain the same elements.
+pub fn run_with_oracle(data: &[u8]) {
+    let mut deque = AltDeque::new();
+    let mut oracle = VecDeque::new();
+    let mut next = 0u32;
+
+    for op in Ops::new(data) {
+        let len = oracle.len();
+        match op {
+            Op::PushFront => {
+                deque.push_front(next);
+                oracle.push_front(next);
+                next += 1;
+            }
+            Op::PushBack => {
+                deque.push_back(next);
+                oracle.push_back(next);
+                next += 1;
+            }
+            Op::PopFront => assert_eq!(deque.pop_front(), oracle.pop_front()),
+            Op::PopBack => assert_eq!(deque.pop_back(), oracle.pop_back()),
+            Op::Insert(index) => {
+                deque.insert(index % (len + 1), next);
+                oracle.insert(index % (len + 1), next);
+                next += 1;
+            }
+            Op::Remove(index) => {
+                assert_eq!(deque.remove(index % (len + 1)), oracle.remove(index % (len + 1)));
+            }
+            Op::Drain { start, len: drain_len, take, from_back } => {
+                let start = start % (len + 1);
+                let end = start + drain_len % (len - start + 1);
+                let mut drain = deque.drain(start..end);
+                let mut oracle_drain = oracle.drain(start..end);
+                for _ in 0..take % (end - start + 1) {
+                    if from_back {
+                        assert_eq!(drain.next_back(), oracle_drain.next_back());
+                    } else {
+                        assert_eq!(drain.next(), oracle_drain.next());
+                    }
+                }
+            }
+            Op::Truncate(new_len) => {
+                deque.truncate(new_len % (len + 1));
+                oracle.truncate(new_len % (len + 1));
+            }
+            Op::MakeContiguous => {
+                assert_eq!(deque.make_contiguous(), oracle.make_contiguous());
+            }
+            Op::SplitOff { at, append } => {
+                let mut other = deque.split_off(at % (len + 1));
+                let mut oracle_other = oracle.split_off(at % (len + 1));
+                assert!(other.iter().eq(oracle_other.iter()));
+                if append {
+                    deque.append(&mut other);
+                    oracle.append(&mut oracle_other);
+                }
+            }
+            Op::RotateLeft(mid) => {
+                deque.rotate_left(mid % (len + 1));
+                oracle.rotate_left(mid % (len + 1));
+            }
+            Op::RotateRight(k) => {
+                deque.rotate_right(k % (len + 1));
+                oracle.rotate_right(k % (len + 1));
+            }
+            Op::Retain(modulus) => {
+                let modulus = u32::from(modulus % 8) + 2;
+                deque.retain(|x| x % modulus != 0);
+                oracle.retain(|x| x % modulus != 0);
+            }
+            Op::Clear => {
+                deque.clear();
+                oracle.clear();
+            }
+            Op::ShrinkToFit => deque.shrink_to_fit(),
+            Op::Reserve(additional) => deque.reserve(additional),
+        }
+        assert_eq!(deque.len(), oracle.len());
+        assert!(deque.iter().eq(oracle.iter()));
+        assert!(deque.iter().rev().eq(oracle.iter().rev()));
+    }
+}
+
+thread_local! {
+    static DROPPED: RefCell<HashSet<u32>> = RefCell::new(HashSet::new());
+    static PANIC_ON: Cell<u8> = const { Cell::new(0) };
+}
+
+/// An element whose destructor panics for some ids and which detects double drops.
+struct Bomb(u32);
+
+impl Drop for Bomb {
+    fn drop(&mut self) {
+        let first = DROPPED.with(|dropped| dropped.borrow_mut().insert(self.0));
+        assert!(first, "element {} dropped twice", self.0);
+        let modulus = PANIC_ON.with(Cell::get);
+        if modulus != 0 && self.0.is_multiple_of(u32::from(modulus)) && !std::thread::panicking() {
+            panic!("boom");
+        }
+    }
+}
+
+/// Runs the operations against an `AltDeque` of elements whose destructors may panic. After
+/// every operation, panicking or not, the deque must still be usable and no element may have
+/// been dropped twice.
+pub fn run_with_panicking_drop(data: &[u8]) {
+    let Some((&modulus, data)) = data.split_first() else {
+        return;
+    };
+    DROPPED.with(|dropped| dropped.borrow_mut().clear());
+    PANIC_ON.with(|panic_on| panic_on.set(modulus % 8));
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut deque = AltDeque::new();
+    let mut next = 0u32;
+    for op in Ops::new(data) {
+        let len = deque.len();
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| match op {
+            Op::PushFront => {
+                next += 1;
+                deque.push_front(Bomb(next));
+            }
+            Op::PushBack => {
+                next += 1;
+                deque.push_back(Bomb(next));
+            }
+            Op::PopFront => drop(deque.pop_front()),
+            Op::PopBack => drop(deque.pop_back()),
+            Op::Insert(index) => {
+                next += 1;
+                deque.insert(index % (len + 1), Bomb(next));
+            }
+            Op::Remove(index) => drop(deque.remove(index % (len + 1))),
+            Op::Drain { start, len: drain_len, take, from_back } => {
+                let start = start % (len + 1);
+                let end = start + drain_len % (len - start + 1);
+                let mut drain = deque.drain(start..end);
+                for _ in 0..take % (end - start + 1) {
+                    if from_back {
+                        drop(drain.next_back());
+                    } else {
+                        drop(drain.next());
+                    }
+                }
+            }
+            Op::Truncate(new_len) => deque.truncate(new_len % (len + 1)),
+            Op::MakeContiguous => drop(deque.make_contiguous()),
+            Op::SplitOff { at, append } => {
+                let mut other = deque.split_off(at % (len + 1));
+                if append {
+                    deque.append(&mut other);
+                }
+            }
+            Op::RotateLeft(mid) => deque.rotate_left(mid % (len + 1)),
+            Op::RotateRight(k) => deque.rotate_right(k % (len + 1)),
+            Op::Retain(modulus) => {
+                let modulus = u32::from(modulus % 8) + 2;
+                deque.retain(|x| x.0 % modulus != 0);
+            }
+            Op::Clear => deque.clear(),
+            Op::ShrinkToFit => deque.shrink_to_fit(),
+            Op::Reserve(additional) => deque.reserve(additional),
+        }));
+        // every element still in the deque must be alive
+        DROPPED.with(|dropped| {
+            let dropped = dropped.borrow();
+            assert!(deque.iter().all(|el| !dropped.contains(&el.0)));
+        });
+    }
+    // the final drop may panic as well
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| drop(deque)));
+    let _ = panic::take_hook();
+}
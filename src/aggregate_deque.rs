@@ -0,0 +1,337 @@
+use std::fmt;
+
+use crate::{AltDeque, Iter};
+
+/// A deque that can fold all of its elements with an associative operation in *O(1)* time.
+///
+/// This generalizes [`MinMaxQueue`] to any associative operation, like sums, maxima, greatest
+/// common divisors or matrix products. The operation does not need to be commutative, the
+/// elements are always folded from front to back.
+///
+/// Next to every element of the underlying [`AltDeque`], the fold of all elements from the
+/// bottom of its stack up to it is stored. A push only folds the new element into the entry
+/// below it, so [`fold_all`] just combines the entries at the tops of both stacks. Only when a
+/// pop finds its stack empty and [`AltDeque`] splits the other stack, all entries are computed
+/// anew, which keeps all operations amortized *O(1)*.
+///
+/// [`MinMaxQueue`]: crate::MinMaxQueue
+/// [`fold_all`]: AggregateDeque::fold_all
+///
+/// # Examples
+///
+/// ```
+/// # use altdeque::AggregateDeque;
+/// // sums over a sliding window of three elements
+/// let mut window = AggregateDeque::new(|a: &i32, b: &i32| a + b);
+/// let mut sums = Vec::new();
+/// for x in [1, 2, 3, 4, 5] {
+///     window.push_back(x);
+///     if window.len() > 3 {
+///         window.pop_front();
+///     }
+///     sums.push(window.fold_all().unwrap());
+/// }
+/// assert_eq!(sums, [1, 3, 6, 9, 12]);
+/// ```
+pub struct AggregateDeque<T, F> {
+    deque: AltDeque<T>,
+    // Folds of the elements from the bottom of the stack up to the one at the same position.
+    // The bottom of the front stack is its last element, the bottom of the back stack its first
+    // one.
+    front: Vec<T>,
+    back: Vec<T>,
+    f: F,
+}
+
+impl<T, F> AggregateDeque<T, F> {
+    /// Creates an empty deque that folds its elements with `f`.
+    ///
+    /// `f` must be associative, that is `f(&f(&a, &b), &c)` must equal `f(&a, &f(&b, &c))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AggregateDeque;
+    /// let deque = AggregateDeque::new(|a: &u32, b: &u32| *a.max(b));
+    /// assert!(deque.is_empty());
+    /// ```
+    pub fn new(f: F) -> Self
+    where
+        F: Fn(&T, &T) -> T,
+    {
+        Self { deque: AltDeque::new(), front: Vec::new(), back: Vec::new(), f }
+    }
+
+    /// Creates an empty deque with space for at least `capacity` elements that folds its
+    /// elements with `f`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AggregateDeque;
+    /// let deque = AggregateDeque::with_capacity(10, |a: &u32, b: &u32| a + b);
+    /// assert!(deque.is_empty());
+    /// ```
+    pub fn with_capacity(capacity: usize, f: F) -> Self
+    where
+        F: Fn(&T, &T) -> T,
+    {
+        Self { deque: AltDeque::with_capacity(capacity), front: Vec::new(), back: Vec::new(), f }
+    }
+
+    /// Returns the number of elements in the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AggregateDeque;
+    /// let mut deque = AggregateDeque::new(|a: &u32, b: &u32| a + b);
+    /// deque.push_back(1);
+    /// assert_eq!(deque.len(), 1);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.deque.len()
+    }
+
+    /// Returns `true` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AggregateDeque;
+    /// let mut deque = AggregateDeque::new(|a: &u32, b: &u32| a + b);
+    /// assert!(deque.is_empty());
+    /// deque.push_back(1);
+    /// assert!(!deque.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.deque.is_empty()
+    }
+
+    /// Provides a reference to the front element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AggregateDeque;
+    /// let mut deque = AggregateDeque::new(|a: &u32, b: &u32| a + b);
+    /// deque.extend([1, 2]);
+    /// assert_eq!(deque.front(), Some(&1));
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        self.deque.front()
+    }
+
+    /// Provides a reference to the back element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AggregateDeque;
+    /// let mut deque = AggregateDeque::new(|a: &u32, b: &u32| a + b);
+    /// deque.extend([1, 2]);
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    pub fn back(&self) -> Option<&T> {
+        self.deque.back()
+    }
+
+    /// Returns a front-to-back iterator over the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AggregateDeque;
+    /// let mut deque = AggregateDeque::new(|a: &u32, b: &u32| a + b);
+    /// deque.extend([1, 2]);
+    /// assert!(deque.iter().eq(&[1, 2]));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.deque.iter()
+    }
+
+    /// Removes all elements from the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AggregateDeque;
+    /// let mut deque = AggregateDeque::new(|a: &u32, b: &u32| a + b);
+    /// deque.extend([1, 2]);
+    /// deque.clear();
+    /// assert_eq!(deque.fold_all(), None);
+    /// ```
+    pub fn clear(&mut self) {
+        self.deque.clear();
+        self.front.clear();
+        self.back.clear();
+    }
+}
+
+impl<T: Clone, F: Fn(&T, &T) -> T> AggregateDeque<T, F> {
+    /// Prepends an element to the front of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AggregateDeque;
+    /// let mut deque = AggregateDeque::new(|a: &String, b: &String| a.clone() + b);
+    /// deque.push_front(String::from("b"));
+    /// deque.push_front(String::from("a"));
+    /// assert_eq!(deque.fold_all().unwrap(), "ab");
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        let entry = match self.front.last() {
+            Some(below) => (self.f)(&value, below),
+            None => value.clone(),
+        };
+        self.deque.push_front(value);
+        self.front.push(entry);
+    }
+
+    /// Appends an element to the back of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AggregateDeque;
+    /// let mut deque = AggregateDeque::new(|a: &String, b: &String| a.clone() + b);
+    /// deque.push_back(String::from("a"));
+    /// deque.push_back(String::from("b"));
+    /// assert_eq!(deque.fold_all().unwrap(), "ab");
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        let entry = match self.back.last() {
+            Some(below) => (self.f)(below, &value),
+            None => value.clone(),
+        };
+        self.deque.push_back(value);
+        self.back.push(entry);
+    }
+
+    /// Removes the first element and returns it, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AggregateDeque;
+    /// let mut deque = AggregateDeque::new(|a: &u32, b: &u32| a + b);
+    /// deque.extend([1, 2, 3]);
+    /// assert_eq!(deque.pop_front(), Some(1));
+    /// assert_eq!(deque.fold_all(), Some(5));
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        let split = self.front.is_empty();
+        let value = self.deque.pop_front()?;
+        if split {
+            self.rebuild();
+        } else {
+            self.front.pop();
+        }
+        Some(value)
+    }
+
+    /// Removes the last element and returns it, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AggregateDeque;
+    /// let mut deque = AggregateDeque::new(|a: &u32, b: &u32| a + b);
+    /// deque.extend([1, 2, 3]);
+    /// assert_eq!(deque.pop_back(), Some(3));
+    /// assert_eq!(deque.fold_all(), Some(3));
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        let split = self.back.is_empty();
+        let value = self.deque.pop_back()?;
+        if split {
+            self.rebuild();
+        } else {
+            self.back.pop();
+        }
+        Some(value)
+    }
+
+    /// Returns the fold of all elements from front to back, or `None` if the deque is empty.
+    ///
+    /// This calls the fold operation at most once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AggregateDeque;
+    /// // the product of 2x2 matrices is associative but not commutative
+    /// type Matrix = [[i64; 2]; 2];
+    /// let mul = |a: &Matrix, b: &Matrix| {
+    ///     let mut c = [[0; 2]; 2];
+    ///     for i in 0..2 {
+    ///         for j in 0..2 {
+    ///             c[i][j] = a[i][0] * b[0][j] + a[i][1] * b[1][j];
+    ///         }
+    ///     }
+    ///     c
+    /// };
+    /// let mut deque = AggregateDeque::new(mul);
+    /// deque.push_back([[1, 1], [0, 1]]);
+    /// deque.push_back([[1, 0], [1, 1]]);
+    /// assert_eq!(deque.fold_all(), Some([[2, 1], [1, 1]]));
+    /// ```
+    pub fn fold_all(&self) -> Option<T> {
+        match (self.front.last(), self.back.last()) {
+            (Some(front), Some(back)) => Some((self.f)(front, back)),
+            (Some(entry), None) | (None, Some(entry)) => Some(entry.clone()),
+            (None, None) => None,
+        }
+    }
+
+    /// Recomputes the entries of both stacks after the deque moved elements between them.
+    fn rebuild(&mut self) {
+        let (front, back) = self.deque.as_slices();
+        self.front.clear();
+        self.back.clear();
+        for value in front.iter().rev() {
+            let entry = match self.front.last() {
+                Some(below) => (self.f)(value, below),
+                None => value.clone(),
+            };
+            self.front.push(entry);
+        }
+        for value in back {
+            let entry = match self.back.last() {
+                Some(below) => (self.f)(below, value),
+                None => value.clone(),
+            };
+            self.back.push(entry);
+        }
+    }
+}
+
+impl<T: Clone, F: Clone> Clone for AggregateDeque<T, F> {
+    fn clone(&self) -> Self {
+        // the entries refer to positions in the stacks, so the split between them is kept
+        let (front, back) = self.deque.as_slices();
+        let mut deque = AltDeque::with_capacity(self.len());
+        front.iter().rev().for_each(|value| deque.push_front(value.clone()));
+        back.iter().for_each(|value| deque.push_back(value.clone()));
+        Self { deque, front: self.front.clone(), back: self.back.clone(), f: self.f.clone() }
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for AggregateDeque<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone, F: Fn(&T, &T) -> T> Extend<T> for AggregateDeque<T, F> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push_back(value));
+    }
+}
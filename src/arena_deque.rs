@@ -0,0 +1,626 @@
+use core::cmp;
+use core::hash::{Hash, Hasher};
+use core::ops::{Index, IndexMut};
+
+use std::fmt;
+use std::mem::{self, MaybeUninit};
+use std::ptr::NonNull;
+use std::slice;
+
+use crate::fixed_stacks::FixedStacks;
+use crate::{index_out_of_bounds, Iter, IterMut};
+
+/// An arena that an [`ArenaAltDeque`] takes its buffers from.
+///
+/// The memory of an arena is only given back when the arena itself is reset or dropped, so the
+/// buffers that a deque has grown out of stay allocated until then.
+pub trait Arena {
+    /// Allocates an uninitialized buffer for at least `capacity` elements, which stays valid
+    /// as long as the arena is borrowed. Panics or aborts if the arena is out of memory.
+    // handing out mutable memory from a shared reference is what an arena is for
+    #[allow(clippy::mut_from_ref)]
+    fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>];
+}
+
+#[cfg(feature = "bumpalo")]
+impl Arena for bumpalo::Bump {
+    #[allow(clippy::mut_from_ref)]
+    fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+        self.alloc_slice_fill_with(capacity, |_| MaybeUninit::uninit())
+    }
+}
+
+/// An [`AltDeque`] whose buffer lives in a [`bumpalo`](https://docs.rs/bumpalo) arena.
+///
+/// This is [`ArenaAltDeque`] with a `bumpalo::Bump` as the arena, created with
+/// [`BumpAltDeque::new_in`](ArenaAltDeque::new_in). It is only available with the `bumpalo`
+/// feature.
+///
+/// [`AltDeque`]: crate::AltDeque
+///
+/// # Examples
+///
+/// ```
+/// # use altdeque::BumpAltDeque;
+/// use bumpalo::Bump;
+///
+/// let bump = Bump::new();
+/// let mut deque: BumpAltDeque<'_, u32> = BumpAltDeque::new_in(&bump);
+/// deque.extend(0..10);
+/// deque.push_front(0);
+/// assert_eq!(deque.len(), 11);
+/// ```
+#[cfg(feature = "bumpalo")]
+pub type BumpAltDeque<'bump, T> = ArenaAltDeque<'bump, T, bumpalo::Bump>;
+
+/// A deque that allocates its buffer in an arena, for data that lives as long as one frame
+/// of a game or one pass of a compiler.
+///
+/// `ArenaAltDeque` uses the same two-stack layout as [`AltDeque`] and grows like it, but
+/// every buffer comes from an [`Arena`] and is never freed by the deque. When the deque grows,
+/// the old buffer stays in the arena until the arena is reset. Dropping the deque drops its
+/// elements, which costs nothing for `Copy` elements.
+///
+/// With the `bumpalo` feature, a `bumpalo::Bump` is an `Arena`, see `BumpAltDeque`.
+///
+/// [`AltDeque`]: crate::AltDeque
+///
+/// # Examples
+///
+/// ```
+/// # use altdeque::{Arena, ArenaAltDeque};
+/// # use std::mem::MaybeUninit;
+/// struct Leak;
+///
+/// impl Arena for Leak {
+///     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+///         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+///     }
+/// }
+///
+/// let mut deque = ArenaAltDeque::new_in(&Leak);
+/// deque.push_back(1);
+/// deque.push_front(0);
+/// assert_eq!(deque, [0, 1]);
+/// ```
+pub struct ArenaAltDeque<'a, T, A> {
+    stacks: FixedStacks<T, &'a mut [MaybeUninit<T>]>,
+    arena: &'a A,
+}
+
+impl<'a, T, A: Arena> ArenaAltDeque<'a, T, A> {
+    /// Creates an empty deque that will allocate its buffer in `arena`. Does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{Arena, ArenaAltDeque};
+    /// # use std::mem::MaybeUninit;
+    /// # struct Leak;
+    /// # impl Arena for Leak {
+    /// #     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+    /// #         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    /// #     }
+    /// # }
+    /// # let arena = Leak;
+    /// let deque: ArenaAltDeque<'_, i32, _> = ArenaAltDeque::new_in(&arena);
+    /// assert_eq!(deque.capacity(), 0);
+    /// ```
+    pub fn new_in(arena: &'a A) -> Self {
+        Self::with_capacity_in(0, arena)
+    }
+
+    /// Creates an empty deque with space for at least `capacity` elements in `arena`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{Arena, ArenaAltDeque};
+    /// # use std::mem::MaybeUninit;
+    /// # struct Leak;
+    /// # impl Arena for Leak {
+    /// #     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+    /// #         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    /// #     }
+    /// # }
+    /// # let arena = Leak;
+    /// let deque: ArenaAltDeque<'_, i32, _> = ArenaAltDeque::with_capacity_in(10, &arena);
+    /// assert!(deque.capacity() >= 10);
+    /// ```
+    pub fn with_capacity_in(capacity: usize, arena: &'a A) -> Self {
+        let buf = if mem::size_of::<T>() == 0 {
+            // SAFETY: a slice of zero-sized elements needs no memory
+            unsafe { slice::from_raw_parts_mut(NonNull::dangling().as_ptr(), usize::MAX) }
+        } else if capacity == 0 {
+            &mut []
+        } else {
+            arena.alloc_buffer(capacity)
+        };
+        Self { stacks: FixedStacks::new(buf), arena }
+    }
+
+    /// Returns the number of elements the deque can hold without growing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{Arena, ArenaAltDeque};
+    /// # use std::mem::MaybeUninit;
+    /// # struct Leak;
+    /// # impl Arena for Leak {
+    /// #     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+    /// #         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    /// #     }
+    /// # }
+    /// # let arena = Leak;
+    /// let mut deque = ArenaAltDeque::new_in(&arena);
+    /// deque.push_back(1);
+    /// assert!(deque.capacity() >= 1);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.stacks.capacity()
+    }
+
+    /// Returns the number of elements in the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{Arena, ArenaAltDeque};
+    /// # use std::mem::MaybeUninit;
+    /// # struct Leak;
+    /// # impl Arena for Leak {
+    /// #     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+    /// #         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    /// #     }
+    /// # }
+    /// # let arena = Leak;
+    /// let mut deque = ArenaAltDeque::new_in(&arena);
+    /// deque.push_back(1);
+    /// assert_eq!(deque.len(), 1);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.stacks.len()
+    }
+
+    /// Returns `true` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{Arena, ArenaAltDeque};
+    /// # use std::mem::MaybeUninit;
+    /// # struct Leak;
+    /// # impl Arena for Leak {
+    /// #     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+    /// #         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    /// #     }
+    /// # }
+    /// # let arena = Leak;
+    /// let mut deque = ArenaAltDeque::new_in(&arena);
+    /// assert!(deque.is_empty());
+    /// deque.push_back(1);
+    /// assert!(!deque.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.stacks.is_empty()
+    }
+
+    /// Returns a pair of slices which contain, in order, the contents of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{Arena, ArenaAltDeque};
+    /// # use std::mem::MaybeUninit;
+    /// # struct Leak;
+    /// # impl Arena for Leak {
+    /// #     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+    /// #         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    /// #     }
+    /// # }
+    /// # let arena = Leak;
+    /// let mut deque = ArenaAltDeque::new_in(&arena);
+    /// deque.push_back(1);
+    /// deque.push_front(0);
+    /// assert_eq!(deque.as_slices(), (&[0][..], &[1][..]));
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        self.stacks.as_slices()
+    }
+
+    /// Returns a pair of mutable slices which contain, in order, the contents of the deque.
+    ///
+    /// See the non-mutable version [`as_slices`] for details and examples.
+    ///
+    /// [`as_slices`]: ArenaAltDeque::as_slices
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        self.stacks.as_mut_slices()
+    }
+
+    /// Provides a reference to the element at the given index.
+    ///
+    /// Element at index 0 is the front of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{Arena, ArenaAltDeque};
+    /// # use std::mem::MaybeUninit;
+    /// # struct Leak;
+    /// # impl Arena for Leak {
+    /// #     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+    /// #         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    /// #     }
+    /// # }
+    /// # let arena = Leak;
+    /// let mut deque = ArenaAltDeque::new_in(&arena);
+    /// deque.push_back(1);
+    /// assert_eq!(deque.get(0), Some(&1));
+    /// assert_eq!(deque.get(1), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.stacks.get(index)
+    }
+
+    /// Provides a mutable reference to the element at the given index.
+    ///
+    /// Element at index 0 is the front of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{Arena, ArenaAltDeque};
+    /// # use std::mem::MaybeUninit;
+    /// # struct Leak;
+    /// # impl Arena for Leak {
+    /// #     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+    /// #         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    /// #     }
+    /// # }
+    /// # let arena = Leak;
+    /// let mut deque = ArenaAltDeque::new_in(&arena);
+    /// deque.push_back(1);
+    /// *deque.get_mut(0).unwrap() = 2;
+    /// assert_eq!(deque, [2]);
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.stacks.get_mut(index)
+    }
+
+    /// Provides a reference to the front element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{Arena, ArenaAltDeque};
+    /// # use std::mem::MaybeUninit;
+    /// # struct Leak;
+    /// # impl Arena for Leak {
+    /// #     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+    /// #         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    /// #     }
+    /// # }
+    /// # let arena = Leak;
+    /// let mut deque = ArenaAltDeque::new_in(&arena);
+    /// deque.extend([1, 2]);
+    /// assert_eq!(deque.front(), Some(&1));
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Provides a reference to the back element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{Arena, ArenaAltDeque};
+    /// # use std::mem::MaybeUninit;
+    /// # struct Leak;
+    /// # impl Arena for Leak {
+    /// #     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+    /// #         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    /// #     }
+    /// # }
+    /// # let arena = Leak;
+    /// let mut deque = ArenaAltDeque::new_in(&arena);
+    /// deque.extend([1, 2]);
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len().wrapping_sub(1))
+    }
+
+    /// Removes the first element and returns it, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{Arena, ArenaAltDeque};
+    /// # use std::mem::MaybeUninit;
+    /// # struct Leak;
+    /// # impl Arena for Leak {
+    /// #     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+    /// #         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    /// #     }
+    /// # }
+    /// # let arena = Leak;
+    /// let mut deque = ArenaAltDeque::new_in(&arena);
+    /// deque.push_back(1);
+    /// assert_eq!(deque.pop_front(), Some(1));
+    /// assert_eq!(deque.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.stacks.pop_front()
+    }
+
+    /// Removes the last element and returns it, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{Arena, ArenaAltDeque};
+    /// # use std::mem::MaybeUninit;
+    /// # struct Leak;
+    /// # impl Arena for Leak {
+    /// #     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+    /// #         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    /// #     }
+    /// # }
+    /// # let arena = Leak;
+    /// let mut deque = ArenaAltDeque::new_in(&arena);
+    /// deque.push_back(1);
+    /// assert_eq!(deque.pop_back(), Some(1));
+    /// assert_eq!(deque.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.stacks.pop_back()
+    }
+
+    /// Prepends an element to the front of the deque, growing the buffer if it is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{Arena, ArenaAltDeque};
+    /// # use std::mem::MaybeUninit;
+    /// # struct Leak;
+    /// # impl Arena for Leak {
+    /// #     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+    /// #         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    /// #     }
+    /// # }
+    /// # let arena = Leak;
+    /// let mut deque = ArenaAltDeque::new_in(&arena);
+    /// deque.push_front(1);
+    /// deque.push_front(0);
+    /// assert_eq!(deque, [0, 1]);
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        if let Err(value) = self.stacks.push_front(value) {
+            self.grow();
+            // cannot fail after growing
+            let _ = self.stacks.push_front(value);
+        }
+    }
+
+    /// Appends an element to the back of the deque, growing the buffer if it is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{Arena, ArenaAltDeque};
+    /// # use std::mem::MaybeUninit;
+    /// # struct Leak;
+    /// # impl Arena for Leak {
+    /// #     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+    /// #         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    /// #     }
+    /// # }
+    /// # let arena = Leak;
+    /// let mut deque = ArenaAltDeque::new_in(&arena);
+    /// deque.push_back(0);
+    /// deque.push_back(1);
+    /// assert_eq!(deque, [0, 1]);
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        if let Err(value) = self.stacks.push_back(value) {
+            self.grow();
+            // cannot fail after growing
+            let _ = self.stacks.push_back(value);
+        }
+    }
+
+    /// Shortens the deque, keeping the first `len` elements and dropping the rest.
+    ///
+    /// If `len` is greater than the deque's current length, this has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{Arena, ArenaAltDeque};
+    /// # use std::mem::MaybeUninit;
+    /// # struct Leak;
+    /// # impl Arena for Leak {
+    /// #     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+    /// #         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    /// #     }
+    /// # }
+    /// # let arena = Leak;
+    /// let mut deque = ArenaAltDeque::new_in(&arena);
+    /// deque.extend([1, 2, 3]);
+    /// deque.truncate(1);
+    /// assert_eq!(deque, [1]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        self.stacks.truncate(len);
+    }
+
+    /// Removes all elements from the deque. The buffer is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{Arena, ArenaAltDeque};
+    /// # use std::mem::MaybeUninit;
+    /// # struct Leak;
+    /// # impl Arena for Leak {
+    /// #     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+    /// #         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    /// #     }
+    /// # }
+    /// # let arena = Leak;
+    /// let mut deque = ArenaAltDeque::new_in(&arena);
+    /// deque.push_back(1);
+    /// deque.clear();
+    /// assert!(deque.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.stacks.truncate(0);
+    }
+
+    /// Returns a front-to-back iterator over the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{Arena, ArenaAltDeque};
+    /// # use std::mem::MaybeUninit;
+    /// # struct Leak;
+    /// # impl Arena for Leak {
+    /// #     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+    /// #         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    /// #     }
+    /// # }
+    /// # let arena = Leak;
+    /// let mut deque = ArenaAltDeque::new_in(&arena);
+    /// deque.push_back(2);
+    /// deque.push_front(1);
+    /// assert!(deque.iter().eq(&[1, 2]));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.stacks.iter()
+    }
+
+    /// Returns a front-to-back iterator that returns mutable references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{Arena, ArenaAltDeque};
+    /// # use std::mem::MaybeUninit;
+    /// # struct Leak;
+    /// # impl Arena for Leak {
+    /// #     fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+    /// #         Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    /// #     }
+    /// # }
+    /// # let arena = Leak;
+    /// let mut deque = ArenaAltDeque::new_in(&arena);
+    /// deque.push_back(1);
+    /// deque.iter_mut().for_each(|x| *x += 1);
+    /// assert_eq!(deque, [2]);
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.stacks.iter_mut()
+    }
+
+    /// Doubles the capacity with a new buffer from the arena, the old one stays in the arena.
+    #[cold]
+    #[inline(never)]
+    fn grow(&mut self) {
+        let cap = self.capacity();
+        let new_cap = cmp::max(cap.checked_mul(2).expect("capacity overflow"), 4);
+        let buf = self.arena.alloc_buffer(new_cap);
+        assert!(buf.len() > cap, "the arena returned a buffer that is too small");
+        self.stacks.replace_storage(buf);
+    }
+}
+
+impl<T: Clone, A: Arena> Clone for ArenaAltDeque<'_, T, A> {
+    /// Clones the deque into a new buffer in the same arena.
+    fn clone(&self) -> Self {
+        let mut deque = Self::with_capacity_in(self.len(), self.arena);
+        deque.extend(self.iter().cloned());
+        deque
+    }
+}
+
+impl<T: fmt::Debug, A: Arena> fmt::Debug for ArenaAltDeque<'_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T, A: Arena> Extend<T> for ArenaAltDeque<'_, T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<'t, T: Copy + 't, A: Arena> Extend<&'t T> for ArenaAltDeque<'_, T, A> {
+    fn extend<I: IntoIterator<Item = &'t T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T: Hash, A: Arena> Hash for ArenaAltDeque<'_, T, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        self.iter().for_each(|elem| elem.hash(state));
+    }
+}
+
+impl<T, A: Arena> Index<usize> for ArenaAltDeque<'_, T, A> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).unwrap_or_else(|| index_out_of_bounds(self.len(), index))
+    }
+}
+
+impl<T, A: Arena> IndexMut<usize> for ArenaAltDeque<'_, T, A> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len();
+        self.get_mut(index).unwrap_or_else(|| index_out_of_bounds(len, index))
+    }
+}
+
+impl<'b, T, A: Arena> IntoIterator for &'b ArenaAltDeque<'_, T, A> {
+    type Item = &'b T;
+    type IntoIter = Iter<'b, T>;
+
+    fn into_iter(self) -> Iter<'b, T> {
+        self.iter()
+    }
+}
+
+impl<'b, T, A: Arena> IntoIterator for &'b mut ArenaAltDeque<'_, T, A> {
+    type Item = &'b mut T;
+    type IntoIter = IterMut<'b, T>;
+
+    fn into_iter(self) -> IterMut<'b, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T: PartialEq, A: Arena> PartialEq for ArenaAltDeque<'_, T, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, A: Arena> Eq for ArenaAltDeque<'_, T, A> {}
+
+__impl_slice_eq! { [A: Arena] ArenaAltDeque<'_, T, A>, Vec<U>, }
+__impl_slice_eq! { [A: Arena] ArenaAltDeque<'_, T, A>, &[U], }
+__impl_slice_eq! { [A: Arena, const N: usize] ArenaAltDeque<'_, T, A>, [U; N], }
+__impl_slice_eq! { [A: Arena, const N: usize] ArenaAltDeque<'_, T, A>, &[U; N], }
@@ -0,0 +1,89 @@
+//! Async I/O traits for byte deques.
+//!
+//! A byte deque is an in-memory pipe: writes append to the back and reads take from the front.
+//! Neither ever waits, so every poll returns `Poll::Ready`.
+
+use std::io;
+#[cfg(feature = "futures-io")]
+use std::mem::MaybeUninit;
+use std::pin::Pin;
+#[cfg(feature = "futures-io")]
+use std::slice;
+use std::task::{Context, Poll};
+
+use crate::AltDeque;
+
+#[cfg(feature = "futures-io")]
+impl futures_io::AsyncRead for AltDeque<u8> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let len = buf.len();
+        // SAFETY: MaybeUninit<u8> has the same layout as u8 and pop_front_into only writes
+        // initialized bytes into the slice.
+        let buf = unsafe {
+            slice::from_raw_parts_mut(buf.as_mut_ptr() as *mut MaybeUninit<u8>, len)
+        };
+        Poll::Ready(Ok(self.get_mut().pop_front_into(buf)))
+    }
+}
+
+#[cfg(feature = "futures-io")]
+impl futures_io::AsyncWrite for AltDeque<u8> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.get_mut().extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl tokio::io::AsyncRead for AltDeque<u8> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        // SAFETY: pop_front_into only writes initialized bytes, and exactly `count` of them
+        // are marked as initialized and filled.
+        unsafe {
+            let count = self.get_mut().pop_front_into(buf.unfilled_mut());
+            buf.assume_init(count);
+            buf.advance(count);
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl tokio::io::AsyncWrite for AltDeque<u8> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.get_mut().extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
@@ -0,0 +1,51 @@
+//! `Encode`, `Decode` and `BorrowDecode` of bincode 2 for `AltDeque`, in the same format as
+//! bincode uses for `Vec` and `VecDeque`: the length followed by the elements.
+
+use std::slice;
+
+use bincode::de::{BorrowDecode, BorrowDecoder, Decode, Decoder};
+use bincode::enc::write::Writer;
+use bincode::enc::{Encode, Encoder};
+use bincode::error::{DecodeError, EncodeError};
+
+use crate::AltDeque;
+
+impl<T: Encode> Encode for AltDeque<T> {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        (self.len() as u64).encode(encoder)?;
+        let (front, back) = self.as_slices();
+        if unty::type_equal::<T, u8>() {
+            // SAFETY: T is u8, so both slices are byte slices
+            let (front, back) = unsafe {
+                (
+                    slice::from_raw_parts(front.as_ptr() as *const u8, front.len()),
+                    slice::from_raw_parts(back.as_ptr() as *const u8, back.len()),
+                )
+            };
+            encoder.writer().write(front)?;
+            encoder.writer().write(back)?;
+        } else {
+            for element in front.iter().chain(back) {
+                element.encode(encoder)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Decoding goes through Vec, which reads bytes in one go, checks the length against the limit
+// of the configuration before allocating, and turns into a deque without reallocating.
+
+impl<Context, T: Decode<Context>> Decode<Context> for AltDeque<T> {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        Ok(Vec::<T>::decode(decoder)?.into())
+    }
+}
+
+impl<'de, Context, T: BorrowDecode<'de, Context>> BorrowDecode<'de, Context> for AltDeque<T> {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        Ok(Vec::<T>::borrow_decode(decoder)?.into())
+    }
+}
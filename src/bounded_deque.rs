@@ -0,0 +1,494 @@
+use core::cmp;
+use core::hash::{Hash, Hasher};
+use core::ops::{Index, IndexMut};
+
+use std::fmt;
+
+use crate::{AltDeque, Iter, IterMut};
+
+/// A deque that holds at most a fixed number of elements.
+///
+/// Pushing into a full deque hands the value back in an `Err` instead of growing the buffer,
+/// which makes it usable for backpressure between producers and consumers. The buffer is
+/// allocated lazily and grows like the one of an [`AltDeque`], but never beyond the limit.
+///
+/// # Examples
+///
+/// ```
+/// # use altdeque::BoundedAltDeque;
+/// let mut deque = BoundedAltDeque::new(2);
+/// assert_eq!(deque.push_back(1), Ok(()));
+/// assert_eq!(deque.push_front(0), Ok(()));
+/// assert_eq!(deque.push_back(2), Err(2));
+/// assert_eq!(deque.pop_front(), Some(0));
+/// assert_eq!(deque.push_back(2), Ok(()));
+/// assert_eq!(deque, [1, 2]);
+/// ```
+pub struct BoundedAltDeque<T> {
+    deque: AltDeque<T>,
+    limit: usize,
+}
+
+impl<T> BoundedAltDeque<T> {
+    /// Creates an empty deque that holds at most `limit` elements. Does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let deque: BoundedAltDeque<i32> = BoundedAltDeque::new(10);
+    /// assert_eq!(deque.limit(), 10);
+    /// assert_eq!(deque.capacity(), 0);
+    /// ```
+    pub fn new(limit: usize) -> Self {
+        Self { deque: AltDeque::new(), limit }
+    }
+
+    /// Returns the maximum number of elements the deque can hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let deque: BoundedAltDeque<i32> = BoundedAltDeque::new(10);
+    /// assert_eq!(deque.limit(), 10);
+    /// ```
+    #[inline]
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns the number of elements the deque can hold without reallocating. Unless `T` is
+    /// zero-sized, this is never more than the limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(3);
+    /// for i in 0..3 {
+    ///     deque.push_back(i).unwrap();
+    /// }
+    /// assert_eq!(deque.capacity(), 3);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.deque.capacity()
+    }
+
+    /// Returns the number of elements in the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(3);
+    /// deque.push_back(1).unwrap();
+    /// assert_eq!(deque.len(), 1);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.deque.len()
+    }
+
+    /// Returns `true` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(3);
+    /// assert!(deque.is_empty());
+    /// deque.push_back(1).unwrap();
+    /// assert!(!deque.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.deque.is_empty()
+    }
+
+    /// Returns `true` if the deque holds as many elements as the limit allows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(1);
+    /// assert!(!deque.is_full());
+    /// deque.push_back(1).unwrap();
+    /// assert!(deque.is_full());
+    /// ```
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len() >= self.limit
+    }
+
+    /// Returns a pair of slices which contain, in order, the contents of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(3);
+    /// deque.push_back(1).unwrap();
+    /// deque.push_front(0).unwrap();
+    /// assert_eq!(deque.as_slices(), (&[0][..], &[1][..]));
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        self.deque.as_slices()
+    }
+
+    /// Returns a pair of mutable slices which contain, in order, the contents of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(3);
+    /// deque.push_back(1).unwrap();
+    /// deque.as_mut_slices().1[0] = 2;
+    /// assert_eq!(deque, [2]);
+    /// ```
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        self.deque.as_mut_slices()
+    }
+
+    /// Provides a reference to the element at the given index.
+    ///
+    /// Element at index 0 is the front of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(3);
+    /// deque.push_back(1).unwrap();
+    /// assert_eq!(deque.get(0), Some(&1));
+    /// assert_eq!(deque.get(1), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.deque.get(index)
+    }
+
+    /// Provides a mutable reference to the element at the given index.
+    ///
+    /// Element at index 0 is the front of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(3);
+    /// deque.push_back(1).unwrap();
+    /// *deque.get_mut(0).unwrap() = 2;
+    /// assert_eq!(deque, [2]);
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.deque.get_mut(index)
+    }
+
+    /// Provides a reference to the front element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(3);
+    /// deque.push_back(1).unwrap();
+    /// deque.push_back(2).unwrap();
+    /// assert_eq!(deque.front(), Some(&1));
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        self.deque.front()
+    }
+
+    /// Provides a reference to the back element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(3);
+    /// deque.push_back(1).unwrap();
+    /// deque.push_back(2).unwrap();
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    pub fn back(&self) -> Option<&T> {
+        self.deque.back()
+    }
+
+    /// Removes the first element and returns it, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(3);
+    /// deque.push_back(1).unwrap();
+    /// assert_eq!(deque.pop_front(), Some(1));
+    /// assert_eq!(deque.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.deque.pop_front()
+    }
+
+    /// Removes the last element and returns it, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(3);
+    /// deque.push_back(1).unwrap();
+    /// assert_eq!(deque.pop_back(), Some(1));
+    /// assert_eq!(deque.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.deque.pop_back()
+    }
+
+    /// Prepends an element to the front of the deque, or returns it in an `Err` if the deque is
+    /// full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(1);
+    /// assert_eq!(deque.push_front(1), Ok(()));
+    /// assert_eq!(deque.push_front(2), Err(2));
+    /// ```
+    pub fn push_front(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        self.grow_for_push();
+        self.deque.push_front(value);
+        Ok(())
+    }
+
+    /// Appends an element to the back of the deque, or returns it in an `Err` if the deque is
+    /// full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(1);
+    /// assert_eq!(deque.push_back(1), Ok(()));
+    /// assert_eq!(deque.push_back(2), Err(2));
+    /// ```
+    pub fn push_back(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        self.grow_for_push();
+        self.deque.push_back(value);
+        Ok(())
+    }
+
+    /// Prepends an element to the front of the deque. If the deque is full, the back element
+    /// is removed first and returned.
+    ///
+    /// If the limit is zero, `value` itself is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(2);
+    /// assert_eq!(deque.push_front_overwrite(1), None);
+    /// assert_eq!(deque.push_front_overwrite(2), None);
+    /// assert_eq!(deque.push_front_overwrite(3), Some(1));
+    /// assert_eq!(deque, [3, 2]);
+    /// ```
+    pub fn push_front_overwrite(&mut self, value: T) -> Option<T> {
+        if self.limit == 0 {
+            return Some(value);
+        }
+        let evicted = if self.is_full() { self.deque.pop_back() } else { None };
+        self.grow_for_push();
+        self.deque.push_front(value);
+        evicted
+    }
+
+    /// Appends an element to the back of the deque. If the deque is full, the front element is
+    /// removed first and returned. This keeps the last `limit` pushed elements, like a history
+    /// buffer.
+    ///
+    /// If the limit is zero, `value` itself is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut history = BoundedAltDeque::new(3);
+    /// for event in 0..5 {
+    ///     history.push_back_overwrite(event);
+    /// }
+    /// assert_eq!(history, [2, 3, 4]);
+    /// assert_eq!(history.push_back_overwrite(5), Some(2));
+    /// ```
+    pub fn push_back_overwrite(&mut self, value: T) -> Option<T> {
+        if self.limit == 0 {
+            return Some(value);
+        }
+        let evicted = if self.is_full() { self.deque.pop_front() } else { None };
+        self.grow_for_push();
+        self.deque.push_back(value);
+        evicted
+    }
+
+    /// Shortens the deque, keeping the first `len` elements and dropping the rest.
+    ///
+    /// If `len` is greater than the deque's current length, this has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(3);
+    /// deque.push_back(1).unwrap();
+    /// deque.push_back(2).unwrap();
+    /// deque.truncate(1);
+    /// assert_eq!(deque, [1]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        self.deque.truncate(len);
+    }
+
+    /// Removes all elements from the deque. The limit stays the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(3);
+    /// deque.push_back(1).unwrap();
+    /// deque.clear();
+    /// assert!(deque.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.deque.clear();
+    }
+
+    /// Returns a front-to-back iterator over the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(3);
+    /// deque.push_back(1).unwrap();
+    /// deque.push_back(2).unwrap();
+    /// assert!(deque.iter().eq(&[1, 2]));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.deque.iter()
+    }
+
+    /// Returns a front-to-back iterator that returns mutable references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BoundedAltDeque;
+    /// let mut deque = BoundedAltDeque::new(3);
+    /// deque.push_back(1).unwrap();
+    /// deque.iter_mut().for_each(|x| *x += 1);
+    /// assert_eq!(deque, [2]);
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.deque.iter_mut()
+    }
+
+    /// Makes room for one more element without letting the capacity exceed the limit.
+    fn grow_for_push(&mut self) {
+        let len = self.len();
+        if len == self.capacity() {
+            let new_cap = cmp::min(cmp::max(len * 2, 4), self.limit);
+            self.deque.reserve_exact(new_cap - len);
+        }
+    }
+}
+
+impl<T: Clone> Clone for BoundedAltDeque<T> {
+    fn clone(&self) -> Self {
+        Self { deque: self.deque.clone(), limit: self.limit }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for BoundedAltDeque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T> From<BoundedAltDeque<T>> for AltDeque<T> {
+    /// Turns a `BoundedAltDeque` into an unbounded [`AltDeque`] without moving the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{AltDeque, BoundedAltDeque};
+    /// let mut deque = BoundedAltDeque::new(1);
+    /// deque.push_back(1).unwrap();
+    /// let mut deque = AltDeque::from(deque);
+    /// deque.push_back(2);
+    /// assert_eq!(deque, [1, 2]);
+    /// ```
+    fn from(deque: BoundedAltDeque<T>) -> Self {
+        deque.deque
+    }
+}
+
+impl<T: Hash> Hash for BoundedAltDeque<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.deque.hash(state);
+    }
+}
+
+impl<T> Index<usize> for BoundedAltDeque<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.deque[index]
+    }
+}
+
+impl<T> IndexMut<usize> for BoundedAltDeque<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.deque[index]
+    }
+}
+
+impl<'a, T> IntoIterator for &'a BoundedAltDeque<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut BoundedAltDeque<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: PartialEq> PartialEq for BoundedAltDeque<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.deque == other.deque
+    }
+}
+
+impl<T: Eq> Eq for BoundedAltDeque<T> {}
+
+__impl_slice_eq! { [] BoundedAltDeque<T>, Vec<U>, }
+__impl_slice_eq! { [] BoundedAltDeque<T>, &[U], }
+__impl_slice_eq! { [const N: usize] BoundedAltDeque<T>, [U; N], }
+__impl_slice_eq! { [const N: usize] BoundedAltDeque<T>, &[U; N], }
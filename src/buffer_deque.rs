@@ -0,0 +1,516 @@
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::{Index, IndexMut};
+
+use std::fmt;
+use std::mem::MaybeUninit;
+
+use crate::fixed_stacks::FixedStacks;
+use crate::{index_out_of_bounds, Iter, IterMut};
+
+/// A fixed-capacity deque over a buffer provided by the caller, which never allocates.
+///
+/// `BufferDeque` uses the same two-stack layout as [`AltDeque`] over a borrowed
+/// `&mut [MaybeUninit<T>]`, so the storage can be a static array, an array on the stack or
+/// memory that was set aside for DMA. Like [`ArrayAltDeque`], pushing into a full deque does
+/// not grow it, instead the value is handed back in an `Err`. The remaining elements are
+/// dropped together with the deque, the buffer is only borrowed.
+///
+/// [`AltDeque`]: crate::AltDeque
+/// [`ArrayAltDeque`]: crate::ArrayAltDeque
+///
+/// # Examples
+///
+/// ```
+/// # use altdeque::BufferDeque;
+/// # use std::mem::MaybeUninit;
+/// let mut buf = [MaybeUninit::uninit(); 2];
+/// let mut deque = BufferDeque::new(&mut buf);
+/// assert_eq!(deque.push_back(1), Ok(()));
+/// assert_eq!(deque.push_front(0), Ok(()));
+/// assert_eq!(deque.push_back(2), Err(2));
+/// assert_eq!(deque, [0, 1]);
+/// ```
+pub struct BufferDeque<'a, T> {
+    stacks: FixedStacks<T, &'a mut [MaybeUninit<T>]>,
+}
+
+impl<'a, T> BufferDeque<'a, T> {
+    /// Creates an empty deque that stores its elements in `buf`.
+    ///
+    /// The capacity of the deque is the length of `buf`. Whatever `buf` contains is ignored
+    /// and the elements left in the deque are dropped when the deque is, so `buf` only holds
+    /// stale bytes afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 8];
+    /// let deque = BufferDeque::new(&mut buf);
+    /// assert!(deque.is_empty());
+    /// ```
+    pub fn new(buf: &'a mut [MaybeUninit<T>]) -> Self {
+        Self { stacks: FixedStacks::new(buf) }
+    }
+
+    /// Returns the number of elements the deque can hold, which is the length of its buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 8];
+    /// let deque = BufferDeque::new(&mut buf);
+    /// assert_eq!(deque.capacity(), 8);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.stacks.capacity()
+    }
+
+    /// Returns the number of elements in the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 8];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// deque.push_back(1).unwrap();
+    /// assert_eq!(deque.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.stacks.len()
+    }
+
+    /// Returns wether the deque is empty or not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 8];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// assert!(deque.is_empty());
+    /// deque.push_back(42).unwrap();
+    /// assert!(!deque.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.stacks.is_empty()
+    }
+
+    /// Returns wether the deque is full or not. Pushing into a full deque fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 1];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// assert!(!deque.is_full());
+    /// deque.push_back(42).unwrap();
+    /// assert!(deque.is_full());
+    /// ```
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.stacks.is_full()
+    }
+
+    /// Returns a pair of slices which contain, in order, the contents of the deque. These are
+    /// equal the front stack and the back stack used internally.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 8];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// deque.push_back(1).unwrap();
+    /// deque.push_front(0).unwrap();
+    /// assert_eq!(deque.as_slices(), (&[0][..], &[1][..]));
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        self.stacks.as_slices()
+    }
+
+    /// Returns a mutable pair of slices which contain, in order, the contents of the deque.
+    ///
+    /// See the non-mutable version [`as_slices`] for details and examples.
+    ///
+    /// [`as_slices`]: BufferDeque::as_slices
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        self.stacks.as_mut_slices()
+    }
+
+    /// Provides a reference to the element at the given index.
+    ///
+    /// Element at index 0 is the front of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 8];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// deque.push_back(1).unwrap();
+    /// deque.push_back(2).unwrap();
+    /// assert_eq!(deque.get(1), Some(&2));
+    /// assert_eq!(deque.get(2), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.stacks.get(index)
+    }
+
+    /// Provides a mutable reference to the element at the given index.
+    ///
+    /// Element at index 0 is the front of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 8];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// deque.push_back(1).unwrap();
+    /// deque.push_back(2).unwrap();
+    /// *deque.get_mut(1).unwrap() += 40;
+    /// assert_eq!(deque.get(1), Some(&42));
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.stacks.get_mut(index)
+    }
+
+    /// Provides a reference to the front element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 8];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// assert_eq!(deque.front(), None);
+    /// deque.push_back(1).unwrap();
+    /// deque.push_back(2).unwrap();
+    /// assert_eq!(deque.front(), Some(&1));
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Provides a mutable reference to the front element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 8];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// assert_eq!(deque.front_mut(), None);
+    /// deque.push_back(1).unwrap();
+    /// *deque.front_mut().unwrap() += 10;
+    /// assert_eq!(deque.front(), Some(&11));
+    /// ```
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Provides a reference to the back element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 8];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// assert_eq!(deque.back(), None);
+    /// deque.push_back(1).unwrap();
+    /// deque.push_back(2).unwrap();
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len().wrapping_sub(1))
+    }
+
+    /// Provides a mutable reference to the back element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 8];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// assert_eq!(deque.back_mut(), None);
+    /// deque.push_back(1).unwrap();
+    /// *deque.back_mut().unwrap() += 10;
+    /// assert_eq!(deque.back(), Some(&11));
+    /// ```
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len().wrapping_sub(1))
+    }
+
+    /// Removes the first element and returns it, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 8];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// deque.push_back(1).unwrap();
+    /// deque.push_back(2).unwrap();
+    /// assert_eq!(deque.pop_front(), Some(1));
+    /// assert_eq!(deque.pop_front(), Some(2));
+    /// assert_eq!(deque.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.stacks.pop_front()
+    }
+
+    /// Removes the last element from the deque and returns it, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 8];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// deque.push_back(1).unwrap();
+    /// deque.push_back(2).unwrap();
+    /// assert_eq!(deque.pop_back(), Some(2));
+    /// assert_eq!(deque.pop_back(), Some(1));
+    /// assert_eq!(deque.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.stacks.pop_back()
+    }
+
+    /// Prepends an element to the front of the deque.
+    ///
+    /// # Errors
+    ///
+    /// If the deque is full, the element is returned back in the `Err` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 2];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// assert_eq!(deque.push_front(1), Ok(()));
+    /// assert_eq!(deque.push_front(2), Ok(()));
+    /// assert_eq!(deque.push_front(3), Err(3));
+    /// assert_eq!(deque, [2, 1]);
+    /// ```
+    pub fn push_front(&mut self, value: T) -> Result<(), T> {
+        self.stacks.push_front(value)
+    }
+
+    /// Appends an element to the back of the deque.
+    ///
+    /// # Errors
+    ///
+    /// If the deque is full, the element is returned back in the `Err` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 2];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// assert_eq!(deque.push_back(1), Ok(()));
+    /// assert_eq!(deque.push_back(2), Ok(()));
+    /// assert_eq!(deque.push_back(3), Err(3));
+    /// assert_eq!(deque, [1, 2]);
+    /// ```
+    pub fn push_back(&mut self, value: T) -> Result<(), T> {
+        self.stacks.push_back(value)
+    }
+
+    /// Shortens the deque, keeping the first `len` elements and dropping the rest.
+    ///
+    /// If `len` is greater than the deque's current length, this is a no-op.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 8];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// deque.push_back(1).unwrap();
+    /// deque.push_back(2).unwrap();
+    /// deque.push_back(3).unwrap();
+    /// deque.truncate(1);
+    /// assert_eq!(deque, [1]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        self.stacks.truncate(len);
+    }
+
+    /// Clears the deque, removing all elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 8];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// deque.push_back(1).unwrap();
+    /// deque.clear();
+    /// assert!(deque.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Reverses the order of the elements in the deque, in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 4];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// deque.push_back(2).unwrap();
+    /// deque.push_back(3).unwrap();
+    /// deque.push_front(1).unwrap();
+    /// deque.reverse();
+    /// assert_eq!(deque, [3, 2, 1]);
+    /// ```
+    pub fn reverse(&mut self) {
+        self.stacks.reverse();
+    }
+
+    /// Returns a front-to-back iterator over the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 8];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// deque.push_back(2).unwrap();
+    /// deque.push_front(1).unwrap();
+    /// assert_eq!(deque.iter().collect::<Vec<_>>(), [&1, &2]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.stacks.iter()
+    }
+
+    /// Returns a front-to-back iterator over the deque that returns mutable references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::BufferDeque;
+    /// # use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<i32>::uninit(); 8];
+    /// let mut deque = BufferDeque::new(&mut buf);
+    /// deque.push_back(2).unwrap();
+    /// deque.push_front(1).unwrap();
+    /// deque.iter_mut().for_each(|el| *el += 10);
+    /// assert_eq!(deque, [11, 12]);
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.stacks.iter_mut()
+    }
+
+}
+
+impl<T: fmt::Debug> fmt::Debug for BufferDeque<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T: Hash> Hash for BufferDeque<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        self.iter().for_each(|elem| elem.hash(state));
+    }
+}
+
+impl<T> Index<usize> for BufferDeque<'_, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).unwrap_or_else(|| index_out_of_bounds(self.len(), index))
+    }
+}
+
+impl<T> IndexMut<usize> for BufferDeque<'_, T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len();
+        self.get_mut(index).unwrap_or_else(|| index_out_of_bounds(len, index))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a BufferDeque<'_, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut BufferDeque<'_, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for BufferDeque<'_, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for BufferDeque<'_, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: PartialEq> PartialEq for BufferDeque<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for BufferDeque<'_, T> {}
+
+__impl_slice_eq! { [] BufferDeque<'_, T>, Vec<U>, }
+__impl_slice_eq! { [] BufferDeque<'_, T>, &[U], }
+__impl_slice_eq! { [] BufferDeque<'_, T>, &mut [U], }
+__impl_slice_eq! { [const M: usize] BufferDeque<'_, T>, [U; M], }
+__impl_slice_eq! { [const M: usize] BufferDeque<'_, T>, &[U; M], }
+__impl_slice_eq! { [const M: usize] BufferDeque<'_, T>, &mut [U; M], }
+__impl_slice_ord! { [] BufferDeque<'_, T>, Vec<U>, }
+__impl_slice_ord! { [] BufferDeque<'_, T>, &[U], }
+__impl_slice_ord! { [const M: usize] BufferDeque<'_, T>, [U; M], }
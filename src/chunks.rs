@@ -0,0 +1,317 @@
+use std::array;
+use std::borrow::Cow;
+use std::fmt;
+use std::iter::FusedIterator;
+#[cfg(feature = "nightly")]
+use std::iter::TrustedLen;
+use std::mem;
+
+/// An iterator over an `AltDeque` in chunks of `chunk_size` elements, starting at the front.
+///
+/// Every chunk is returned as a pair of slices, like [`as_slices`] returns the whole deque.
+/// Only the chunk that spans the boundary between the two internal stacks has two non-empty
+/// parts, all other chunks are in the first slice and the second one is empty. If the length
+/// of the deque is not divisible by `chunk_size`, the last chunk is shorter.
+///
+/// This struct is created by the [`chunks`] method on [`AltDeque`].
+///
+/// [`AltDeque`]: crate::AltDeque
+/// [`as_slices`]: crate::AltDeque::as_slices
+/// [`chunks`]: crate::AltDeque::chunks
+#[derive(Clone, Debug)]
+pub struct Chunks<'a, T> {
+    front: &'a [T],
+    back: &'a [T],
+    chunk_size: usize,
+}
+
+impl<'a, T> Chunks<'a, T> {
+    pub(crate) fn new(front: &'a [T], back: &'a [T], chunk_size: usize) -> Self {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        Self { front, back, chunk_size }
+    }
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = (&'a [T], &'a [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.front.len() + self.back.len();
+        if len == 0 {
+            return None;
+        }
+        Some(take_front(&mut self.front, &mut self.back, self.chunk_size.min(len)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = chunk_count(self.front.len() + self.back.len(), self.chunk_size);
+        (count, Some(count))
+    }
+}
+
+impl<T> DoubleEndedIterator for Chunks<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = self.front.len() + self.back.len();
+        if len == 0 {
+            return None;
+        }
+        Some(take_back(&mut self.front, &mut self.back, last_chunk_len(len, self.chunk_size)))
+    }
+}
+
+impl<T> ExactSizeIterator for Chunks<'_, T> {}
+
+impl<T> FusedIterator for Chunks<'_, T> {}
+
+// SAFETY: size_hint returns the exact number of remaining items
+#[cfg(feature = "nightly")]
+unsafe impl<T> TrustedLen for Chunks<'_, T> {}
+
+/// An iterator over an `AltDeque` in chunks of `chunk_size` elements, starting at the back.
+///
+/// The chunks are returned as pairs of slices like by [`Chunks`]. If the length of the deque is
+/// not divisible by `chunk_size`, the last chunk, which holds the front of the deque, is
+/// shorter.
+///
+/// This struct is created by the [`rchunks`] method on [`AltDeque`].
+///
+/// [`AltDeque`]: crate::AltDeque
+/// [`rchunks`]: crate::AltDeque::rchunks
+#[derive(Clone, Debug)]
+pub struct RChunks<'a, T> {
+    front: &'a [T],
+    back: &'a [T],
+    chunk_size: usize,
+}
+
+impl<'a, T> RChunks<'a, T> {
+    pub(crate) fn new(front: &'a [T], back: &'a [T], chunk_size: usize) -> Self {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        Self { front, back, chunk_size }
+    }
+}
+
+impl<'a, T> Iterator for RChunks<'a, T> {
+    type Item = (&'a [T], &'a [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.front.len() + self.back.len();
+        if len == 0 {
+            return None;
+        }
+        Some(take_back(&mut self.front, &mut self.back, self.chunk_size.min(len)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = chunk_count(self.front.len() + self.back.len(), self.chunk_size);
+        (count, Some(count))
+    }
+}
+
+impl<T> DoubleEndedIterator for RChunks<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = self.front.len() + self.back.len();
+        if len == 0 {
+            return None;
+        }
+        Some(take_front(&mut self.front, &mut self.back, last_chunk_len(len, self.chunk_size)))
+    }
+}
+
+impl<T> ExactSizeIterator for RChunks<'_, T> {}
+
+impl<T> FusedIterator for RChunks<'_, T> {}
+
+// SAFETY: size_hint returns the exact number of remaining items
+#[cfg(feature = "nightly")]
+unsafe impl<T> TrustedLen for RChunks<'_, T> {}
+
+/// An iterator over an `AltDeque` in arrays of `N` elements, starting at the front.
+///
+/// Chunks that lie in one of the two internal stacks are borrowed, the one chunk that spans the
+/// boundary between them is copied into an array. If the length of the deque is not divisible
+/// by `N`, the last up to `N - 1` elements are not yielded.
+///
+/// This struct is created by the [`array_chunks`] method on [`AltDeque`].
+///
+/// [`AltDeque`]: crate::AltDeque
+/// [`array_chunks`]: crate::AltDeque::array_chunks
+#[derive(Clone, Debug)]
+pub struct ArrayChunks<'a, T, const N: usize> {
+    inner: Chunks<'a, T>,
+}
+
+impl<'a, T, const N: usize> ArrayChunks<'a, T, N> {
+    pub(crate) fn new(mut front: &'a [T], mut back: &'a [T]) -> Self {
+        assert!(N != 0, "chunk size must be non-zero");
+        let rem = (front.len() + back.len()) % N;
+        take_back(&mut front, &mut back, rem);
+        Self { inner: Chunks::new(front, back, N) }
+    }
+}
+
+impl<'a, T: Copy, const N: usize> Iterator for ArrayChunks<'a, T, N> {
+    type Item = Cow<'a, [T; N]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(to_array)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T: Copy, const N: usize> DoubleEndedIterator for ArrayChunks<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(to_array)
+    }
+}
+
+impl<T: Copy, const N: usize> ExactSizeIterator for ArrayChunks<'_, T, N> {}
+
+impl<T: Copy, const N: usize> FusedIterator for ArrayChunks<'_, T, N> {}
+
+// SAFETY: size_hint returns the exact number of remaining items
+#[cfg(feature = "nightly")]
+unsafe impl<T: Copy, const N: usize> TrustedLen for ArrayChunks<'_, T, N> {}
+
+/// An iterator over the runs of an `AltDeque` in which each pair of neighbouring elements
+/// satisfies a predicate.
+///
+/// The runs are returned as pairs of slices like by [`Chunks`]. Every run is as long as
+/// possible, so the predicate is false for the last element of a run and the first element of
+/// the next one.
+///
+/// This struct is created by the [`chunk_by`] method on [`AltDeque`].
+///
+/// [`AltDeque`]: crate::AltDeque
+/// [`chunk_by`]: crate::AltDeque::chunk_by
+pub struct ChunkBy<'a, T, P> {
+    front: &'a [T],
+    back: &'a [T],
+    pred: P,
+}
+
+impl<'a, T, P: FnMut(&T, &T) -> bool> ChunkBy<'a, T, P> {
+    pub(crate) fn new(front: &'a [T], back: &'a [T], pred: P) -> Self {
+        Self { front, back, pred }
+    }
+}
+
+impl<'a, T, P: FnMut(&T, &T) -> bool> Iterator for ChunkBy<'a, T, P> {
+    type Item = (&'a [T], &'a [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.front.len() + self.back.len();
+        if len == 0 {
+            return None;
+        }
+        let (front, back) = (self.front, self.back);
+        let mut run = 1;
+        while run < len && (self.pred)(get(front, back, run - 1), get(front, back, run)) {
+            run += 1;
+        }
+        Some(take_front(&mut self.front, &mut self.back, run))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (usize::from(len != 0), Some(len))
+    }
+}
+
+impl<T, P: FnMut(&T, &T) -> bool> DoubleEndedIterator for ChunkBy<'_, T, P> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = self.front.len() + self.back.len();
+        if len == 0 {
+            return None;
+        }
+        let (front, back) = (self.front, self.back);
+        let mut run = 1;
+        while run < len && (self.pred)(get(front, back, len - run - 1), get(front, back, len - run)) {
+            run += 1;
+        }
+        Some(take_back(&mut self.front, &mut self.back, run))
+    }
+}
+
+impl<T, P: FnMut(&T, &T) -> bool> FusedIterator for ChunkBy<'_, T, P> {}
+
+impl<T: fmt::Debug, P> fmt::Debug for ChunkBy<'_, T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkBy").field("front", &self.front).field("back", &self.back).finish()
+    }
+}
+
+/// Turns a pair of slices with a combined length of `N` into an array, which is borrowed if
+/// the second slice is empty.
+pub(crate) fn to_array<'a, T: Copy, const N: usize>(
+    (a, b): (&'a [T], &'a [T]),
+) -> Cow<'a, [T; N]> {
+    match a.try_into() {
+        Ok(array) => Cow::Borrowed(array),
+        Err(_) => Cow::Owned(array::from_fn(|i| if i < a.len() { a[i] } else { b[i - a.len()] })),
+    }
+}
+
+fn chunk_count(len: usize, chunk_size: usize) -> usize {
+    len.div_ceil(chunk_size)
+}
+
+/// Returns the length of the chunk that is yielded last, which is the only one that may be
+/// shorter than `chunk_size`. `len` must not be zero.
+fn last_chunk_len(len: usize, chunk_size: usize) -> usize {
+    match len % chunk_size {
+        0 => chunk_size,
+        rem => rem,
+    }
+}
+
+/// Returns the element at `index` of the two slices joined together.
+fn get<'a, T>(front: &'a [T], back: &'a [T], index: usize) -> &'a T {
+    match index.checked_sub(front.len()) {
+        None => &front[index],
+        Some(index) => &back[index],
+    }
+}
+
+/// Splits the first `count` elements off the two slices. `count` must not exceed their
+/// combined length.
+pub(crate) fn take_front<'a, T>(
+    front: &mut &'a [T],
+    back: &mut &'a [T],
+    count: usize,
+) -> (&'a [T], &'a [T]) {
+    if count <= front.len() {
+        let (chunk, rest) = front.split_at(count);
+        *front = rest;
+        (chunk, &[])
+    } else if front.is_empty() {
+        let (chunk, rest) = back.split_at(count);
+        *back = rest;
+        (chunk, &[])
+    } else {
+        let (chunk, rest) = back.split_at(count - front.len());
+        *back = rest;
+        (mem::take(front), chunk)
+    }
+}
+
+/// Splits the last `count` elements off the two slices. `count` must not exceed their combined
+/// length.
+fn take_back<'a, T>(front: &mut &'a [T], back: &mut &'a [T], count: usize) -> (&'a [T], &'a [T]) {
+    if count <= back.len() {
+        let (rest, chunk) = back.split_at(back.len() - count);
+        *back = rest;
+        (chunk, &[])
+    } else if back.is_empty() {
+        let (rest, chunk) = front.split_at(front.len() - count);
+        *front = rest;
+        (chunk, &[])
+    } else {
+        let (rest, chunk) = front.split_at(front.len() - (count - back.len()));
+        *front = rest;
+        (chunk, mem::take(back))
+    }
+}
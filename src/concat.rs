@@ -0,0 +1,66 @@
+use crate::AltDeque;
+
+/// A collection whose elements [`AltDeque::concat_into`] can add to the back of a deque.
+///
+/// Owned deques and vectors are moved with bulk copies, slices are cloned element by element.
+/// No item needs an allocation of its own.
+///
+/// # Examples
+///
+/// ```
+/// use altdeque::{AltDeque, ConcatSource};
+///
+/// fn total_len<T, S: ConcatSource<T>>(parts: &[S]) -> usize {
+///     parts.iter().map(ConcatSource::len).sum()
+/// }
+///
+/// let parts: [&[i32]; 2] = [&[1, 2], &[3]];
+/// assert_eq!(total_len(&parts), 3);
+///
+/// let mut deque = AltDeque::new();
+/// deque.concat_into(parts);
+/// assert_eq!(deque, [1, 2, 3]);
+/// ```
+pub trait ConcatSource<T> {
+    /// Returns the number of elements that will be added.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no elements to add.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds all elements to the back of `deque`, in order.
+    fn append_to(self, deque: &mut AltDeque<T>);
+}
+
+impl<T> ConcatSource<T> for AltDeque<T> {
+    fn len(&self) -> usize {
+        AltDeque::len(self)
+    }
+
+    fn append_to(mut self, deque: &mut AltDeque<T>) {
+        deque.append(&mut self);
+    }
+}
+
+impl<T> ConcatSource<T> for Vec<T> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn append_to(self, deque: &mut AltDeque<T>) {
+        // turning a Vec into a deque does not allocate
+        deque.append(&mut AltDeque::from(self));
+    }
+}
+
+impl<T: Clone> ConcatSource<T> for &[T] {
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn append_to(self, deque: &mut AltDeque<T>) {
+        deque.extend_from_slice(self);
+    }
+}
@@ -0,0 +1,165 @@
+//! An unbounded multi-producer/multi-consumer queue with one lock per end.
+//!
+//! [`TwoLockQueue`] is the classic two-lock queue built from two stacks. Producers only lock
+//! the back stack and consumers only lock the front stack, so a push and a pop never wait for
+//! each other. Only when the front stack runs dry, the consumer that found it empty also locks
+//! the back stack and takes all of its elements at once. The two stacks are separate `Vec`s
+//! like in a [`DualStackDeque`], because each of them needs its own lock.
+//!
+//! [`DualStackDeque`]: crate::DualStackDeque
+//!
+//! # Examples
+//!
+//! ```
+//! use altdeque::concurrent::TwoLockQueue;
+//! use std::sync::Arc;
+//! use std::thread;
+//!
+//! let queue = Arc::new(TwoLockQueue::new());
+//! let producer = {
+//!     let queue = Arc::clone(&queue);
+//!     thread::spawn(move || {
+//!         for i in 0..100 {
+//!             queue.push(i);
+//!         }
+//!     })
+//! };
+//! let mut received = Vec::new();
+//! while received.len() < 100 {
+//!     received.extend(queue.pop());
+//! }
+//! producer.join().unwrap();
+//! assert_eq!(received, (0..100).collect::<Vec<_>>());
+//! ```
+
+use std::fmt;
+use std::mem;
+use std::sync::PoisonError;
+
+use crate::loom::{Mutex, MutexGuard};
+
+/// An unbounded FIFO queue whose producers and consumers lock different ends.
+///
+/// See the [module documentation](self) for more.
+pub struct TwoLockQueue<T> {
+    // the front stack holds the oldest elements in reverse order, so its last one is popped next
+    front: Mutex<Vec<T>>,
+    back: Mutex<Vec<T>>,
+}
+
+impl<T> TwoLockQueue<T> {
+    /// Creates an empty queue. Does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::concurrent::TwoLockQueue;
+    /// let queue: TwoLockQueue<i32> = TwoLockQueue::new();
+    /// assert!(queue.is_empty());
+    /// ```
+    #[cfg(not(loom))]
+    pub const fn new() -> Self {
+        Self { front: Mutex::new(Vec::new()), back: Mutex::new(Vec::new()) }
+    }
+
+    /// Creates an empty queue. Loom's mutexes cannot be created in a const context.
+    #[cfg(loom)]
+    pub fn new() -> Self {
+        Self { front: Mutex::new(Vec::new()), back: Mutex::new(Vec::new()) }
+    }
+
+    /// Returns the number of elements in the queue. Other threads may change it at any time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::concurrent::TwoLockQueue;
+    /// let queue = TwoLockQueue::new();
+    /// queue.push(1);
+    /// assert_eq!(queue.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        // the front is locked first, like in pop
+        let front = lock(&self.front);
+        front.len() + lock(&self.back).len()
+    }
+
+    /// Returns `true` if the queue is empty. Other threads may change it at any time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::concurrent::TwoLockQueue;
+    /// let queue = TwoLockQueue::new();
+    /// assert!(queue.is_empty());
+    /// queue.push(1);
+    /// assert!(!queue.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends an element to the back of the queue. Only locks the back stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::concurrent::TwoLockQueue;
+    /// let queue = TwoLockQueue::new();
+    /// queue.push(1);
+    /// assert_eq!(queue.pop(), Some(1));
+    /// ```
+    pub fn push(&self, value: T) {
+        lock(&self.back).push(value);
+    }
+
+    /// Removes the first element and returns it, or `None` if the queue is empty.
+    ///
+    /// Usually this only locks the front stack. If it is empty, the back stack is locked as
+    /// well and all its elements are moved to the front stack, which takes *O(n)* time but
+    /// only happens once for every element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::concurrent::TwoLockQueue;
+    /// let queue = TwoLockQueue::new();
+    /// queue.push(1);
+    /// queue.push(2);
+    /// assert_eq!(queue.pop(), Some(1));
+    /// assert_eq!(queue.pop(), Some(2));
+    /// assert_eq!(queue.pop(), None);
+    /// ```
+    pub fn pop(&self) -> Option<T> {
+        let mut front = lock(&self.front);
+        if front.is_empty() {
+            // take the whole back stack and leave the empty vec with its capacity to the
+            // producers, the back lock is only held for the swap
+            mem::swap(&mut *front, &mut *lock(&self.back));
+            front.reverse();
+        }
+        front.pop()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for TwoLockQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let front = lock(&self.front);
+        let back = lock(&self.back);
+        f.debug_list().entries(front.iter().rev().chain(back.iter())).finish()
+    }
+}
+
+impl<T> Default for TwoLockQueue<T> {
+    /// Creates an empty queue.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Locks a stack. Pushing and popping cannot leave a stack in an inconsistent state, so a
+/// poisoned lock is ignored.
+fn lock<T>(stack: &Mutex<Vec<T>>) -> MutexGuard<'_, Vec<T>> {
+    stack.lock().unwrap_or_else(PoisonError::into_inner)
+}
@@ -0,0 +1,285 @@
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::{AltDeque, Iter};
+
+/// A copy-on-write [`AltDeque`] behind an [`Arc`], whose clones are cheap snapshots.
+///
+/// Cloning a `CowAltDeque` only increments a reference count, no matter how many elements it
+/// holds, so the current state of a queue can be handed to a reader thread in *O(1)* time. The
+/// first mutation through a clone that is still shared copies the deque, every later one
+/// works on that copy in place. The snapshots that were handed out never change.
+///
+/// The deque derefs to [`AltDeque`] for all read-only methods. Mutation goes through
+/// [`make_mut`] or the push and pop shortcuts, which need `T: Clone` for the copy.
+///
+/// [`make_mut`]: CowAltDeque::make_mut
+///
+/// # Examples
+///
+/// ```
+/// # use altdeque::CowAltDeque;
+/// let mut deque: CowAltDeque<_> = (0..1000).collect();
+/// let snapshot = deque.clone();
+/// assert!(CowAltDeque::ptr_eq(&deque, &snapshot));
+///
+/// deque.push_back(1000);
+/// assert!(!CowAltDeque::ptr_eq(&deque, &snapshot));
+/// assert_eq!(deque.len(), 1001);
+/// assert_eq!(snapshot.len(), 1000);
+/// ```
+pub struct CowAltDeque<T> {
+    inner: Arc<AltDeque<T>>,
+}
+
+impl<T> CowAltDeque<T> {
+    /// Creates an empty deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::CowAltDeque;
+    /// let deque: CowAltDeque<i32> = CowAltDeque::new();
+    /// assert!(deque.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self { inner: Arc::new(AltDeque::new()) }
+    }
+
+    /// Returns `true` if no other clone shares the deque, so that mutating it does not copy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::CowAltDeque;
+    /// let deque: CowAltDeque<i32> = CowAltDeque::new();
+    /// assert!(deque.is_unique());
+    /// let snapshot = deque.clone();
+    /// assert!(!deque.is_unique());
+    /// drop(snapshot);
+    /// assert!(deque.is_unique());
+    /// ```
+    pub fn is_unique(&self) -> bool {
+        Arc::strong_count(&self.inner) == 1
+    }
+
+    /// Returns `true` if both deques share the same buffer, like [`Arc::ptr_eq`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::CowAltDeque;
+    /// let deque: CowAltDeque<_> = [1, 2].into_iter().collect();
+    /// let snapshot = deque.clone();
+    /// let equal: CowAltDeque<_> = [1, 2].into_iter().collect();
+    /// assert!(CowAltDeque::ptr_eq(&deque, &snapshot));
+    /// assert!(!CowAltDeque::ptr_eq(&deque, &equal));
+    /// ```
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.inner, &other.inner)
+    }
+
+    /// Removes all elements. A shared deque is not copied, this one just lets go of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::CowAltDeque;
+    /// let mut deque: CowAltDeque<_> = [1, 2].into_iter().collect();
+    /// let snapshot = deque.clone();
+    /// deque.clear();
+    /// assert!(deque.is_empty());
+    /// assert_eq!(*snapshot, [1, 2]);
+    /// ```
+    pub fn clear(&mut self) {
+        match Arc::get_mut(&mut self.inner) {
+            Some(inner) => inner.clear(),
+            None => *self = Self::new(),
+        }
+    }
+}
+
+impl<T: Clone> CowAltDeque<T> {
+    /// Returns a mutable reference to the deque, which is copied first if it is shared.
+    ///
+    /// The copy takes *O(n)* time and leaves the other clones untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::CowAltDeque;
+    /// let mut deque: CowAltDeque<_> = [1, 2, 3].into_iter().collect();
+    /// let snapshot = deque.clone();
+    /// deque.make_mut().retain(|&x| x != 2);
+    /// assert_eq!(*deque, [1, 3]);
+    /// assert_eq!(*snapshot, [1, 2, 3]);
+    /// ```
+    pub fn make_mut(&mut self) -> &mut AltDeque<T> {
+        Arc::make_mut(&mut self.inner)
+    }
+
+    /// Turns the deque into an [`AltDeque`], which copies it only if it is shared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{AltDeque, CowAltDeque};
+    /// let deque = CowAltDeque::from(AltDeque::from([1, 2]));
+    /// assert_eq!(deque.into_inner(), [1, 2]);
+    /// ```
+    pub fn into_inner(self) -> AltDeque<T> {
+        Arc::try_unwrap(self.inner).unwrap_or_else(|inner| (*inner).clone())
+    }
+
+    /// Prepends an element to the deque, which is copied first if it is shared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::CowAltDeque;
+    /// let mut deque = CowAltDeque::new();
+    /// deque.push_front(1);
+    /// deque.push_front(2);
+    /// assert_eq!(*deque, [2, 1]);
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        self.make_mut().push_front(value);
+    }
+
+    /// Appends an element to the back of the deque, which is copied first if it is shared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::CowAltDeque;
+    /// let mut deque = CowAltDeque::new();
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// assert_eq!(*deque, [1, 2]);
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        self.make_mut().push_back(value);
+    }
+
+    /// Removes the first element and returns it, or `None` if the deque is empty.
+    ///
+    /// A shared deque is copied first, unless it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::CowAltDeque;
+    /// let mut deque: CowAltDeque<_> = [1, 2].into_iter().collect();
+    /// let snapshot = deque.clone();
+    /// assert_eq!(deque.pop_front(), Some(1));
+    /// assert_eq!(*snapshot, [1, 2]);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.make_mut().pop_front()
+    }
+
+    /// Removes the last element and returns it, or `None` if the deque is empty.
+    ///
+    /// A shared deque is copied first, unless it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::CowAltDeque;
+    /// let mut deque: CowAltDeque<_> = [1, 2].into_iter().collect();
+    /// let snapshot = deque.clone();
+    /// assert_eq!(deque.pop_back(), Some(2));
+    /// assert_eq!(*snapshot, [1, 2]);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.make_mut().pop_back()
+    }
+}
+
+impl<T> AsRef<AltDeque<T>> for CowAltDeque<T> {
+    fn as_ref(&self) -> &AltDeque<T> {
+        &self.inner
+    }
+}
+
+impl<T> Clone for CowAltDeque<T> {
+    /// Returns a snapshot that shares the deque, which takes *O(1)* time.
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { inner: Arc::clone(&self.inner) }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for CowAltDeque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.inner, f)
+    }
+}
+
+impl<T> Default for CowAltDeque<T> {
+    /// Creates an empty deque.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for CowAltDeque<T> {
+    type Target = AltDeque<T>;
+
+    #[inline]
+    fn deref(&self) -> &AltDeque<T> {
+        &self.inner
+    }
+}
+
+impl<T: Clone> Extend<T> for CowAltDeque<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.make_mut().extend(iter);
+    }
+}
+
+impl<T> From<AltDeque<T>> for CowAltDeque<T> {
+    /// Moves the deque behind an [`Arc`] without copying its elements.
+    fn from(deque: AltDeque<T>) -> Self {
+        Self { inner: Arc::new(deque) }
+    }
+}
+
+impl<T> FromIterator<T> for CowAltDeque<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(AltDeque::from_iter(iter))
+    }
+}
+
+impl<T: Hash> Hash for CowAltDeque<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+impl<'a, T> IntoIterator for &'a CowAltDeque<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl<T: PartialEq> PartialEq for CowAltDeque<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T: Eq> Eq for CowAltDeque<T> {}
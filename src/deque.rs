@@ -0,0 +1,115 @@
+#[cfg(feature = "vec-deque")]
+use std::collections::{vec_deque, VecDeque};
+
+/// The common operations of a double-ended queue, to be generic over its implementation.
+///
+/// Libraries can accept any `D: Deque<T>` instead of a concrete type, and applications can
+/// swap [`AltDeque`] for another deque, for example to compare them in a benchmark, without
+/// changing other code. The trait is implemented for all growable deques of this crate and,
+/// with the `vec-deque` feature, for the standard library's `VecDeque`.
+///
+/// The deques have inherent methods of the same names, which take precedence when the type is
+/// known, so the trait only needs to be in scope for generic code.
+///
+/// [`AltDeque`]: crate::AltDeque
+///
+/// # Examples
+///
+/// ```
+/// use altdeque::{AltDeque, Deque, DualStackDeque};
+///
+/// fn rotate<D: Deque<i32>>(deque: &mut D, steps: usize) {
+///     for _ in 0..steps {
+///         if let Some(x) = deque.pop_front() {
+///             deque.push_back(x);
+///         }
+///     }
+/// }
+///
+/// let mut alt = AltDeque::from([1, 2, 3]);
+/// let mut dual = DualStackDeque::from(vec![1, 2, 3]);
+/// rotate(&mut alt, 1);
+/// rotate(&mut dual, 1);
+/// assert!(alt.iter().eq(dual.iter()));
+/// assert_eq!(alt, [2, 3, 1]);
+/// ```
+pub trait Deque<T> {
+    /// A front-to-back iterator over references to the elements.
+    type Iter<'a>: DoubleEndedIterator<Item = &'a T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Returns the number of elements in the deque.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the deque is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the element at `index`, where index 0 is the front, or `None`
+    /// if it is out of bounds.
+    fn get(&self, index: usize) -> Option<&T>;
+
+    /// Returns a reference to the front element, or `None` if the deque is empty.
+    fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the back element, or `None` if the deque is empty.
+    fn back(&self) -> Option<&T> {
+        self.len().checked_sub(1).and_then(|index| self.get(index))
+    }
+
+    /// Prepends an element to the deque.
+    fn push_front(&mut self, value: T);
+
+    /// Appends an element to the back of the deque.
+    fn push_back(&mut self, value: T);
+
+    /// Removes the first element and returns it, or `None` if the deque is empty.
+    fn pop_front(&mut self) -> Option<T>;
+
+    /// Removes the last element and returns it, or `None` if the deque is empty.
+    fn pop_back(&mut self) -> Option<T>;
+
+    /// Returns a front-to-back iterator over the deque.
+    fn iter(&self) -> Self::Iter<'_>;
+}
+
+#[cfg(feature = "vec-deque")]
+impl<T> Deque<T> for VecDeque<T> {
+    type Iter<'a>
+        = vec_deque::Iter<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        VecDeque::get(self, index)
+    }
+
+    fn push_front(&mut self, value: T) {
+        VecDeque::push_front(self, value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        VecDeque::push_back(self, value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        VecDeque::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        VecDeque::pop_back(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        VecDeque::iter(self)
+    }
+}
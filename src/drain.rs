@@ -1,6 +1,14 @@
 use std::iter::FusedIterator;
+#[cfg(feature = "nightly")]
+use std::iter::TrustedLen;
+use std::mem;
 use std::ops::Range;
+#[cfg(feature = "futures")]
+use std::pin::Pin;
 use std::ptr;
+use std::slice;
+#[cfg(feature = "futures")]
+use std::task::{Context, Poll};
 use super::AltDeque;
 
 /// A draining iterator over the elements of an `AltDeque`.
@@ -12,98 +20,214 @@ use super::AltDeque;
 #[derive(Debug)]
 pub struct Drain<'a, T> {
     inner: &'a mut AltDeque<T>,
-    old_head: usize,
-    old_tail: usize,
-    // the original draining range, this is not modified
-    range: Range<usize>,
-    // the element that `.next()` returns
-    start: usize,
-    // the element after the one `.next_back()` return
-    end: usize,
+    // Buffer indices of the drained elements that are not yet yielded. The elements are already
+    // moved out of the stacks, `front` lies just before the front stack and `back` just after
+    // the back stack.
+    front: Range<usize>,
+    back: Range<usize>,
 }
 
 impl<'a, T> Drain<'a, T> {
-    pub(super) fn new(deque: &'a mut AltDeque<T>, old_head: usize, old_tail: usize, range: Range<usize>) -> Self {
-        let Range { start, end } = range;
-        Self { inner: deque, old_head, old_tail, range, start, end }
+    /// Moves the elements in `range` out of the stacks of `deque` and returns an iterator over
+    /// them. The deque only keeps the remaining elements, so leaking the iterator only leaks the
+    /// drained ones.
+    pub(super) fn new(deque: &'a mut AltDeque<T>, range: Range<usize>) -> Self {
+        let cap = deque.cap();
+        let front_len = cap - deque.tail;
+        let front_end = range.end.min(front_len);
+        let back_start = range.start.max(front_len) - front_len;
+
+        // the drained part of the front stack is rotated to the start of the front stack
+        let front = if range.start < front_len {
+            let tail = deque.tail;
+            let drained = front_end - range.start;
+            // SAFETY: the elements in [tail, tail + front_end) are initialized.
+            unsafe {
+                slice::from_raw_parts_mut(deque.buf_add(tail), front_end).rotate_left(range.start);
+            }
+            deque.tail += drained;
+            tail..tail + drained
+        } else {
+            0..0
+        };
+
+        // the drained part of the back stack is rotated to the end of the back stack
+        let back = if range.end > front_len {
+            let drained = range.end - front_len - back_start;
+            let len = deque.head - back_start;
+            // SAFETY: the elements in [back_start, head) are initialized.
+            unsafe {
+                slice::from_raw_parts_mut(deque.buf_add(back_start), len).rotate_left(drained);
+            }
+            deque.head -= drained;
+            deque.head..deque.head + drained
+        } else {
+            0..0
+        };
+
+        check_invariants!(deque);
+        Self { inner: deque, front, back }
     }
 }
 
 impl<T> Iterator for Drain<'_, T> {
     type Item = T;
-    
+
     fn next(&mut self) -> Option<T> {
-        if self.start < self.end {
-            let front_len = self.inner.cap() - self.old_tail;
-            let start = self.start;
-            self.start += 1;
-            if start < front_len {
-                unsafe { Some(ptr::read(self.inner.buf_add(start + self.old_tail))) }
-            } else {
-                unsafe { Some(ptr::read(self.inner.buf_add(start - front_len))) }
-            }
-        } else {
-            None
-        }
+        let idx = self.front.next().or_else(|| self.back.next())?;
+        // SAFETY: the element is initialized and not owned by the deque anymore.
+        unsafe { Some(ptr::read(self.inner.buf_add(idx))) }
     }
-    
+
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = self.end - self.start;
+        let len = self.front.len() + self.back.len();
         (len, Some(len))
     }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        let ptr = self.inner.buf.ptr();
+        // SAFETY: the elements in both ranges are initialized and not owned by the deque.
+        unsafe {
+            let skipped = drop_start(ptr, &mut self.front, n);
+            drop_start(ptr, &mut self.back, n - skipped);
+        }
+        self.next()
+    }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        let mut acc = init;
+        let ptr = self.inner.buf.ptr();
+        // the ranges are advanced before each read, so if f panics, Drop only drops the elements
+        // that were not yielded yet
+        for idx in self.front.by_ref() {
+            // SAFETY: the element is initialized and not owned by the deque anymore.
+            acc = f(acc, unsafe { ptr::read(ptr.add(idx)) });
+        }
+        for idx in self.back.by_ref() {
+            // SAFETY: the element is initialized and not owned by the deque anymore.
+            acc = f(acc, unsafe { ptr::read(ptr.add(idx)) });
+        }
+        acc
+    }
 }
 
 impl<T> DoubleEndedIterator for Drain<'_, T> {
     fn next_back(&mut self) -> Option<T> {
-        if self.start < self.end {
-            let front_len = self.inner.cap() - self.old_tail;
-            self.end -= 1;
-            if self.end < front_len {
-                unsafe { Some(ptr::read(self.inner.buf_add(self.end + self.old_tail))) }
-            } else {
-                unsafe { Some(ptr::read(self.inner.buf_add(self.end - front_len))) }
-            }
-        } else {
-            None
+        let idx = self.back.next_back().or_else(|| self.front.next_back())?;
+        // SAFETY: the element is initialized and not owned by the deque anymore.
+        unsafe { Some(ptr::read(self.inner.buf_add(idx))) }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        let ptr = self.inner.buf.ptr();
+        // SAFETY: the elements in both ranges are initialized and not owned by the deque.
+        unsafe {
+            let skipped = drop_end(ptr, &mut self.back, n);
+            drop_end(ptr, &mut self.front, n - skipped);
+        }
+        self.next_back()
+    }
+
+    fn rfold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        let mut acc = init;
+        let ptr = self.inner.buf.ptr();
+        for idx in self.back.by_ref().rev() {
+            // SAFETY: the element is initialized and not owned by the deque anymore.
+            acc = f(acc, unsafe { ptr::read(ptr.add(idx)) });
         }
+        for idx in self.front.by_ref().rev() {
+            // SAFETY: the element is initialized and not owned by the deque anymore.
+            acc = f(acc, unsafe { ptr::read(ptr.add(idx)) });
+        }
+        acc
     }
 }
 
 impl<T> Drop for Drain<'_, T> {
     fn drop(&mut self) {
-        while let Some(item) = self.next() {
-            drop(item);
-        }
-        
-        let front_len = self.inner.cap() - self.old_tail;
-        if self.range.start < front_len {
-            if self.range.end <= front_len {
-                let new_tail = self.inner.cap() - self.range.len();
-                unsafe {
-                    self.inner.copy(self.old_tail, new_tail, self.range.start);
-                }
-                self.inner.tail = new_tail;
-            } else {
-                let new_head = self.old_head - (self.range.end - front_len);
-                let new_tail = self.inner.cap() - self.range.start;
-                unsafe {
-                    self.inner.copy(self.old_tail, new_tail, self.range.start);
-                    self.inner.copy(self.range.end - front_len, 0, new_head);
-                }
-                self.inner.head = new_head;
-                self.inner.tail = new_tail;
-            }
-        } else {
-            unsafe {
-                let end = self.range.end - front_len;
-                let start = self.range.start - front_len;
-                self.inner.copy(end, start, self.old_head - end);
+        // Drops the back part even if dropping an element of the front part panics.
+        struct DropGuard<T>(*mut [T]);
+
+        impl<T> Drop for DropGuard<T> {
+            fn drop(&mut self) {
+                // SAFETY: see below.
+                unsafe { ptr::drop_in_place(self.0) }
             }
-            self.inner.head = self.old_head - self.range.len();
+        }
+
+        let front = mem::replace(&mut self.front, 0..0);
+        let back = mem::replace(&mut self.back, 0..0);
+        // SAFETY: the elements in both ranges are initialized, not yielded yet and not owned by
+        // the deque. The ranges are emptied above, so they are dropped only once.
+        unsafe {
+            let _guard = DropGuard(ptr::slice_from_raw_parts_mut(self.inner.buf_add(back.start), back.len()));
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.inner.buf_add(front.start), front.len()));
         }
     }
 }
 
+/// Drops up to `n` elements at the start of `range` at once and removes them from it. Returns
+/// the number of dropped elements.
+///
+/// # Safety
+///
+/// The elements at `ptr` in `range` must be initialized and owned by the caller.
+unsafe fn drop_start<T>(ptr: *mut T, range: &mut Range<usize>, n: usize) -> usize {
+    let count = n.min(range.len());
+    let start = range.start;
+    // the range is shrunk first, so the elements are not dropped again if dropping panics
+    range.start += count;
+    // SAFETY: guaranteed by the caller
+    unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr.add(start), count)) };
+    count
+}
+
+/// Drops up to `n` elements at the end of `range` at once and removes them from it. Returns the
+/// number of dropped elements.
+///
+/// # Safety
+///
+/// The elements at `ptr` in `range` must be initialized and owned by the caller.
+unsafe fn drop_end<T>(ptr: *mut T, range: &mut Range<usize>, n: usize) -> usize {
+    let count = n.min(range.len());
+    range.end -= count;
+    // SAFETY: guaranteed by the caller
+    unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr.add(range.end), count)) };
+    count
+}
+
 impl<T> ExactSizeIterator for Drain<'_, T> {}
 
 impl<T> FusedIterator for Drain<'_, T> {}
+
+// SAFETY: size_hint returns the exact number of remaining items
+#[cfg(feature = "nightly")]
+unsafe impl<T> TrustedLen for Drain<'_, T> {}
+
+/// The elements are always ready, so the stream never returns `Poll::Pending`. This allows to
+/// feed the elements of a deque that is kept around into a consumer of streams.
+#[cfg(feature = "futures")]
+impl<T> futures_core::Stream for Drain<'_, T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<T>> {
+        Poll::Ready(self.get_mut().next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Iterator::size_hint(self)
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T> futures_core::FusedStream for Drain<'_, T> {
+    fn is_terminated(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,549 @@
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::{Index, IndexMut};
+
+use std::fmt;
+use std::iter::{Chain, Rev};
+use std::mem;
+use std::slice;
+
+use crate::{index_out_of_bounds, Deque, ExtendFront};
+
+/// A deque made of two stacks that are separate [`Vec`]s.
+///
+/// `DualStackDeque` runs the same algorithm as [`AltDeque`], but the front and the back stack
+/// each have their own allocation instead of sharing one buffer. This costs the memory
+/// efficiency of a single buffer, in exchange:
+///
+/// - a [`Vec`] is taken over as the back stack without copying, see `From<Vec<T>>` and
+///   [`append_vec`], and the deque turns back into a [`Vec`] without allocating
+/// - each stack grows on its own, a long back stack is never moved because the front one grows
+/// - when a pop finds its stack empty, the other stack keeps its allocation for the half that is
+///   moved over and the spare capacity of the empty stack takes the rest
+///
+/// The front stack stores its elements in reverse order, its last element is the front of the
+/// deque. Hence the contents are not available as two slices in deque order.
+///
+/// [`AltDeque`]: crate::AltDeque
+/// [`append_vec`]: DualStackDeque::append_vec
+///
+/// # Examples
+///
+/// ```
+/// # use altdeque::DualStackDeque;
+/// let mut deque = DualStackDeque::from(vec![1, 2, 3]);
+/// deque.push_front(0);
+/// assert_eq!(deque.pop_back(), Some(3));
+/// deque.append_vec(vec![4, 5]);
+/// assert_eq!(Vec::from(deque), [0, 1, 2, 4, 5]);
+/// ```
+pub struct DualStackDeque<T> {
+    // Stored in reverse order, the last element is the front of the deque.
+    front: Vec<T>,
+    back: Vec<T>,
+}
+
+impl<T> DualStackDeque<T> {
+    /// Creates an empty deque. Does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::DualStackDeque;
+    /// let deque: DualStackDeque<i32> = DualStackDeque::new();
+    /// assert!(deque.is_empty());
+    /// ```
+    pub const fn new() -> Self {
+        Self { front: Vec::new(), back: Vec::new() }
+    }
+
+    /// Returns the number of elements in the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::DualStackDeque;
+    /// let mut deque = DualStackDeque::new();
+    /// deque.push_back(1);
+    /// deque.push_front(0);
+    /// assert_eq!(deque.len(), 2);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+
+    /// Returns `true` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::DualStackDeque;
+    /// let mut deque = DualStackDeque::new();
+    /// assert!(deque.is_empty());
+    /// deque.push_back(1);
+    /// assert!(!deque.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.front.is_empty() && self.back.is_empty()
+    }
+
+    /// Provides a reference to the element at the given index, or `None` if it is out of
+    /// bounds.
+    ///
+    /// Element at index 0 is the front of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::DualStackDeque;
+    /// let mut deque = DualStackDeque::from(vec![1, 2]);
+    /// deque.push_front(0);
+    /// assert_eq!(deque.get(0), Some(&0));
+    /// assert_eq!(deque.get(2), Some(&2));
+    /// assert_eq!(deque.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let front_len = self.front.len();
+        if index < front_len {
+            self.front.get(front_len - 1 - index)
+        } else {
+            self.back.get(index - front_len)
+        }
+    }
+
+    /// Provides a mutable reference to the element at the given index, or `None` if it is out
+    /// of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::DualStackDeque;
+    /// let mut deque = DualStackDeque::from(vec![1, 2]);
+    /// *deque.get_mut(1).unwrap() += 40;
+    /// assert_eq!(deque.get(1), Some(&42));
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let front_len = self.front.len();
+        if index < front_len {
+            self.front.get_mut(front_len - 1 - index)
+        } else {
+            self.back.get_mut(index - front_len)
+        }
+    }
+
+    /// Provides a reference to the front element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::DualStackDeque;
+    /// let deque = DualStackDeque::from(vec![1, 2]);
+    /// assert_eq!(deque.front(), Some(&1));
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        self.front.last().or_else(|| self.back.first())
+    }
+
+    /// Provides a mutable reference to the front element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::DualStackDeque;
+    /// let mut deque = DualStackDeque::from(vec![1, 2]);
+    /// *deque.front_mut().unwrap() += 10;
+    /// assert_eq!(deque.front(), Some(&11));
+    /// ```
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        match self.front.last_mut() {
+            Some(value) => Some(value),
+            None => self.back.first_mut(),
+        }
+    }
+
+    /// Provides a reference to the back element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::DualStackDeque;
+    /// let deque = DualStackDeque::from(vec![1, 2]);
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    pub fn back(&self) -> Option<&T> {
+        self.back.last().or_else(|| self.front.first())
+    }
+
+    /// Provides a mutable reference to the back element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::DualStackDeque;
+    /// let mut deque = DualStackDeque::from(vec![1, 2]);
+    /// *deque.back_mut().unwrap() += 10;
+    /// assert_eq!(deque.back(), Some(&12));
+    /// ```
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        match self.back.last_mut() {
+            Some(value) => Some(value),
+            None => self.front.first_mut(),
+        }
+    }
+
+    /// Prepends an element to the front of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::DualStackDeque;
+    /// let mut deque = DualStackDeque::new();
+    /// deque.push_front(2);
+    /// deque.push_front(1);
+    /// assert_eq!(deque.front(), Some(&1));
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        self.front.push(value);
+    }
+
+    /// Appends an element to the back of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::DualStackDeque;
+    /// let mut deque = DualStackDeque::new();
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        self.back.push(value);
+    }
+
+    /// Removes the first element and returns it, or `None` if the deque is empty.
+    ///
+    /// If the front stack is empty, the front half of the back stack is moved over first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::DualStackDeque;
+    /// let mut deque = DualStackDeque::from(vec![1, 2]);
+    /// assert_eq!(deque.pop_front(), Some(1));
+    /// assert_eq!(deque.pop_front(), Some(2));
+    /// assert_eq!(deque.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.front.is_empty() {
+            flip(&mut self.back, &mut self.front);
+        }
+        self.front.pop()
+    }
+
+    /// Removes the last element and returns it, or `None` if the deque is empty.
+    ///
+    /// If the back stack is empty, the back half of the front stack is moved over first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::DualStackDeque;
+    /// let mut deque = DualStackDeque::from(vec![1, 2]);
+    /// assert_eq!(deque.pop_back(), Some(2));
+    /// assert_eq!(deque.pop_back(), Some(1));
+    /// assert_eq!(deque.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.back.is_empty() {
+            flip(&mut self.front, &mut self.back);
+        }
+        self.back.pop()
+    }
+
+    /// Appends all elements of `vec` to the back of the deque.
+    ///
+    /// If the back stack is empty, `vec` becomes the new back stack without copying any
+    /// element, and the allocation of the old back stack is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::DualStackDeque;
+    /// let mut deque = DualStackDeque::new();
+    /// deque.push_front(0);
+    /// deque.append_vec(vec![1, 2]);
+    /// deque.append_vec(vec![3]);
+    /// assert_eq!(deque, [0, 1, 2, 3]);
+    /// ```
+    pub fn append_vec(&mut self, mut vec: Vec<T>) {
+        if self.back.is_empty() {
+            self.back = vec;
+        } else {
+            self.back.append(&mut vec);
+        }
+    }
+
+    /// Prepends all elements of `vec` to the front of the deque, keeping their order.
+    ///
+    /// If the front stack is empty, `vec` is reversed in place and becomes the new front stack,
+    /// so no allocation is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::DualStackDeque;
+    /// let mut deque = DualStackDeque::new();
+    /// deque.push_back(3);
+    /// deque.prepend_vec(vec![1, 2]);
+    /// deque.prepend_vec(vec![0]);
+    /// assert_eq!(deque, [0, 1, 2, 3]);
+    /// ```
+    pub fn prepend_vec(&mut self, mut vec: Vec<T>) {
+        if self.front.is_empty() {
+            vec.reverse();
+            self.front = vec;
+        } else {
+            self.front.extend(vec.into_iter().rev());
+        }
+    }
+
+    /// Removes all elements from the deque, keeping the allocations of both stacks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::DualStackDeque;
+    /// let mut deque = DualStackDeque::from(vec![1, 2]);
+    /// deque.clear();
+    /// assert!(deque.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.front.clear();
+        self.back.clear();
+    }
+
+    /// Returns a front-to-back iterator over the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::DualStackDeque;
+    /// let mut deque = DualStackDeque::from(vec![1, 2]);
+    /// deque.push_front(0);
+    /// assert!(deque.iter().eq(&[0, 1, 2]));
+    /// ```
+    pub fn iter(&self) -> Chain<Rev<slice::Iter<'_, T>>, slice::Iter<'_, T>> {
+        self.front.iter().rev().chain(self.back.iter())
+    }
+
+    /// Returns a front-to-back iterator over the deque that returns mutable references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::DualStackDeque;
+    /// let mut deque = DualStackDeque::from(vec![1, 2]);
+    /// deque.iter_mut().for_each(|x| *x *= 10);
+    /// assert_eq!(deque, [10, 20]);
+    /// ```
+    pub fn iter_mut(&mut self) -> Chain<Rev<slice::IterMut<'_, T>>, slice::IterMut<'_, T>> {
+        self.front.iter_mut().rev().chain(self.back.iter_mut())
+    }
+}
+
+/// Moves the bottom half of `from` over to the empty stack `to`, with the bottom element of
+/// `from` ending up on top of `to`.
+///
+/// `from` keeps its allocation for the elements that end up in `to`, which are then reversed
+/// in place, and the rest is moved into the spare capacity of `to`.
+fn flip<T>(from: &mut Vec<T>, to: &mut Vec<T>) {
+    debug_assert!(to.is_empty());
+    let len = from.len();
+    if len == 0 {
+        return;
+    }
+    let moved = len - len / 2;
+    mem::swap(from, to);
+    from.extend(to.drain(moved..));
+    to.reverse();
+}
+
+impl<T: Clone> Clone for DualStackDeque<T> {
+    fn clone(&self) -> Self {
+        Self { front: self.front.clone(), back: self.back.clone() }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for DualStackDeque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Default for DualStackDeque<T> {
+    /// Creates an empty deque.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deque<T> for DualStackDeque<T> {
+    type Iter<'a>
+        = Chain<Rev<slice::Iter<'a, T>>, slice::Iter<'a, T>>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        DualStackDeque::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        DualStackDeque::get(self, index)
+    }
+
+    fn push_front(&mut self, value: T) {
+        DualStackDeque::push_front(self, value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        DualStackDeque::push_back(self, value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        DualStackDeque::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        DualStackDeque::pop_back(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        DualStackDeque::iter(self)
+    }
+}
+
+impl<T> Extend<T> for DualStackDeque<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.back.extend(iter);
+    }
+}
+
+impl<T> ExtendFront<T> for DualStackDeque<T> {
+    fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.front.extend(iter);
+    }
+}
+
+impl<T> From<Vec<T>> for DualStackDeque<T> {
+    /// Turns a [`Vec<T>`] into the back stack of a [`DualStackDeque<T>`] without copying.
+    fn from(vec: Vec<T>) -> Self {
+        Self { front: Vec::new(), back: vec }
+    }
+}
+
+impl<T> From<DualStackDeque<T>> for Vec<T> {
+    /// Turns a [`DualStackDeque<T>`] into a [`Vec<T>`].
+    ///
+    /// If the front stack is empty, the back stack is returned as is. Otherwise the front stack
+    /// is reversed in place and the back stack is appended to it, which only allocates if the
+    /// front stack has not enough spare capacity.
+    fn from(deque: DualStackDeque<T>) -> Self {
+        let DualStackDeque { mut front, mut back } = deque;
+        if front.is_empty() {
+            return back;
+        }
+        front.reverse();
+        front.append(&mut back);
+        front
+    }
+}
+
+impl<T> FromIterator<T> for DualStackDeque<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(Vec::from_iter(iter))
+    }
+}
+
+impl<T: Hash> Hash for DualStackDeque<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        self.iter().for_each(|elem| elem.hash(state));
+    }
+}
+
+impl<T> Index<usize> for DualStackDeque<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).unwrap_or_else(|| index_out_of_bounds(self.len(), index))
+    }
+}
+
+impl<T> IndexMut<usize> for DualStackDeque<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len();
+        self.get_mut(index).unwrap_or_else(|| index_out_of_bounds(len, index))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DualStackDeque<T> {
+    type Item = &'a T;
+    type IntoIter = Chain<Rev<slice::Iter<'a, T>>, slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut DualStackDeque<T> {
+    type Item = &'a mut T;
+    type IntoIter = Chain<Rev<slice::IterMut<'a, T>>, slice::IterMut<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for DualStackDeque<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for DualStackDeque<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: PartialEq> PartialEq for DualStackDeque<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for DualStackDeque<T> {}
+
+impl<T: PartialEq<U>, U> PartialEq<[U]> for DualStackDeque<T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for DualStackDeque<T> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<Vec<U>> for DualStackDeque<T> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        *self == other[..]
+    }
+}
@@ -0,0 +1,355 @@
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, Index, IndexMut};
+
+use std::fmt;
+
+use crate::{AltDeque, IntoIter, Iter, IterMut};
+
+/// A deque that never allocates more capacity than it needs.
+///
+/// Whenever the deque has to grow, it grows to exactly the new length, as if
+/// [`AltDeque::reserve_exact`] was called, and the minimum capacity for the first allocation is
+/// skipped. This trades more frequent reallocations for no slack, which is useful if memory is
+/// tight. Note that pushing to a full deque reallocates every time.
+///
+/// The growth policy lives in this wrapper, so an [`AltDeque`] does not pay for it. Only the
+/// methods that may grow the buffer, and those that need `&mut self`, are defined here, all
+/// others are reached through `Deref<Target = AltDeque<T>>`.
+///
+/// # Examples
+///
+/// ```
+/// # use altdeque::ExactAltDeque;
+/// let mut deque = ExactAltDeque::with_capacity(2);
+/// deque.extend([1, 2, 3]);
+/// assert_eq!(deque.capacity(), 3);
+/// deque.push_front(0);
+/// assert_eq!(deque.capacity(), 4);
+/// assert_eq!(deque, [0, 1, 2, 3]);
+/// ```
+pub struct ExactAltDeque<T> {
+    deque: AltDeque<T>,
+}
+
+impl<T> ExactAltDeque<T> {
+    /// Creates an empty deque. Does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::ExactAltDeque;
+    /// let deque: ExactAltDeque<i32> = ExactAltDeque::new();
+    /// assert_eq!(deque.capacity(), 0);
+    /// ```
+    pub fn new() -> Self {
+        Self { deque: AltDeque::new() }
+    }
+
+    /// Creates an empty deque with space for exactly `capacity` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::ExactAltDeque;
+    /// let deque: ExactAltDeque<i32> = ExactAltDeque::with_capacity(10);
+    /// assert_eq!(deque.capacity(), 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { deque: AltDeque::with_capacity(capacity) }
+    }
+
+    /// Prepends an element to the front of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::ExactAltDeque;
+    /// let mut deque = ExactAltDeque::new();
+    /// deque.push_front(1);
+    /// deque.push_front(0);
+    /// assert_eq!(deque, [0, 1]);
+    /// assert_eq!(deque.capacity(), 2);
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        self.reserve(1);
+        self.deque.push_front(value);
+    }
+
+    /// Appends an element to the back of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::ExactAltDeque;
+    /// let mut deque = ExactAltDeque::new();
+    /// deque.push_back(0);
+    /// deque.push_back(1);
+    /// assert_eq!(deque, [0, 1]);
+    /// assert_eq!(deque.capacity(), 2);
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        self.reserve(1);
+        self.deque.push_back(value);
+    }
+
+    /// Inserts an element at `index` within the deque, shifting all elements with indices
+    /// greater than or equal to `index` towards the back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the deque's length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::ExactAltDeque;
+    /// let mut deque = ExactAltDeque::from_iter([0, 2]);
+    /// deque.insert(1, 1);
+    /// assert_eq!(deque, [0, 1, 2]);
+    /// assert_eq!(deque.capacity(), 3);
+    /// ```
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.reserve(1);
+        self.deque.insert(index, value);
+    }
+
+    /// Reserves capacity for exactly `additional` more elements, if the deque does not have
+    /// enough room yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::ExactAltDeque;
+    /// let mut deque = ExactAltDeque::from_iter([1]);
+    /// deque.reserve(10);
+    /// assert_eq!(deque.capacity(), 11);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.deque.reserve_exact(additional);
+    }
+
+    /// Moves all the elements of `other` into `self`, leaving `other` empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{AltDeque, ExactAltDeque};
+    /// let mut deque = ExactAltDeque::from_iter([1, 2]);
+    /// deque.append(&mut AltDeque::from([3, 4]));
+    /// assert_eq!(deque, [1, 2, 3, 4]);
+    /// assert_eq!(deque.capacity(), 4);
+    /// ```
+    pub fn append(&mut self, other: &mut AltDeque<T>) {
+        self.reserve(other.len());
+        self.deque.append(other);
+    }
+
+    // The methods below never grow the buffer and only forward to the inner deque. Everything
+    // that takes `&self` is reached through `Deref`.
+
+    /// See [`AltDeque::pop_front`].
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.deque.pop_front()
+    }
+
+    /// See [`AltDeque::pop_back`].
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.deque.pop_back()
+    }
+
+    /// See [`AltDeque::remove`].
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        self.deque.remove(index)
+    }
+
+    /// See [`AltDeque::get_mut`].
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.deque.get_mut(index)
+    }
+
+    /// See [`AltDeque::iter_mut`].
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.deque.iter_mut()
+    }
+
+    /// See [`AltDeque::truncate`].
+    pub fn truncate(&mut self, len: usize) {
+        self.deque.truncate(len);
+    }
+
+    /// See [`AltDeque::clear`].
+    pub fn clear(&mut self) {
+        self.deque.clear();
+    }
+
+    /// See [`AltDeque::shrink_to_fit`].
+    pub fn shrink_to_fit(&mut self) {
+        self.deque.shrink_to_fit();
+    }
+
+    /// See [`AltDeque::split_off`]. The returned deque has exactly the capacity for its
+    /// elements.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        Self { deque: self.deque.split_off(at) }
+    }
+}
+
+impl<T: Clone> Clone for ExactAltDeque<T> {
+    fn clone(&self) -> Self {
+        // cloning an AltDeque allocates exactly the length
+        Self { deque: self.deque.clone() }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ExactAltDeque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T> Default for ExactAltDeque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for ExactAltDeque<T> {
+    type Target = AltDeque<T>;
+
+    fn deref(&self) -> &AltDeque<T> {
+        &self.deque
+    }
+}
+
+impl<T> Extend<T> for ExactAltDeque<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        // fill the room we have without reallocating
+        while self.deque.len() < self.deque.capacity() {
+            match iter.next() {
+                Some(value) => self.deque.push_back(value),
+                None => return,
+            }
+        }
+        // growing by one for every element beyond the size hint would copy the buffer each
+        // time, so the rest is gathered first and moved in with a single reallocation
+        let rest: Vec<T> = iter.collect();
+        self.reserve(rest.len());
+        self.deque.extend(rest);
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for ExactAltDeque<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T> FromIterator<T> for ExactAltDeque<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut deque = Self::new();
+        deque.extend(iter);
+        deque
+    }
+}
+
+impl<T> From<AltDeque<T>> for ExactAltDeque<T> {
+    /// Wraps an [`AltDeque`] without moving the elements. The capacity stays as it is until the
+    /// deque has to grow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{AltDeque, ExactAltDeque};
+    /// let mut deque = ExactAltDeque::from(AltDeque::from([1, 2]));
+    /// deque.shrink_to_fit();
+    /// deque.push_back(3);
+    /// assert_eq!(deque.capacity(), 3);
+    /// ```
+    fn from(deque: AltDeque<T>) -> Self {
+        Self { deque }
+    }
+}
+
+impl<T> From<ExactAltDeque<T>> for AltDeque<T> {
+    /// Turns an `ExactAltDeque` into an [`AltDeque`] that grows as usual, without moving the
+    /// elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{AltDeque, ExactAltDeque};
+    /// let deque = ExactAltDeque::from_iter([1, 2]);
+    /// let mut deque = AltDeque::from(deque);
+    /// deque.push_back(3);
+    /// assert_eq!(deque, [1, 2, 3]);
+    /// ```
+    fn from(deque: ExactAltDeque<T>) -> Self {
+        deque.deque
+    }
+}
+
+impl<T: Hash> Hash for ExactAltDeque<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.deque.hash(state);
+    }
+}
+
+impl<T> Index<usize> for ExactAltDeque<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.deque[index]
+    }
+}
+
+impl<T> IndexMut<usize> for ExactAltDeque<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.deque[index]
+    }
+}
+
+impl<T> IntoIterator for ExactAltDeque<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.deque.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ExactAltDeque<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut ExactAltDeque<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: PartialEq> PartialEq for ExactAltDeque<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.deque == other.deque
+    }
+}
+
+impl<T: Eq> Eq for ExactAltDeque<T> {}
+
+__impl_slice_eq! { [] ExactAltDeque<T>, Vec<U>, }
+__impl_slice_eq! { [] ExactAltDeque<T>, &[U], }
+__impl_slice_eq! { [const N: usize] ExactAltDeque<T>, [U; N], }
+__impl_slice_eq! { [const N: usize] ExactAltDeque<T>, &[U; N], }
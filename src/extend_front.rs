@@ -0,0 +1,51 @@
+#[cfg(not(no_global_oom_handling))]
+use std::collections::VecDeque;
+
+/// Extend a collection at its front with the contents of an iterator.
+///
+/// This is the counterpart of [`Extend`] for the other end of a deque. Every element is
+/// prepended in turn, like with repeated calls to `push_front`, so the elements end up in
+/// reverse order. Pass a reversed iterator to keep their order.
+///
+/// The standard library's `VecDeque` has an unstable inherent `extend_front` method with the
+/// same behavior, which takes precedence over this trait once it is stabilized.
+///
+/// # Examples
+///
+/// ```
+/// use altdeque::{AltDeque, ExtendFront};
+/// use std::collections::VecDeque;
+///
+/// fn surround<D: Extend<i32> + ExtendFront<i32>>(deque: &mut D) {
+///     deque.extend_front([-1, -2]);
+///     deque.extend([1, 2]);
+/// }
+///
+/// let mut alt = AltDeque::from([0]);
+/// let mut vec = VecDeque::from([0]);
+/// surround(&mut alt);
+/// surround(&mut vec);
+/// assert_eq!(alt, [-2, -1, 0, 1, 2]);
+/// assert_eq!(alt, vec);
+/// ```
+pub trait ExtendFront<A> {
+    /// Prepends the elements of an iterator one by one to the front of the collection.
+    fn extend_front<I: IntoIterator<Item = A>>(&mut self, iter: I);
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl<T> ExtendFront<T> for VecDeque<T> {
+    fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(|element| self.push_front(element));
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl<'a, T: 'a + Copy> ExtendFront<&'a T> for VecDeque<T> {
+    fn extend_front<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        // VecDeque has an unstable inherent method of the same name
+        ExtendFront::extend_front(self, iter.into_iter().copied());
+    }
+}
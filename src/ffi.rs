@@ -0,0 +1,159 @@
+//! A C API for byte deques.
+//!
+//! The functions in this module are exported unmangled with the C calling convention, so C and
+//! C++ code can link against this crate when it is built as a `staticlib` or `cdylib`. The
+//! deque is an opaque [`AltDequeU8`] that is only handled through pointers, a header for it
+//! can be generated with [cbindgen](https://github.com/mozilla/cbindgen).
+//!
+//! Every deque created with [`altdeque_u8_new`] must be freed with [`altdeque_u8_free`]. All
+//! other functions require a valid pointer returned by [`altdeque_u8_new`] that was not freed
+//! yet, and no other function may be called with the same deque at the same time.
+//!
+//! # Examples
+//!
+//! ```
+//! use altdeque::ffi::*;
+//!
+//! unsafe {
+//!     let deque = altdeque_u8_new();
+//!     altdeque_u8_push_back(deque, 1);
+//!     altdeque_u8_push_front(deque, 0);
+//!     assert_eq!(altdeque_u8_len(deque), 2);
+//!
+//!     let mut byte = 0;
+//!     assert!(altdeque_u8_pop_back(deque, &mut byte));
+//!     assert_eq!(byte, 1);
+//!     altdeque_u8_free(deque);
+//! }
+//! ```
+
+use std::ptr;
+
+use crate::AltDeque;
+
+/// An opaque deque of bytes.
+pub struct AltDequeU8(AltDeque<u8>);
+
+/// The contents of a deque as two slices, see [`altdeque_u8_as_slices`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct AltDequeU8Slices {
+    /// The first elements of the deque.
+    pub front: *const u8,
+    /// The number of elements `front` points to.
+    pub front_len: usize,
+    /// The remaining elements of the deque.
+    pub back: *const u8,
+    /// The number of elements `back` points to.
+    pub back_len: usize,
+}
+
+/// Creates an empty deque. Does not allocate.
+#[no_mangle]
+pub extern "C" fn altdeque_u8_new() -> *mut AltDequeU8 {
+    Box::into_raw(Box::new(AltDequeU8(AltDeque::new())))
+}
+
+/// Frees a deque. Does nothing if `deque` is null.
+///
+/// # Safety
+///
+/// `deque` must be null or a deque returned by [`altdeque_u8_new`] that was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn altdeque_u8_free(deque: *mut AltDequeU8) {
+    if !deque.is_null() {
+        // SAFETY: the caller guarantees that deque came from Box::into_raw
+        drop(unsafe { Box::from_raw(deque) });
+    }
+}
+
+/// Returns the number of bytes in the deque.
+///
+/// # Safety
+///
+/// `deque` must be a valid deque, see the [module documentation](self).
+#[no_mangle]
+pub unsafe extern "C" fn altdeque_u8_len(deque: *const AltDequeU8) -> usize {
+    // SAFETY: the caller guarantees that deque is valid
+    unsafe { (*deque).0.len() }
+}
+
+/// Prepends a byte to the front of the deque.
+///
+/// # Safety
+///
+/// `deque` must be a valid deque, see the [module documentation](self).
+#[no_mangle]
+pub unsafe extern "C" fn altdeque_u8_push_front(deque: *mut AltDequeU8, value: u8) {
+    // SAFETY: the caller guarantees that deque is valid and not used elsewhere
+    unsafe { (*deque).0.push_front(value) }
+}
+
+/// Appends a byte to the back of the deque.
+///
+/// # Safety
+///
+/// `deque` must be a valid deque, see the [module documentation](self).
+#[no_mangle]
+pub unsafe extern "C" fn altdeque_u8_push_back(deque: *mut AltDequeU8, value: u8) {
+    // SAFETY: the caller guarantees that deque is valid and not used elsewhere
+    unsafe { (*deque).0.push_back(value) }
+}
+
+/// Removes the first byte and writes it to `out`. Returns `false` and leaves `out` untouched if
+/// the deque is empty.
+///
+/// # Safety
+///
+/// `deque` must be a valid deque, see the [module documentation](self), and `out` must be
+/// valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn altdeque_u8_pop_front(deque: *mut AltDequeU8, out: *mut u8) -> bool {
+    // SAFETY: the caller guarantees that deque is valid and not used elsewhere
+    match unsafe { (*deque).0.pop_front() } {
+        Some(value) => {
+            // SAFETY: the caller guarantees that out is valid for a write
+            unsafe { ptr::write(out, value) };
+            true
+        }
+        None => false,
+    }
+}
+
+/// Removes the last byte and writes it to `out`. Returns `false` and leaves `out` untouched if
+/// the deque is empty.
+///
+/// # Safety
+///
+/// `deque` must be a valid deque, see the [module documentation](self), and `out` must be
+/// valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn altdeque_u8_pop_back(deque: *mut AltDequeU8, out: *mut u8) -> bool {
+    // SAFETY: the caller guarantees that deque is valid and not used elsewhere
+    match unsafe { (*deque).0.pop_back() } {
+        Some(value) => {
+            // SAFETY: the caller guarantees that out is valid for a write
+            unsafe { ptr::write(out, value) };
+            true
+        }
+        None => false,
+    }
+}
+
+/// Returns the contents of the deque as two slices, which are valid until the deque is
+/// modified or freed. Empty slices may have dangling pointers.
+///
+/// # Safety
+///
+/// `deque` must be a valid deque, see the [module documentation](self).
+#[no_mangle]
+pub unsafe extern "C" fn altdeque_u8_as_slices(deque: *const AltDequeU8) -> AltDequeU8Slices {
+    // SAFETY: the caller guarantees that deque is valid
+    let (front, back) = unsafe { (*deque).0.as_slices() };
+    AltDequeU8Slices {
+        front: front.as_ptr(),
+        front_len: front.len(),
+        back: back.as_ptr(),
+        back_len: back.len(),
+    }
+}
@@ -0,0 +1,323 @@
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::slice;
+
+use crate::{reverse_stacks, split_back_stack, split_front_stack, Dropper, Iter, IterMut};
+
+/// The memory that [`FixedStacks`] keeps its elements in.
+///
+/// # Safety
+///
+/// Both pointers must be valid for `capacity` elements of `T` for as long as the storage is
+/// not moved or mutated through other means, and `capacity` must never change.
+pub(crate) unsafe trait Storage<T> {
+    fn as_ptr(&self) -> *const T;
+    fn as_mut_ptr(&mut self) -> *mut T;
+    fn capacity(&self) -> usize;
+}
+
+// SAFETY: the array holds exactly N elements
+unsafe impl<T, const N: usize> Storage<T> for [MaybeUninit<T>; N] {
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        <[MaybeUninit<T>]>::as_ptr(self) as *const T
+    }
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut T {
+        <[MaybeUninit<T>]>::as_mut_ptr(self) as *mut T
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        N
+    }
+}
+
+// SAFETY: the slice is borrowed mutably, so nobody else can change it while we hold it
+unsafe impl<T> Storage<T> for &mut [MaybeUninit<T>] {
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        <[MaybeUninit<T>]>::as_ptr(self) as *const T
+    }
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut T {
+        <[MaybeUninit<T>]>::as_mut_ptr(self) as *mut T
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+}
+
+/// The two stacks of a deque in a storage of fixed size, which is the core of the deques that
+/// do not use a `RawVec`: [`ArrayAltDeque`], [`BufferDeque`] and [`ArenaAltDeque`]. Pushing
+/// into a full deque hands the value back in an `Err`, the owner may then call
+/// [`replace_storage`] with a bigger storage.
+///
+/// [`ArrayAltDeque`]: crate::ArrayAltDeque
+/// [`BufferDeque`]: crate::BufferDeque
+/// [`ArenaAltDeque`]: crate::ArenaAltDeque
+/// [`replace_storage`]: FixedStacks::replace_storage
+pub(crate) struct FixedStacks<T, S: Storage<T>> {
+    // Same meaning as in AltDeque, with the capacity of the storage as the capacity.
+    // 0 <= head <= tail <= cap
+    tail: usize,
+    head: usize,
+    storage: S,
+    // the elements are owned and dropped by us
+    _marker: PhantomData<T>,
+}
+
+impl<T, const N: usize> FixedStacks<T, [MaybeUninit<T>; N]> {
+    pub(crate) const fn new_inline() -> Self {
+        Self { tail: N, head: 0, storage: [const { MaybeUninit::uninit() }; N], _marker: PhantomData }
+    }
+}
+
+impl<T, S: Storage<T>> FixedStacks<T, S> {
+    pub(crate) fn new(storage: S) -> Self {
+        Self { tail: storage.capacity(), head: 0, storage, _marker: PhantomData }
+    }
+
+    #[inline]
+    pub(crate) fn capacity(&self) -> usize {
+        self.storage.capacity()
+    }
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        // this cannot overflow because head <= tail <= cap
+        self.capacity() - self.tail + self.head
+    }
+
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.head == 0 && self.tail == self.capacity()
+    }
+
+    #[inline]
+    pub(crate) fn is_full(&self) -> bool {
+        self.tail == self.head
+    }
+
+    pub(crate) fn as_slices(&self) -> (&[T], &[T]) {
+        let ptr = self.storage.as_ptr();
+        // SAFETY: all elements in the ranges [0, head) and [tail, cap) are valid
+        unsafe {
+            let front = slice::from_raw_parts(ptr.add(self.tail), self.capacity() - self.tail);
+            let back = slice::from_raw_parts(ptr, self.head);
+            (front, back)
+        }
+    }
+
+    pub(crate) fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (head, tail, cap) = (self.head, self.tail, self.capacity());
+        let ptr = self.storage.as_mut_ptr();
+        // SAFETY: all elements in the ranges [0, head) and [tail, cap) are valid and do not overlap
+        unsafe {
+            let front = slice::from_raw_parts_mut(ptr.add(tail), cap - tail);
+            let back = slice::from_raw_parts_mut(ptr, head);
+            (front, back)
+        }
+    }
+
+    pub(crate) fn get(&self, index: usize) -> Option<&T> {
+        let (front, back) = self.as_slices();
+        if index < front.len() {
+            front.get(index)
+        } else {
+            back.get(index - front.len())
+        }
+    }
+
+    pub(crate) fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let (front, back) = self.as_mut_slices();
+        if index < front.len() {
+            front.get_mut(index)
+        } else {
+            back.get_mut(index - front.len())
+        }
+    }
+
+    pub(crate) fn pop_front(&mut self) -> Option<T> {
+        let cap = self.capacity();
+        let ptr = self.storage.as_mut_ptr();
+        if self.tail != cap {
+            let tail = self.tail;
+            self.tail += 1;
+            // SAFETY: tail < cap
+            unsafe { Some(ptr::read(ptr.add(tail))) }
+        } else if self.head != 0 {
+            let len = self.head;
+            let front_len = len / 2;
+            // SAFETY: head > 0 and the front stack is empty
+            unsafe {
+                let value = ptr::read(ptr);
+                // ignore the first element because we return it
+                split_back_stack(ptr, cap, 1, len, front_len);
+                self.tail = cap - front_len;
+                self.head = len - 1 - front_len;
+                Some(value)
+            }
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn pop_back(&mut self) -> Option<T> {
+        let cap = self.capacity();
+        let ptr = self.storage.as_mut_ptr();
+        if self.head != 0 {
+            self.head -= 1;
+            // SAFETY: old head was > 0
+            unsafe { Some(ptr::read(ptr.add(self.head))) }
+        } else if self.tail != cap {
+            let len = cap - self.tail;
+            let back_len = len / 2;
+            // SAFETY: tail < cap and the back stack is empty
+            unsafe {
+                let value = ptr::read(ptr.add(cap - 1));
+                // ignore the last element because we return it
+                split_front_stack(ptr, cap, self.tail, cap - 1, back_len);
+                self.head = back_len;
+                self.tail = cap - (len - 1 - back_len);
+                Some(value)
+            }
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn push_front(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        self.tail -= 1;
+        // SAFETY: old tail was > 0 because the storage is not full
+        unsafe {
+            ptr::write(self.storage.as_mut_ptr().add(self.tail), value);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn push_back(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        // SAFETY: head < tail because the storage is not full
+        unsafe {
+            ptr::write(self.storage.as_mut_ptr().add(self.head), value);
+        }
+        self.head += 1;
+        Ok(())
+    }
+
+    pub(crate) fn truncate(&mut self, len: usize) {
+        /// Moves the kept front elements in place even if the destructor of a dropped element
+        /// panics.
+        struct DropGuard<'a, T, S: Storage<T>> { stacks: &'a mut FixedStacks<T, S>, old_tail: usize, len: usize }
+
+        impl<T, S: Storage<T>> Drop for DropGuard<'_, T, S> {
+            fn drop(&mut self) {
+                self.stacks.tail = self.stacks.capacity() - self.len;
+                let ptr = self.stacks.storage.as_mut_ptr();
+                // SAFETY: len <= old front len -> we can copy len elements from old_tail to cap - len
+                unsafe {
+                    ptr::copy(ptr.add(self.old_tail), ptr.add(self.stacks.tail), self.len);
+                }
+            }
+        }
+
+        if len >= self.len() {
+            return;
+        }
+
+        let front_len = self.capacity() - self.tail;
+        let ptr = self.storage.as_mut_ptr();
+        if len > front_len {
+            let begin = len - front_len;
+            let old_head = self.head;
+            // set head before dropping so that no value is dropped twice if a destructor panics
+            self.head = begin;
+            // SAFETY: begin < old_head, the elements in [begin, old_head) are valid
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr.add(begin), old_head - begin));
+            }
+        } else {
+            // SAFETY: both ranges are in bounds and only contain valid elements, head and tail
+            // are reset before dropping so no value can be dropped twice
+            unsafe {
+                let drop_front = slice::from_raw_parts_mut(ptr.add(self.tail + len), front_len - len);
+                let drop_back = slice::from_raw_parts_mut(ptr, self.head);
+                let old_tail = self.tail;
+                self.head = 0;
+                self.tail = self.capacity();
+                let _guard = DropGuard { stacks: self, old_tail, len };
+                {
+                    // Make sure the second half is dropped even when a destructor in the first one panics.
+                    let _back_dropper = Dropper(drop_back);
+                    ptr::drop_in_place(drop_front);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn reverse(&mut self) {
+        let (head, tail, cap) = (self.head, self.tail, self.capacity());
+        // SAFETY: [0, head) and [tail, cap) are the initialized stacks of the storage
+        unsafe {
+            reverse_stacks(self.storage.as_mut_ptr(), cap, head, tail);
+        }
+        self.head = cap - tail;
+        self.tail = cap - head;
+    }
+
+    pub(crate) fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        front.iter().chain(back.iter())
+    }
+
+    pub(crate) fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+        front.iter_mut().chain(back.iter_mut())
+    }
+
+    /// Moves all elements into `storage` and returns the old storage, whose slots are all
+    /// uninitialized afterwards. The back stack stays at the start and the front stack at the
+    /// end of the storage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `storage` cannot hold all elements.
+    #[cfg(not(no_global_oom_handling))]
+    pub(crate) fn replace_storage(&mut self, mut storage: S) -> S {
+        let new_cap = storage.capacity();
+        assert!(new_cap >= self.len(), "the new storage is too small");
+        let front_len = self.capacity() - self.tail;
+        let old = self.storage.as_mut_ptr();
+        let new = storage.as_mut_ptr();
+        // SAFETY: both stacks fit into the new storage, which we own and which is therefore a
+        // different allocation than ours
+        unsafe {
+            ptr::copy_nonoverlapping(old, new, self.head);
+            ptr::copy_nonoverlapping(old.add(self.tail), new.add(new_cap - front_len), front_len);
+        }
+        self.tail = new_cap - front_len;
+        std::mem::replace(&mut self.storage, storage)
+    }
+}
+
+impl<T, S: Storage<T>> Drop for FixedStacks<T, S> {
+    fn drop(&mut self) {
+        let (front, back) = self.as_mut_slices();
+        unsafe {
+            let _back_dropper = Dropper(back);
+            // use drop for [T]
+            ptr::drop_in_place(front);
+        }
+    }
+}
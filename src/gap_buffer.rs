@@ -0,0 +1,449 @@
+use std::fmt;
+use std::ptr;
+use std::slice;
+
+use crate::raw_vec::RawVec;
+use crate::{index_out_of_bounds, Dropper, Iter, IterMut};
+
+/// A gap buffer, a sequence with a cursor where inserting and deleting take *O(1)* time.
+///
+/// Like an [`AltDeque`], a gap buffer keeps two stacks at the ends of one allocation with the
+/// free space between them. Here the free space is the cursor: the elements before it are at
+/// the start of the buffer and the elements after it at the end, both in order. [`insert`],
+/// [`delete`] and [`backspace`] work at the edges of the gap, and [`set_cursor`] moves the
+/// elements between the old and the new position across the gap. This makes many edits close
+/// to each other cheap, which is why text editors use gap buffers.
+///
+/// [`AltDeque`]: crate::AltDeque
+/// [`insert`]: GapBuffer::insert
+/// [`delete`]: GapBuffer::delete
+/// [`backspace`]: GapBuffer::backspace
+/// [`set_cursor`]: GapBuffer::set_cursor
+///
+/// # Examples
+///
+/// ```
+/// # use altdeque::GapBuffer;
+/// let mut text: GapBuffer<char> = "helo world".chars().collect();
+/// text.set_cursor(3);
+/// text.insert('l');
+/// text.set_cursor(text.len());
+/// text.backspace();
+/// text.extend("d!".chars());
+/// assert_eq!(text.iter().collect::<String>(), "hello world!");
+/// ```
+pub struct GapBuffer<T> {
+    buf: RawVec<T>,
+    // the gap is [cursor, gap_end), the elements occupy [0, cursor) and [gap_end, capacity)
+    cursor: usize,
+    gap_end: usize,
+}
+
+impl<T> GapBuffer<T> {
+    /// Creates an empty gap buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::GapBuffer;
+    /// let buffer: GapBuffer<char> = GapBuffer::new();
+    /// assert!(buffer.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates an empty gap buffer with space for at least `capacity` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::GapBuffer;
+    /// let buffer: GapBuffer<char> = GapBuffer::with_capacity(10);
+    /// assert!(buffer.capacity() >= 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        let buf = RawVec::with_capacity(capacity);
+        Self { cursor: 0, gap_end: buf.capacity(), buf }
+    }
+
+    /// Returns the number of elements the gap buffer can hold without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::GapBuffer;
+    /// let buffer: GapBuffer<char> = GapBuffer::with_capacity(10);
+    /// assert!(buffer.capacity() >= 10);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    /// Returns the number of elements in the gap buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::GapBuffer;
+    /// let buffer: GapBuffer<_> = [1, 2, 3].into_iter().collect();
+    /// assert_eq!(buffer.len(), 3);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cursor + self.capacity() - self.gap_end
+    }
+
+    /// Returns `true` if the gap buffer is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::GapBuffer;
+    /// let mut buffer = GapBuffer::new();
+    /// assert!(buffer.is_empty());
+    /// buffer.insert(1);
+    /// assert!(!buffer.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the position of the cursor, which is the number of elements before it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::GapBuffer;
+    /// let mut buffer = GapBuffer::new();
+    /// buffer.insert(1);
+    /// buffer.insert(2);
+    /// assert_eq!(buffer.cursor(), 2);
+    /// ```
+    #[inline]
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Moves the cursor to `index`, so that `index` elements are before it.
+    ///
+    /// This moves all elements between the old and the new position across the gap and takes
+    /// *O(d)* time, where d is the distance between them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::GapBuffer;
+    /// let mut buffer: GapBuffer<_> = [1, 2, 3].into_iter().collect();
+    /// buffer.set_cursor(1);
+    /// assert_eq!(buffer.as_slices(), (&[1][..], &[2, 3][..]));
+    /// ```
+    pub fn set_cursor(&mut self, index: usize) {
+        let len = self.len();
+        if index > len {
+            index_out_of_bounds(len, index);
+        }
+        if index < self.cursor {
+            let count = self.cursor - index;
+            // SAFETY: the count elements before the cursor move to the end of the gap, which is
+            // at least count elements long as index <= len
+            unsafe { self.buf.copy(index, self.gap_end - count, count) };
+            self.cursor = index;
+            self.gap_end -= count;
+        } else {
+            let count = index - self.cursor;
+            // SAFETY: the count elements after the gap move to its start, there are at least
+            // count elements after it as index <= len
+            unsafe { self.buf.copy(self.gap_end, self.cursor, count) };
+            self.cursor = index;
+            self.gap_end += count;
+        }
+    }
+
+    /// Inserts an element at the cursor and moves the cursor after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::GapBuffer;
+    /// let mut buffer: GapBuffer<_> = [1, 3].into_iter().collect();
+    /// buffer.set_cursor(1);
+    /// buffer.insert(2);
+    /// assert_eq!(buffer.cursor(), 2);
+    /// assert!(buffer.iter().eq(&[1, 2, 3]));
+    /// ```
+    pub fn insert(&mut self, value: T) {
+        if self.cursor == self.gap_end {
+            self.reserve(1);
+        }
+        // SAFETY: the gap is not empty, so cursor is free and in bounds
+        unsafe { ptr::write(self.buf.ptr().add(self.cursor), value) };
+        self.cursor += 1;
+    }
+
+    /// Removes and returns the element after the cursor, or `None` if the cursor is at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::GapBuffer;
+    /// let mut buffer: GapBuffer<_> = [1, 2].into_iter().collect();
+    /// buffer.set_cursor(1);
+    /// assert_eq!(buffer.delete(), Some(2));
+    /// assert_eq!(buffer.delete(), None);
+    /// ```
+    pub fn delete(&mut self) -> Option<T> {
+        if self.gap_end == self.capacity() {
+            return None;
+        }
+        self.gap_end += 1;
+        // SAFETY: the old gap_end was the first element after the gap
+        unsafe { Some(ptr::read(self.buf.ptr().add(self.gap_end - 1))) }
+    }
+
+    /// Removes and returns the element before the cursor, or `None` if the cursor is at the
+    /// start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::GapBuffer;
+    /// let mut buffer: GapBuffer<_> = [1, 2].into_iter().collect();
+    /// buffer.set_cursor(1);
+    /// assert_eq!(buffer.backspace(), Some(1));
+    /// assert_eq!(buffer.backspace(), None);
+    /// ```
+    pub fn backspace(&mut self) -> Option<T> {
+        self.cursor = self.cursor.checked_sub(1)?;
+        // SAFETY: the old cursor - 1 was the last element before the gap
+        unsafe { Some(ptr::read(self.buf.ptr().add(self.cursor))) }
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be inserted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::GapBuffer;
+    /// let mut buffer: GapBuffer<_> = [1].into_iter().collect();
+    /// buffer.reserve(10);
+    /// assert!(buffer.capacity() >= 11);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        let old_cap = self.capacity();
+        self.buf.reserve(self.len(), additional);
+        if self.capacity() == old_cap {
+            return;
+        }
+        let after_len = old_cap - self.gap_end;
+        let new_gap_end = self.capacity() - after_len;
+        // SAFETY: the elements after the gap move to the end of the grown buffer
+        unsafe { self.buf.copy(self.gap_end, new_gap_end, after_len) };
+        self.gap_end = new_gap_end;
+    }
+
+    /// Returns a pair of slices with the elements before and after the cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::GapBuffer;
+    /// let mut buffer: GapBuffer<_> = [1, 2, 3].into_iter().collect();
+    /// buffer.set_cursor(2);
+    /// assert_eq!(buffer.as_slices(), (&[1, 2][..], &[3][..]));
+    /// ```
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        // SAFETY: [0, cursor) and [gap_end, capacity) are initialized
+        unsafe {
+            (
+                slice::from_raw_parts(self.buf.ptr(), self.cursor),
+                slice::from_raw_parts(self.buf.ptr().add(self.gap_end), self.capacity() - self.gap_end),
+            )
+        }
+    }
+
+    /// Returns a pair of mutable slices with the elements before and after the cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::GapBuffer;
+    /// let mut buffer: GapBuffer<_> = [1, 2, 3].into_iter().collect();
+    /// buffer.set_cursor(1);
+    /// buffer.as_mut_slices().1[0] = 20;
+    /// assert!(buffer.iter().eq(&[1, 20, 3]));
+    /// ```
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        // SAFETY: [0, cursor) and [gap_end, capacity) are initialized and do not overlap
+        unsafe {
+            (
+                slice::from_raw_parts_mut(self.buf.ptr(), self.cursor),
+                slice::from_raw_parts_mut(self.buf.ptr().add(self.gap_end), self.capacity() - self.gap_end),
+            )
+        }
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::GapBuffer;
+    /// let mut buffer: GapBuffer<_> = [1, 2, 3].into_iter().collect();
+    /// buffer.set_cursor(1);
+    /// assert_eq!(buffer.get(2), Some(&3));
+    /// assert_eq!(buffer.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let (before, after) = self.as_slices();
+        match index.checked_sub(before.len()) {
+            None => before.get(index),
+            Some(index) => after.get(index),
+        }
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::GapBuffer;
+    /// let mut buffer: GapBuffer<_> = [1, 2, 3].into_iter().collect();
+    /// *buffer.get_mut(1).unwrap() = 20;
+    /// assert!(buffer.iter().eq(&[1, 20, 3]));
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let (before, after) = self.as_mut_slices();
+        match index.checked_sub(before.len()) {
+            None => before.get_mut(index),
+            Some(index) => after.get_mut(index),
+        }
+    }
+
+    /// Returns a front-to-back iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::GapBuffer;
+    /// let buffer: GapBuffer<_> = [1, 2, 3].into_iter().collect();
+    /// assert!(buffer.iter().eq(&[1, 2, 3]));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (before, after) = self.as_slices();
+        before.iter().chain(after)
+    }
+
+    /// Returns a front-to-back iterator that returns mutable references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::GapBuffer;
+    /// let mut buffer: GapBuffer<_> = [1, 2, 3].into_iter().collect();
+    /// buffer.iter_mut().for_each(|x| *x *= 2);
+    /// assert!(buffer.iter().eq(&[2, 4, 6]));
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (before, after) = self.as_mut_slices();
+        before.iter_mut().chain(after)
+    }
+
+    /// Removes all elements and moves the cursor to the start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::GapBuffer;
+    /// let mut buffer: GapBuffer<_> = [1, 2, 3].into_iter().collect();
+    /// buffer.clear();
+    /// assert!(buffer.is_empty());
+    /// assert_eq!(buffer.cursor(), 0);
+    /// ```
+    pub fn clear(&mut self) {
+        let (before, after) = self.as_mut_slices();
+        let (before, after) = (before as *mut [T], after as *mut [T]);
+        self.cursor = 0;
+        self.gap_end = self.capacity();
+        // SAFETY: the gap is reset before dropping, so no element can be dropped twice
+        unsafe {
+            let _after_dropper = Dropper(&mut *after);
+            ptr::drop_in_place(before);
+        }
+    }
+}
+
+impl<T: Clone> Clone for GapBuffer<T> {
+    fn clone(&self) -> Self {
+        let (before, after) = self.as_slices();
+        let mut buffer = Self::with_capacity(self.len());
+        before.iter().chain(after).for_each(|value| buffer.insert(value.clone()));
+        buffer.set_cursor(self.cursor);
+        buffer
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for GapBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (before, after) = self.as_slices();
+        f.debug_struct("GapBuffer").field("before", &before).field("after", &after).finish()
+    }
+}
+
+impl<T> Default for GapBuffer<T> {
+    /// Creates an empty gap buffer.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for GapBuffer<T> {
+    fn drop(&mut self) {
+        let (before, after) = self.as_mut_slices();
+        // SAFETY: the elements are initialized and the buffer is not used afterwards
+        unsafe {
+            let _after_dropper = Dropper(after);
+            ptr::drop_in_place(before);
+        }
+        // RawVec handles deallocation
+    }
+}
+
+/// Inserts the elements at the cursor, which ends up after them.
+impl<T> Extend<T> for GapBuffer<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(|value| self.insert(value));
+    }
+}
+
+/// Collects the elements with the cursor at the end.
+impl<T> FromIterator<T> for GapBuffer<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut buffer = Self::new();
+        buffer.extend(iter);
+        buffer
+    }
+}
@@ -1,4 +1,10 @@
 use std::iter::FusedIterator;
+#[cfg(feature = "nightly")]
+use std::iter::TrustedLen;
+#[cfg(feature = "futures")]
+use std::pin::Pin;
+#[cfg(feature = "futures")]
+use std::task::{Context, Poll};
 
 use super::AltDeque;
 
@@ -9,7 +15,8 @@ use super::AltDeque;
 ///
 /// [`into_iter`]: AltDeque::into_iter
 /// [`IntoIterator`]: core::iter::IntoIterator
-#[derive(Debug, Clone)]
+#[derive(Debug)]
+#[cfg_attr(not(no_global_oom_handling), derive(Clone))]
 pub struct IntoIter<T> {
     inner: AltDeque<T>,
 }
@@ -18,6 +25,12 @@ impl<T> IntoIter<T> {
     pub(super) fn new(inner: AltDeque<T>) -> Self {
         IntoIter { inner }
     }
+
+    /// Returns the deque that holds the elements which have not been yielded yet.
+    #[cfg(feature = "nightly")]
+    pub(super) fn as_deque_mut(&mut self) -> &mut AltDeque<T> {
+        &mut self.inner
+    }
 }
 
 impl<T> Iterator for IntoIter<T> {
@@ -42,3 +55,31 @@ impl<T> DoubleEndedIterator for IntoIter<T> {
 impl<T> ExactSizeIterator for IntoIter<T> {}
 
 impl<T> FusedIterator for IntoIter<T> {}
+
+// SAFETY: size_hint returns the exact number of remaining items
+#[cfg(feature = "nightly")]
+unsafe impl<T> TrustedLen for IntoIter<T> {}
+
+// The elements live on the heap and are never pinned, so moving the iterator is always fine.
+impl<T> Unpin for IntoIter<T> {}
+
+/// The elements are always ready, so the stream never returns `Poll::Pending`.
+#[cfg(feature = "futures")]
+impl<T> futures_core::Stream for IntoIter<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<T>> {
+        Poll::Ready(self.get_mut().next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Iterator::size_hint(self)
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T> futures_core::FusedStream for IntoIter<T> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
@@ -4,15 +4,15 @@
 //! mostly the same methods and has the performance characteristics. But
 //! instead of using a ring buffer to achieve efficient insertion on both
 //! ends, it uses two stacks. One stack to `push` and `pop` elements for each
-//! end of the deuque. If `pop` is called on one end but it's stack is empty, then all
-//! elements from the other stack are removed, reversed and put into it. This
-//! operation takes *O(n)* time (where n is the length of the deque) but
-//! after it *n* elements can be popped in constant time resulting in an
-//! amortized runtime of *O(1)* for popping.
+//! end of the deuque. If `pop` is called on one end but it's stack is empty, then
+//! the other stack is split in half and the half closer to the popped end is moved
+//! over. This operation takes *O(n)* time (where n is the length of the deque) but
+//! after it *n/2* elements can be popped in constant time from either end, resulting
+//! in an amortized runtime of *O(1)* for popping, even when popping from both ends.
 //!
 //! For more efficient memory usage both stacks are located at the ends of one
 //! allocated buffer:
-//! ```
+//! ```text
 //!         growth ->               <- growth
 //! +- back stack --+               +- front stack -+
 //! |               |               |               |
@@ -33,38 +33,231 @@
 //! - accessing elemnts needs an additional branch to check in which stack they are
 //! - popping elements is only *amortized* constant time, a single pop-call will
 //!   take linear time if the coresponding stack is empty
-//! - popping elements alternating from both sides is very inefficient as all
-//!   elements need to be moved from one side to the other every time the side is changed
+//! - popping elements alternating from both sides moves about half of the elements
+//!   whenever one stack runs dry, which is still slower than a ring buffer
 //!
 //! In my simple tests `AltDeque` and `VecDeque` are about equally fast for a simply
 //! `push_back` and `pop_front` workload.
 //!
+//! If single slow operations are not acceptable, [`RtAltDeque`] is a variant where every
+//! operation takes constant time in the worst case. It pops past the bottom of a stack instead
+//! of moving elements over, and copies into a bigger buffer a few elements at a time.
+//! [`BoundedAltDeque`] grows like an `AltDeque` up to a fixed limit and rejects pushes beyond it,
+//! which is useful for backpressure. For that between threads, the [`sync`] module has a
+//! blocking bounded queue. The [`concurrent`] module has an unbounded queue where producers and
+//! consumers lock different stacks. All deques are `Send` and `Sync` when their elements are,
+//! like a `Vec`, so they can also be moved to other threads or shared behind a lock. Up to
+//! version 1.0.0, `AltDeque` was neither.
+//! [`SegmentedAltDeque`] is meant for huge queues, it chains fixed-size blocks so that neither
+//! growing nor popping ever moves the elements that are already stored.
+//!
+//! [`ArrayAltDeque`] and [`BufferDeque`] never allocate. The first one stores its elements inline,
+//! the second one in a buffer provided by the caller, like a static array or DMA-capable
+//! memory. [`ArenaAltDeque`] grows, but takes its buffers from an [`Arena`], like a bumpalo
+//! `Bump`, so that per-frame data is freed all at once with the arena.
+//!
+//! [`DualStackDeque`] keeps the two stacks in separate `Vec`s, which wastes more memory but
+//! takes over existing `Vec`s without copying and lets each stack grow on its own.
+//!
+//! [`ExactAltDeque`] never allocates more capacity than it needs, at the cost of reallocating on
+//! every push to a full deque.
+//!
+//! [`MinMaxQueue`] uses the two stacks for the classic min-queue, which reports its minimum and
+//! maximum element in constant time, for example to track them over a sliding window.
+//! [`AggregateDeque`] does the same for any associative operation, like sums or matrix products.
+//! [`CowAltDeque`] shares its buffer between clones until one of them is mutated, so a snapshot
+//! of a large queue costs a reference count.
+//! [`UndoRedo`] is an undo and redo history of actions with an optional limit.
+//! [`GapBuffer`] uses the same layout of two stacks in one allocation for a sequence with a
+//! cursor at the free space, where text editors insert and delete.
+//!
+//! ## Cargo features
+//!
+//! - `debug-invariants`: checks the internal invariants of the deque after every mutating
+//!   operation and panics if one of them does not hold. This is meant for testing and fuzzing.
+//! - `metrics`: counts stack flips, reallocations, moved elements and the peak length of every
+//!   deque, see [`AltDeque::stats`].
+//! - `memchr`: adds [`AltDeque::find_byte`] and [`AltDeque::rfind_byte`], which search a byte
+//!   deque with the [memchr](https://docs.rs/memchr) crate.
+//! - `futures`: implements `Stream` from [futures-core](https://docs.rs/futures-core) for
+//!   [`IntoIter`] and [`Drain`], so a deque of messages can be consumed as an async stream.
+//! - `futures-io` and `tokio`: implement the `AsyncRead` and `AsyncWrite` traits of
+//!   [futures-io](https://docs.rs/futures-io) and [tokio](https://docs.rs/tokio) for
+//!   `AltDeque<u8>`. Writes append to the back, reads take from the front and neither ever
+//!   waits, which makes a byte deque an in-memory pipe for tests and loopback transports.
+//! - `bytemuck`: adds [`AltDeque::as_byte_slices`], [`AltDeque::try_cast_deque`] and
+//!   [`AltDeque::from_bytes`] for elements that are [`Pod`](https://docs.rs/bytemuck), to read
+//!   and write binary data without copying element by element.
+//! - `ffi`: adds the [`ffi`] module with a C API for byte deques, for use from C and C++.
+//! - `bumpalo`: makes a [bumpalo](https://docs.rs/bumpalo) `Bump` an [`Arena`] and adds the
+//!   [`BumpAltDeque`] alias, a growable deque whose buffers are allocated in the arena.
+//! - `mmap`: adds [`MmapAltDeque`], whose buffer is a memory-mapped temporary file, so that
+//!   queues larger than the memory are paged out to disk. It uses the
+//!   [memmap2](https://docs.rs/memmap2) crate.
+//! - `rand`: adds [`AltDeque::shuffle`], [`AltDeque::partial_shuffle`], [`AltDeque::choose`]
+//!   and [`AltDeque::choose_mut`], which take a random number generator of the
+//!   [rand](https://docs.rs/rand) crate and work on the logical indices of a deque.
+//! - `bincode`: implements `Encode`, `Decode` and `BorrowDecode` of
+//!   [bincode 2](https://docs.rs/bincode) for [`AltDeque`], without going through serde. The
+//!   encoding is the same as for a `Vec`, and deques of `u8` are written as two byte slices.
+//! - `serde`: implements `Serialize` and `Deserialize` of [serde](https://docs.rs/serde) for
+//!   [`AltDeque`]. Deserializing allocates at most 1 MiB up front, whatever length the input
+//!   announces, and [`AltDeque::deserialize_bounded`] also limits the number of elements, for
+//!   untrusted input.
+//! - `tracing`: emits `DEBUG` events with the target `altdeque` through the
+//!   [tracing](https://docs.rs/tracing) crate whenever an [`AltDeque`] reallocates its buffer,
+//!   moves elements between its stacks or is made contiguous, with the number of elements
+//!   involved, to correlate latency spikes with the internals of a deque.
+//! - `vec-deque`: implements the [`Deque`] trait for the standard library's `VecDeque`, so
+//!   generic code can use either deque.
+//! - `nightly`: requires a nightly compiler. Implements `TrustedLen` for the iterators of this
+//!   crate and specializes `Extend`, which `FromIterator` builds on, for sources of a trusted
+//!   length and for slice iterators of `Copy` elements, which are copied in one go. The `Drop`
+//!   impl of `AltDeque` is marked with `#[may_dangle]`, so like a `Vec`, a deque may hold
+//!   references that do not outlive it. Deques of `u8` and `i8` are hashed in chunks of bytes.
+//!
+//! ## Fallible allocation
+//!
+//! Building with `RUSTFLAGS="--cfg no_global_oom_handling"` compiles out everything that may
+//! panic or abort when an allocation fails, like the cfg of the same name in the standard
+//! library's `alloc` crate. What remains are [`AltDeque::try_with_capacity`],
+//! [`AltDeque::try_reserve`] and [`AltDeque::try_reserve_exact`], the non-allocating
+//! [`AltDeque::push_front_within_capacity`] and [`AltDeque::push_back_within_capacity`], and
+//! all methods that never allocate. The other deque types and the `concurrent`, `ffi`, `spsc`
+//! and `sync` modules are not available in this configuration.
+//!
 //!
 //! Some of the code and a lot of the docs and examples are taken from the code in the
 //! [rust repository](https://github.com/rust-lang/rust/), so credits to it's contributors.
 
+#![cfg_attr(feature = "nightly", feature(dropck_eyepatch, min_specialization, trusted_len))]
+
 use core::cmp::{self, Ordering};
 use core::hash::{Hash, Hasher};
 use core::ops::{Bound, Index, IndexMut, Range, RangeBounds};
 
+use std::collections::VecDeque;
+use std::array;
 use std::fmt;
-use std::iter::{repeat_with, Chain};
-use std::mem::{self, ManuallyDrop};
+use std::iter::{self, Chain};
+use std::mem::{self, ManuallyDrop, MaybeUninit};
 use std::ptr;
 use std::slice;
 
 #[macro_use]
 mod macros;
 
+#[cfg(not(no_global_oom_handling))]
+mod aggregate_deque;
+#[cfg(not(no_global_oom_handling))]
+mod arena_deque;
+mod array_deque;
+#[cfg(all(any(feature = "futures-io", feature = "tokio"), not(no_global_oom_handling)))]
+mod async_io;
+#[cfg(all(feature = "bincode", not(no_global_oom_handling)))]
+mod bincode_impls;
+#[cfg(not(no_global_oom_handling))]
+mod bounded_deque;
+mod buffer_deque;
+mod chunks;
+#[cfg(not(no_global_oom_handling))]
+mod concat;
+#[cfg(not(no_global_oom_handling))]
+pub mod concurrent;
+#[cfg(not(no_global_oom_handling))]
+mod cow_deque;
+#[cfg(not(no_global_oom_handling))]
+mod deque;
 mod drain;
+#[cfg(not(no_global_oom_handling))]
+mod dual_stack_deque;
+#[cfg(not(no_global_oom_handling))]
+mod exact_deque;
+mod extend_front;
+mod fixed_stacks;
+#[cfg(all(feature = "ffi", not(no_global_oom_handling)))]
+pub mod ffi;
+#[cfg(not(no_global_oom_handling))]
+mod gap_buffer;
 mod into_iter;
+#[cfg(not(no_global_oom_handling))]
+mod loom;
+#[cfg(not(no_global_oom_handling))]
+mod min_max_queue;
+#[cfg(all(feature = "mmap", not(no_global_oom_handling)))]
+mod mmap_deque;
 mod raw_vec;
+#[cfg(not(no_global_oom_handling))]
+mod rt_deque;
+#[cfg(not(no_global_oom_handling))]
+mod segmented_deque;
+mod segments;
+#[cfg(all(feature = "serde", not(no_global_oom_handling)))]
+mod serde_impls;
+#[cfg(not(no_global_oom_handling))]
+mod small_deque;
+#[cfg(all(feature = "nightly", not(no_global_oom_handling)))]
+mod spec_extend;
+#[cfg(feature = "nightly")]
+mod spec_hash;
+mod split;
+#[cfg(not(no_global_oom_handling))]
+pub mod spsc;
+#[cfg(not(no_global_oom_handling))]
+pub mod sync;
+#[cfg(not(no_global_oom_handling))]
+mod undo_redo;
+mod windows;
 
+#[cfg(not(no_global_oom_handling))]
+pub use aggregate_deque::AggregateDeque;
+#[cfg(not(no_global_oom_handling))]
+pub use arena_deque::{Arena, ArenaAltDeque};
+#[cfg(all(feature = "bumpalo", not(no_global_oom_handling)))]
+pub use arena_deque::BumpAltDeque;
+pub use array_deque::ArrayAltDeque;
+#[cfg(not(no_global_oom_handling))]
+pub use bounded_deque::BoundedAltDeque;
+pub use buffer_deque::BufferDeque;
+pub use chunks::{ArrayChunks, ChunkBy, Chunks, RChunks};
+#[cfg(not(no_global_oom_handling))]
+pub use concat::ConcatSource;
+#[cfg(not(no_global_oom_handling))]
+pub use cow_deque::CowAltDeque;
+#[cfg(not(no_global_oom_handling))]
+pub use deque::Deque;
 pub use drain::Drain;
+#[cfg(not(no_global_oom_handling))]
+pub use dual_stack_deque::DualStackDeque;
+#[cfg(not(no_global_oom_handling))]
+pub use exact_deque::ExactAltDeque;
+pub use extend_front::ExtendFront;
+#[cfg(not(no_global_oom_handling))]
+pub use gap_buffer::GapBuffer;
 pub use into_iter::IntoIter;
+#[cfg(not(no_global_oom_handling))]
+pub use min_max_queue::MinMaxQueue;
+#[cfg(all(feature = "mmap", not(no_global_oom_handling)))]
+pub use mmap_deque::MmapAltDeque;
+pub use raw_vec::TryReserveError;
+#[cfg(not(no_global_oom_handling))]
+pub use rt_deque::{RtAltDeque, RtIter, RtIterMut};
+#[cfg(not(no_global_oom_handling))]
+pub use segmented_deque::{SegmentedAltDeque, SegmentedIter};
+pub use segments::{Segments, SegmentsMut};
+#[cfg(not(no_global_oom_handling))]
+pub use small_deque::SmallAltDeque;
+pub use split::{Split, SplitInclusive};
+#[cfg(not(no_global_oom_handling))]
+pub use undo_redo::UndoRedo;
+pub use windows::{ArrayWindows, Windows};
 use raw_vec::RawVec;
 
-#[cfg(test)]
+#[cfg(all(test, loom, not(no_global_oom_handling)))]
+mod loom_tests;
+#[cfg(all(test, not(loom), not(no_global_oom_handling)))]
+mod model_tests;
+#[cfg(all(test, not(loom), not(no_global_oom_handling)))]
 mod tests;
 
 pub type Iter<'a, T> = Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>;
@@ -84,6 +277,41 @@ impl<'a, T> Drop for Dropper<'a, T> {
     }
 }
 
+/// A snapshot of the internal layout of an [`AltDeque`], returned by [`AltDeque::debug_layout`].
+///
+/// The back stack occupies the buffer from `0` to `head`, the front stack from `tail` to
+/// `capacity` and the free space in between is the gap.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StackLayout {
+    /// The capacity of the buffer.
+    pub capacity: usize,
+    /// The index where the next element of the back stack is written.
+    pub head: usize,
+    /// The index of the first element of the front stack.
+    pub tail: usize,
+    /// The number of elements in the front stack.
+    pub front_len: usize,
+    /// The number of elements in the back stack.
+    pub back_len: usize,
+    /// The number of free slots between the two stacks.
+    pub gap: usize,
+}
+
+/// Operation counters of an [`AltDeque`], returned by [`AltDeque::stats`].
+#[cfg(feature = "metrics")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Stats {
+    /// The number of times elements were moved from one stack to the other, either by a pop
+    /// on an empty stack or by [`AltDeque::rebalance`].
+    pub rebalances: u64,
+    /// The number of times the buffer was reallocated to grow or shrink.
+    pub reallocations: u64,
+    /// The number of elements moved by rebalances and reallocations.
+    pub elements_moved: u64,
+    /// The highest number of elements the deque has held.
+    pub peak_len: usize,
+}
+
 /// An alternative deque implementation to [`VecDeque`] in the standard library.
 ///
 /// See the [module-level documentation](./index.html) for more details.
@@ -100,6 +328,8 @@ pub struct AltDeque<T> {
     tail: usize,
     head: usize,
     buf: RawVec<T>,
+    #[cfg(feature = "metrics")]
+    stats: Stats,
 }
 
 impl<T> AltDeque<T> {
@@ -113,7 +343,7 @@ impl<T> AltDeque<T> {
     /// let deque: AltDeque<i32> = AltDeque::new();
     ///```
     pub fn new() -> Self {
-        Self::with_capacity(0)
+        Self::from_buf(RawVec::new())
     }
 
     /// Creates an empty deque with space for at least `capacity` elements.
@@ -126,9 +356,38 @@ impl<T> AltDeque<T> {
     /// let deque: AltDeque<i32> = AltDeque::with_capacity(10);
     /// assert!(deque.capacity() >= 10)
     ///```
+    #[cfg(not(no_global_oom_handling))]
     pub fn with_capacity(capacity: usize) -> Self {
-        let buf = RawVec::with_capacity(capacity);
-        Self { tail: buf.capacity(), head: 0, buf }
+        Self::from_buf(RawVec::with_capacity(capacity))
+    }
+
+    /// Creates an empty deque with space for at least `capacity` elements, returning an error
+    /// if the allocation fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the capacity overflows or the allocator reports a failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque: AltDeque<i32> = AltDeque::try_with_capacity(10).unwrap();
+    /// assert!(deque.capacity() >= 10);
+    /// assert!(AltDeque::<u64>::try_with_capacity(usize::MAX).is_err());
+    /// ```
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        RawVec::try_with_capacity(capacity).map(Self::from_buf)
+    }
+
+    fn from_buf(buf: RawVec<T>) -> Self {
+        Self {
+            tail: buf.capacity(),
+            head: 0,
+            buf,
+            #[cfg(feature = "metrics")]
+            stats: Stats::default(),
+        }
     }
 
     /// Returns the number of elements the deque can hold without reallocating.
@@ -145,6 +404,43 @@ impl<T> AltDeque<T> {
         self.cap()
     }
 
+    /// Returns the number of unused slots between the two stacks, that is how many elements can
+    /// be pushed to either end without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::with_capacity(10);
+    /// deque.extend([1, 2, 3]);
+    /// assert_eq!(deque.spare_capacity_len(), deque.capacity() - 3);
+    /// ```
+    #[inline]
+    pub fn spare_capacity_len(&self) -> usize {
+        self.tail - self.head
+    }
+
+    /// Returns the size in bytes of the heap block of the deque, which is zero if nothing is
+    /// allocated. The bytes of the unused slots are
+    /// `spare_capacity_len() * size_of::<T>()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque: AltDeque<u32> = AltDeque::new();
+    /// assert_eq!(deque.allocated_bytes(), 0);
+    /// deque.reserve_exact(10);
+    /// assert_eq!(deque.allocated_bytes(), 40);
+    /// ```
+    pub fn allocated_bytes(&self) -> usize {
+        if mem::size_of::<T>() == 0 {
+            0
+        } else {
+            self.cap() * mem::size_of::<T>()
+        }
+    }
+
     /// Returns the number of elements in the deque.
     ///
     /// # Examples
@@ -222,6 +518,143 @@ impl<T> AltDeque<T> {
         }
     }
 
+    /// Returns an iterator over the non-empty contiguous slices of the deque, in order.
+    ///
+    /// These are the slices of [`as_slices`] without the empty ones, so there are at most two of
+    /// them. This is convenient for running slice algorithms over the whole deque.
+    ///
+    /// [`as_slices`]: AltDeque::as_slices
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2], [3]));
+    /// assert!(deque.segments().eq([&[1, 2][..], &[3]]));
+    ///
+    /// let deque = AltDeque::from(([], [3]));
+    /// assert!(deque.segments().eq([&[3][..]]));
+    /// ```
+    pub fn segments(&self) -> Segments<'_, T> {
+        let (front, back) = self.as_slices();
+        Segments::new(front, back)
+    }
+
+    /// Returns an iterator over the non-empty contiguous mutable slices of the deque, in order.
+    ///
+    /// See the non-mutable version [`segments`] for details.
+    ///
+    /// [`segments`]: AltDeque::segments
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([3, 1], [2]));
+    /// deque.segments_mut().for_each(|segment| segment.sort());
+    /// assert_eq!(deque, [1, 3, 2]);
+    /// ```
+    pub fn segments_mut(&mut self) -> SegmentsMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+        SegmentsMut::new(front, back)
+    }
+
+    /// Returns the number of elements in the internal front stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2], [3]));
+    /// assert_eq!(deque.front_stack_len(), 2);
+    /// ```
+    #[inline]
+    pub fn front_stack_len(&self) -> usize {
+        self.cap() - self.tail
+    }
+
+    /// Returns the number of elements in the internal back stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2], [3]));
+    /// assert_eq!(deque.back_stack_len(), 1);
+    /// ```
+    #[inline]
+    pub fn back_stack_len(&self) -> usize {
+        self.head
+    }
+
+    /// Returns a snapshot of the internal layout of the deque. This is meant for debugging and
+    /// tuning, the layout after an operation is not part of the stability guarantees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::with_capacity(8);
+    /// deque.push_front(1);
+    /// deque.push_back(2);
+    /// deque.push_back(3);
+    /// let layout = deque.debug_layout();
+    /// assert_eq!(layout.front_len, 1);
+    /// assert_eq!(layout.back_len, 2);
+    /// assert_eq!(layout.head, 2);
+    /// assert_eq!(layout.tail, layout.capacity - 1);
+    /// assert_eq!(layout.gap, layout.capacity - 3);
+    /// ```
+    pub fn debug_layout(&self) -> StackLayout {
+        StackLayout {
+            capacity: self.cap(),
+            head: self.head,
+            tail: self.tail,
+            front_len: self.front_stack_len(),
+            back_len: self.back_stack_len(),
+            gap: self.tail - self.head,
+        }
+    }
+
+    /// Returns the operation counters of the deque.
+    ///
+    /// The counters start at zero when the deque is created and are not copied by [`clone`].
+    ///
+    /// [`clone`]: Clone::clone
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque: AltDeque<_> = (0..8).collect();
+    /// deque.pop_front();
+    /// deque.pop_back();
+    /// let stats = deque.stats();
+    /// assert_eq!(stats.rebalances, 1);
+    /// assert_eq!(stats.peak_len, 8);
+    /// ```
+    #[cfg(feature = "metrics")]
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// Resets all operation counters to zero. The peak length is set to the current length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque: AltDeque<_> = (0..8).collect();
+    /// deque.pop_back();
+    /// deque.reset_stats();
+    /// assert_eq!(deque.stats().rebalances, 0);
+    /// assert_eq!(deque.stats().peak_len, 7);
+    /// ```
+    #[cfg(feature = "metrics")]
+    pub fn reset_stats(&mut self) {
+        self.stats = Stats { peak_len: self.len(), ..Stats::default() };
+    }
+
     /// Provides a reference to the element at the given index.
     ///
     /// Element at index 0 is the front of the deque.
@@ -234,15 +667,9 @@ impl<T> AltDeque<T> {
     /// assert_eq!(deque.get(1), Some(&2));
     /// ```
     pub fn get(&self, index: usize) -> Option<&T> {
-        let front_len = self.cap() - self.tail;
-        if index < front_len + self.head {
-            if index < front_len {
-                // SAFETY: index < cap - tail -> tail <= tail + index < cap
-                unsafe { Some(&*self.buf_add(self.tail + index)) }
-            } else {
-                // SAFETY: index >= cap - tail && index < len -> 0 <= index - front_len < head
-                unsafe { Some(&*self.buf_add(index - front_len)) }
-            }
+        if index < self.len() {
+            // SAFETY: index < len
+            unsafe { Some(&*self.buf_add(self.physical_index(index))) }
         } else {
             None
         }
@@ -261,20 +688,154 @@ impl<T> AltDeque<T> {
     /// assert_eq!(deque.get(1), Some(&42));
     /// ```
     pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        let front_len = self.cap() - self.tail;
-        if index < front_len + self.head {
-            if index < front_len {
-                // SAFETY: index < cap - tail -> tail <= tail + index < cap
-                unsafe { Some(&mut *self.buf_add(self.tail + index)) }
-            } else {
-                // SAFETY: index >= cap - tail && index < len -> 0 <= index - front_len < head
-                unsafe { Some(&mut *self.buf_add(index - front_len)) }
-            }
+        if index < self.len() {
+            // SAFETY: index < len
+            unsafe { Some(&mut *self.buf_add(self.physical_index(index))) }
         } else {
             None
         }
     }
 
+    /// Provides a reference to the element at the given index taken modulo the length, or `None`
+    /// if the deque is empty.
+    ///
+    /// This treats the deque as a circular structure where the front follows the back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from([1, 2, 3]);
+    /// assert_eq!(deque.get_wrapping(1), Some(&2));
+    /// assert_eq!(deque.get_wrapping(4), Some(&2));
+    /// assert_eq!(AltDeque::<i32>::new().get_wrapping(4), None);
+    /// ```
+    pub fn get_wrapping(&self, index: usize) -> Option<&T> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        // SAFETY: index % len < len
+        unsafe { Some(&*self.buf_add(self.physical_index(index % len))) }
+    }
+
+    /// Provides a mutable reference to the element at the given index taken modulo the length,
+    /// or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([1, 2, 3]);
+    /// *deque.get_wrapping_mut(5).unwrap() += 40;
+    /// assert_eq!(deque, [1, 2, 43]);
+    /// ```
+    pub fn get_wrapping_mut(&mut self, index: usize) -> Option<&mut T> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        // SAFETY: index % len < len
+        unsafe { Some(&mut *self.buf_add(self.physical_index(index % len))) }
+    }
+
+    /// Provides a reference to the element at the given index counted from the back.
+    ///
+    /// Element at index 0 is the back of the deque, so this is the same as
+    /// `deque.get(deque.len() - 1 - index)` without the risk of an underflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2], [3]));
+    /// assert_eq!(deque.get_from_back(0), Some(&3));
+    /// assert_eq!(deque.get_from_back(2), Some(&1));
+    /// assert_eq!(deque.get_from_back(3), None);
+    /// ```
+    pub fn get_from_back(&self, index: usize) -> Option<&T> {
+        let idx = self.physical_index_from_back(index)?;
+        // SAFETY: physical_index_from_back only returns indices of elements
+        unsafe { Some(&*self.buf_add(idx)) }
+    }
+
+    /// Provides a mutable reference to the element at the given index counted from the back.
+    ///
+    /// Element at index 0 is the back of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([1, 2, 3]);
+    /// *deque.get_from_back_mut(1).unwrap() += 40;
+    /// assert_eq!(deque, [1, 42, 3]);
+    /// ```
+    pub fn get_from_back_mut(&mut self, index: usize) -> Option<&mut T> {
+        let idx = self.physical_index_from_back(index)?;
+        // SAFETY: physical_index_from_back only returns indices of elements
+        unsafe { Some(&mut *self.buf_add(idx)) }
+    }
+
+    /// Provides a reference to the element at the given index, without doing bounds checking.
+    ///
+    /// For a safe alternative see [`get`].
+    ///
+    /// [`get`]: AltDeque::get
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]* even if the
+    /// resulting reference is not used.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2], [3]));
+    /// unsafe {
+    ///     assert_eq!(deque.get_unchecked(1), &2);
+    ///     assert_eq!(deque.get_unchecked(2), &3);
+    /// }
+    /// ```
+    #[inline]
+    pub unsafe fn get_unchecked(&self, index: usize) -> &T {
+        debug_assert!(index < self.len(), "get_unchecked requires that the index is within bounds");
+        &*self.buf_add(self.physical_index(index))
+    }
+
+    /// Provides a mutable reference to the element at the given index, without doing bounds
+    /// checking.
+    ///
+    /// For a safe alternative see [`get_mut`].
+    ///
+    /// [`get_mut`]: AltDeque::get_mut
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]* even if the
+    /// resulting reference is not used.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([1, 2], [3]));
+    /// unsafe {
+    ///     *deque.get_unchecked_mut(2) += 40;
+    /// }
+    /// assert_eq!(deque, [1, 2, 43]);
+    /// ```
+    #[inline]
+    pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
+        debug_assert!(index < self.len(), "get_unchecked_mut requires that the index is within bounds");
+        &mut *self.buf_add(self.physical_index(index))
+    }
+
     /// Reserves the minimum capacity for at least `additional` more elements to be inserted in the
     /// given deque. Does nothing if the capacity is already sufficient.
     ///
@@ -292,6 +853,7 @@ impl<T> AltDeque<T> {
     /// deque.reserve_exact(10);
     /// assert!(deque.capacity() >= 14);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     pub fn reserve_exact(&mut self, additional: usize) {
         let old_cap = self.cap();
         let used_cap = self.len();
@@ -307,6 +869,9 @@ impl<T> AltDeque<T> {
     /// Reserves capacity for at least `additional` more elements to be inserted in the given
     /// deque. The collection may reserve more space to speculatively avoid frequent reallocations.
     ///
+    /// The free space always lies between the two stacks, so it can be used by pushes to either
+    /// end of the deque.
+    ///
     /// # Examples
     ///
     /// ```
@@ -315,6 +880,7 @@ impl<T> AltDeque<T> {
     /// deque.reserve(10);
     /// assert!(deque.capacity() >= 14);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     pub fn reserve(&mut self, additional: usize) {
         let old_cap = self.cap();
         let used_cap = self.len();
@@ -327,74 +893,198 @@ impl<T> AltDeque<T> {
         }
     }
 
-    /// Modifies the deque in-place so that `len()` is equal to `new_len`, either by removing
-    /// excess elements from the back or by appending elements generated by calling `generator` to
-    /// the back.
-    ///
-    /// # Examples
+    /// Tries to reserve the minimum capacity for at least `additional` more elements to be
+    /// inserted in the given deque. Does nothing if the capacity is already sufficient.
     ///
-    /// ```
-    /// # use altdeque::AltDeque;
-    /// let mut deque = AltDeque::from([1, 2]);
-    /// let mut i = 3;
+    /// This is the fallible version of [`reserve_exact`].
     ///
-    /// deque.resize_with(5, || { i += 1; i });
-    /// assert_eq!(deque, [1, 2, 4, 5, 6]);
+    /// [`reserve_exact`]: AltDeque::reserve_exact
     ///
-    /// deque.resize_with(3, || unreachable!());
-    /// assert_eq!(deque, [1, 2, 4]);
-    /// ```
-    pub fn resize_with<F>(&mut self, new_len: usize, generator: F)
-    where
-        F: FnMut() -> T,
-    {
-        let len = self.len();
-        if new_len > len {
-            self.extend(repeat_with(generator).take(new_len - len));
-        } else {
-            self.truncate(new_len);
-        }
-    }
-
-    /// Shrinks the capacity of the deque as much as possible.
+    /// # Errors
     ///
-    /// It will drop down as close as possible to the length but the allocator may still inform the
-    /// deque that there is space for a few more elements.
+    /// Returns an error if the capacity overflows or the allocator reports a failure. The deque
+    /// is left unchanged in that case.
     ///
     /// # Examples
     ///
     /// ```
     /// # use altdeque::AltDeque;
-    /// let mut deque = AltDeque::with_capacity(16);
-    /// deque.extend(0..4);
-    /// assert_eq!(deque.capacity(), 16);
-    /// deque.shrink_to_fit();
-    /// assert!(deque.capacity() >= 4);
+    /// let mut deque = AltDeque::from([1, 2, 3, 4]);
+    /// deque.try_reserve_exact(10).unwrap();
+    /// assert!(deque.capacity() >= 14);
+    /// assert!(deque.try_reserve_exact(usize::MAX).is_err());
+    /// assert_eq!(deque, [1, 2, 3, 4]);
     /// ```
-    pub fn shrink_to_fit(&mut self) {
-        self.shrink_to(0);
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let old_cap = self.cap();
+        let used_cap = self.len();
+        self.buf.try_reserve_exact(used_cap, additional)?;
+        // SAFETY: old_cap is correct
+        unsafe {
+            self.handle_capacity_increase(old_cap);
+        }
+        Ok(())
     }
 
-    /// Shrinks the capacity of the deque with a lower bound.
+    /// Tries to reserve capacity for at least `additional` more elements to be inserted in the
+    /// given deque. The collection may reserve more space to speculatively avoid frequent
+    /// reallocations.
     ///
-    /// The capacity will remain at least as large as both the length and the supplied lower bound.
+    /// This is the fallible version of [`reserve`].
     ///
-    /// If the current capacity is less than the lower bound, this is a no-op.
+    /// [`reserve`]: AltDeque::reserve
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the capacity overflows or the allocator reports a failure. The deque
+    /// is left unchanged in that case.
     ///
     /// # Examples
     ///
     /// ```
     /// # use altdeque::AltDeque;
-    /// let mut deque = AltDeque::with_capacity(16);
-    /// deque.extend(0..4);
-    /// assert_eq!(deque.capacity(), 16);
+    /// let mut deque = AltDeque::from([1, 2, 3, 4]);
+    /// deque.try_reserve(10).unwrap();
+    /// assert!(deque.capacity() >= 14);
+    /// assert!(deque.try_reserve(usize::MAX).is_err());
+    /// assert_eq!(deque, [1, 2, 3, 4]);
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let old_cap = self.cap();
+        let used_cap = self.len();
+        self.buf.try_reserve(used_cap, additional)?;
+        // SAFETY: old_cap is correct
+        unsafe {
+            self.handle_capacity_increase(old_cap);
+        }
+        Ok(())
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be pushed to the front of the
+    /// deque without reallocating.
+    ///
+    /// The free space of the deque always lies between the two stacks and is shared by both ends,
+    /// so this is the same as [`reserve`]. It exists to make the intent clear at the call site.
+    ///
+    /// [`reserve`]: AltDeque::reserve
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([1, 2, 3, 4]);
+    /// deque.reserve_front(10);
+    /// let cap = deque.capacity();
+    /// (0..10).for_each(|i| deque.push_front(i));
+    /// assert_eq!(deque.capacity(), cap);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[inline]
+    pub fn reserve_front(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be pushed to the back of the
+    /// deque without reallocating.
+    ///
+    /// The free space of the deque always lies between the two stacks and is shared by both ends,
+    /// so this is the same as [`reserve`]. It exists to make the intent clear at the call site.
+    ///
+    /// [`reserve`]: AltDeque::reserve
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([1, 2, 3, 4]);
+    /// deque.reserve_back(10);
+    /// let cap = deque.capacity();
+    /// (0..10).for_each(|i| deque.push_back(i));
+    /// assert_eq!(deque.capacity(), cap);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[inline]
+    pub fn reserve_back(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    /// Modifies the deque in-place so that `len()` is equal to `new_len`, either by removing
+    /// excess elements from the back or by appending elements generated by calling `generator` to
+    /// the back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([1, 2]);
+    /// let mut i = 3;
+    ///
+    /// deque.resize_with(5, || { i += 1; i });
+    /// assert_eq!(deque, [1, 2, 4, 5, 6]);
+    ///
+    /// deque.resize_with(3, || unreachable!());
+    /// assert_eq!(deque, [1, 2, 4]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn resize_with<F>(&mut self, new_len: usize, generator: F)
+    where
+        F: FnMut() -> T,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(iter::repeat_with(generator).take(new_len - len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    /// Shrinks the capacity of the deque as much as possible.
+    ///
+    /// It will drop down as close as possible to the length but the allocator may still inform the
+    /// deque that there is space for a few more elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::with_capacity(16);
+    /// deque.extend(0..4);
+    /// assert_eq!(deque.capacity(), 16);
+    /// deque.shrink_to_fit();
+    /// assert!(deque.capacity() >= 4);
+    ///
+    /// // an empty deque releases its buffer entirely
+    /// deque.clear();
+    /// deque.shrink_to_fit();
+    /// assert_eq!(deque.capacity(), 0);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_to(0);
+    }
+
+    /// Shrinks the capacity of the deque with a lower bound.
+    ///
+    /// The capacity will remain at least as large as both the length and the supplied lower bound.
+    ///
+    /// If the current capacity is less than the lower bound, this is a no-op.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::with_capacity(16);
+    /// deque.extend(0..4);
+    /// assert_eq!(deque.capacity(), 16);
     /// deque.shrink_to(7);
     /// assert!(deque.capacity() >= 7);
     /// deque.shrink_to(0);
     /// assert!(deque.capacity() >= 4);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     pub fn shrink_to(&mut self, min_capacity: usize) {
-        if min_capacity >= self.capacity() {
+        // the capacity of zero-sized types is always usize::MAX
+        if mem::size_of::<T>() == 0 || min_capacity >= self.capacity() {
             return;
         }
 
@@ -407,17 +1097,13 @@ impl<T> AltDeque<T> {
             self.copy(self.tail, new_tail, front_len);
         }
         self.tail = new_tail;
+        // the buffer is shrunk to exactly target_cap, so the front stack is already in place
+        trace_event!(old_capacity = self.cap(), new_capacity = target_cap, moved = front_len, "shrinking buffer");
         self.buf.shrink_to_fit(target_cap);
-
-        if self.cap() > target_cap {
-            // oh no, more capacity remained than we requested
-            let new_tail = self.cap() - front_len;
-            // SAFETY: cap > target_cap -> we can move front_len elements from tail to new_tail
-            unsafe {
-                self.copy(self.tail, new_tail, front_len);
-            }
-            self.tail = new_tail;
-        }
+        debug_assert_eq!(self.cap(), target_cap);
+        record!(self, reallocations += 1);
+        record!(self, elements_moved += front_len);
+        check_invariants!(self);
     }
 
     /// Shortens the deque, keeping the first `len` elements and dropping the rest.
@@ -481,6 +1167,7 @@ impl<T> AltDeque<T> {
                 }
             }
         }
+        check_invariants!(self);
     }
 
     /// Clears the deque, removing all elements.
@@ -526,6 +1213,118 @@ impl<T> AltDeque<T> {
         a.contains(x) || b.contains(x)
     }
 
+    /// Returns the index of the first element equal to the given value, or `None` if there is
+    /// none.
+    ///
+    /// For `AltDeque<u8>` with the `memchr` feature enabled, [`find_byte`] is a vectorized
+    /// alternative.
+    ///
+    /// [`find_byte`]: AltDeque::find_byte
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2], [3, 2]));
+    ///
+    /// assert_eq!(deque.index_of(&2), Some(1));
+    /// assert_eq!(deque.index_of(&3), Some(2));
+    /// assert_eq!(deque.index_of(&4), None);
+    /// ```
+    pub fn index_of(&self, x: &T) -> Option<usize>
+    where
+        T: PartialEq<T>,
+    {
+        self.index_of_by(|el| el == x)
+    }
+
+    /// Returns the index of the last element equal to the given value, or `None` if there is
+    /// none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2], [3, 2]));
+    ///
+    /// assert_eq!(deque.rindex_of(&2), Some(3));
+    /// assert_eq!(deque.rindex_of(&1), Some(0));
+    /// assert_eq!(deque.rindex_of(&4), None);
+    /// ```
+    pub fn rindex_of(&self, x: &T) -> Option<usize>
+    where
+        T: PartialEq<T>,
+    {
+        self.rindex_of_by(|el| el == x)
+    }
+
+    /// Returns the index of the first element for which the predicate returns `true`, or `None`
+    /// if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2], [3, 4]));
+    ///
+    /// assert_eq!(deque.index_of_by(|&x| x > 2), Some(2));
+    /// assert_eq!(deque.index_of_by(|&x| x > 4), None);
+    /// ```
+    pub fn index_of_by<F>(&self, mut f: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let (a, b) = self.as_slices();
+        match a.iter().position(&mut f) {
+            Some(idx) => Some(idx),
+            None => b.iter().position(f).map(|idx| a.len() + idx),
+        }
+    }
+
+    /// Returns the index of the last element for which the predicate returns `true`, or `None`
+    /// if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2], [3, 4]));
+    ///
+    /// assert_eq!(deque.rindex_of_by(|&x| x < 3), Some(1));
+    /// assert_eq!(deque.rindex_of_by(|&x| x < 1), None);
+    /// ```
+    pub fn rindex_of_by<F>(&self, mut f: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let (a, b) = self.as_slices();
+        match b.iter().rposition(&mut f) {
+            Some(idx) => Some(a.len() + idx),
+            None => a.iter().rposition(f),
+        }
+    }
+
+    /// Returns the index of the first element equal to the given value, or `None` if there is
+    /// none. This is the same as [`index_of`], and for `AltDeque<u8>` with the `memchr` feature
+    /// enabled, [`find_byte`] is still the vectorized alternative.
+    ///
+    /// [`index_of`]: AltDeque::index_of
+    /// [`find_byte`]: AltDeque::find_byte
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2], [3, 2]));
+    /// assert_eq!(deque.position(&3), Some(2));
+    /// ```
+    pub fn position(&self, x: &T) -> Option<usize>
+    where
+        T: PartialEq<T>,
+    {
+        self.index_of(x)
+    }
+
     /// Provides a reference to the front element, or `None` if the deque is empty.
     ///
     /// # Examples
@@ -621,13 +1420,101 @@ impl<T> AltDeque<T> {
         }
     }
 
+    /// Returns a reference to the first `N` elements as an array, or `None` if the deque has fewer
+    /// than `N` elements or they are not stored contiguously.
+    ///
+    /// The first `N` elements are contiguous if they all lie in one of the slices returned by
+    /// [`as_slices`]. Call [`make_contiguous`] first to guarantee this, or use
+    /// [`front_chunk_copied`] for `Copy` elements.
+    ///
+    /// [`as_slices`]: AltDeque::as_slices
+    /// [`make_contiguous`]: AltDeque::make_contiguous
+    /// [`front_chunk_copied`]: AltDeque::front_chunk_copied
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2, 3], [4]));
+    /// assert_eq!(deque.front_chunk::<2>(), Some(&[1, 2]));
+    /// assert_eq!(deque.front_chunk::<4>(), None);
+    /// ```
+    pub fn front_chunk<const N: usize>(&self) -> Option<&[T; N]> {
+        let (a, b) = self.as_slices();
+        if a.is_empty() { b.first_chunk() } else { a.first_chunk() }
+    }
+
+    /// Returns a mutable reference to the first `N` elements as an array, or `None` if the deque
+    /// has fewer than `N` elements or they are not stored contiguously.
+    ///
+    /// See [`front_chunk`] for when the elements are contiguous.
+    ///
+    /// [`front_chunk`]: AltDeque::front_chunk
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([1, 2, 3]);
+    /// if let Some(chunk) = deque.front_chunk_mut::<2>() {
+    ///     chunk.swap(0, 1);
+    /// }
+    /// assert_eq!(deque, [2, 1, 3]);
+    /// ```
+    pub fn front_chunk_mut<const N: usize>(&mut self) -> Option<&mut [T; N]> {
+        let (a, b) = self.as_mut_slices();
+        if a.is_empty() { b.first_chunk_mut() } else { a.first_chunk_mut() }
+    }
+
+    /// Returns a reference to the last `N` elements as an array, or `None` if the deque has fewer
+    /// than `N` elements or they are not stored contiguously.
+    ///
+    /// See [`front_chunk`] for when the elements are contiguous.
+    ///
+    /// [`front_chunk`]: AltDeque::front_chunk
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1], [2, 3, 4]));
+    /// assert_eq!(deque.back_chunk::<2>(), Some(&[3, 4]));
+    /// assert_eq!(deque.back_chunk::<4>(), None);
+    /// ```
+    pub fn back_chunk<const N: usize>(&self) -> Option<&[T; N]> {
+        let (a, b) = self.as_slices();
+        if b.is_empty() { a.last_chunk() } else { b.last_chunk() }
+    }
+
+    /// Returns a mutable reference to the last `N` elements as an array, or `None` if the deque
+    /// has fewer than `N` elements or they are not stored contiguously.
+    ///
+    /// See [`front_chunk`] for when the elements are contiguous.
+    ///
+    /// [`front_chunk`]: AltDeque::front_chunk
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([1, 2, 3]);
+    /// if let Some(chunk) = deque.back_chunk_mut::<2>() {
+    ///     chunk.swap(0, 1);
+    /// }
+    /// assert_eq!(deque, [1, 3, 2]);
+    /// ```
+    pub fn back_chunk_mut<const N: usize>(&mut self) -> Option<&mut [T; N]> {
+        let (a, b) = self.as_mut_slices();
+        if b.is_empty() { a.last_chunk_mut() } else { b.last_chunk_mut() }
+    }
+
     /// Removes the first element and returns it, or `None` if the deque is empty.
     ///
-    /// Be careful when also using [`pop_back`]. Popping elements from both sides can be very
-    /// inefficient. Use [`VecDeque`] if in doubt.
+    /// If the front stack is empty, half of the other stack is moved over first. This takes
+    /// *O(n)* time, but leaves enough elements on both stacks that mixing calls with
+    /// [`pop_back`] stays amortized *O(1)*.
     ///
     /// [`pop_back`]: AltDeque::pop_back
-    /// [`VecDeque`]: std::collections::VecDeque
     ///
     /// # Examples
     ///
@@ -641,18 +1528,25 @@ impl<T> AltDeque<T> {
         if self.tail != self.cap() {
             let tail = self.tail;
             self.tail += 1;
+            check_invariants!(self);
             // SAFETY: tail < cap
             unsafe { Some(ptr::read(self.buf_add(tail))) }
         } else if self.head != 0 {
-            self.tail = self.cap() - self.head + 1;
-            // SAFETY: head > 0 && tail = cap - (head - 1)
+            let len = self.head;
+            let front_len = len / 2;
+            // SAFETY: head > 0 and the front stack is empty
             unsafe {
-                // ignore the first element because we return it anyway
-                self.copy(1, self.tail, self.head - 1);
+                let value = ptr::read(self.buf_add(0));
+                // ignore the first element because we return it
+                split_back_stack(self.buf_add(0), self.cap(), 1, len, front_len);
+                self.tail = self.cap() - front_len;
+                self.head = len - 1 - front_len;
+                record!(self, rebalances += 1);
+                record!(self, elements_moved += len - 1);
+                trace_event!(moved = len - 1, "split back stack to pop from the front");
+                check_invariants!(self);
+                Some(value)
             }
-            self.head = 0;
-            // SAFETY: old head was > 0
-            unsafe { Some(ptr::read(self.buf_add(0))) }
         } else {
             None
         }
@@ -660,11 +1554,11 @@ impl<T> AltDeque<T> {
 
     /// Removes the last element from the deque and returns it, or `None` if the deque is empty.
     ///
-    /// Be careful when also using [`pop_front`]. Popping elements from both sides can be very
-    /// inefficient. Use [`VecDeque`] if in doubt.
+    /// If the back stack is empty, half of the other stack is moved over first. This takes
+    /// *O(n)* time, but leaves enough elements on both stacks that mixing calls with
+    /// [`pop_front`] stays amortized *O(1)*.
     ///
     /// [`pop_front`]: AltDeque::pop_front
-    /// [`VecDeque`]: std::collections::VecDeque
     ///
     /// # Examples
     ///
@@ -677,65 +1571,414 @@ impl<T> AltDeque<T> {
     pub fn pop_back(&mut self) -> Option<T> {
         if self.head != 0 {
             self.head -= 1;
+            check_invariants!(self);
             // SAFETY: old head was > 0
             unsafe { Some(ptr::read(self.buf_add(self.head))) }
         } else if self.tail != self.cap() {
-            self.head = self.cap() - self.tail - 1;
-            // SAFETY: cap - tail < head
+            let len = self.cap() - self.tail;
+            let back_len = len / 2;
+            // SAFETY: tail < cap and the back stack is empty
             unsafe {
-                // ignore the last element because we return it anyway
-                self.copy(self.tail, 0, self.head);
+                let value = ptr::read(self.buf_add(self.cap() - 1));
+                // ignore the last element because we return it
+                split_front_stack(self.buf_add(0), self.cap(), self.tail, self.cap() - 1, back_len);
+                self.head = back_len;
+                self.tail = self.cap() - (len - 1 - back_len);
+                record!(self, rebalances += 1);
+                record!(self, elements_moved += len - 1);
+                trace_event!(moved = len - 1, "split front stack to pop from the back");
+                check_invariants!(self);
+                Some(value)
             }
-            self.tail = self.cap();
-            // SAFETY: old tail was < cap
-            unsafe { Some(ptr::read(self.buf_add(self.cap() - 1))) }
         } else {
             None
         }
     }
 
-    /// Prepends an element to the front of the deque.
+    /// Moves up to `buf.len()` elements from the front of the deque into `buf` and returns how
+    /// many were moved. The first returned number of slots of `buf` are initialized afterwards,
+    /// in deque order.
+    ///
+    /// The elements are moved with at most two bulk copies. If the front stack runs dry, the
+    /// remaining elements are split between both stacks once, like [`pop_front`] does.
+    ///
+    /// [`pop_front`]: AltDeque::pop_front
     ///
     /// # Examples
     ///
     /// ```
     /// # use altdeque::AltDeque;
-    /// let mut deque = AltDeque::new();
-    /// deque.push_front(1);
-    /// deque.push_front(2);
-    /// deque.push_front(3);
-    /// assert_eq!(deque, [3, 2, 1]);
-    pub fn push_front(&mut self, value: T) {
-        if self.is_full() {
-            self.grow();
-        }
-        self.tail -= 1;
-        // SAFETY: old tail was > 0 because buf is not full
+    /// # use std::mem::MaybeUninit;
+    /// let mut deque = AltDeque::from(([1, 2], [3, 4]));
+    /// let mut buf = [MaybeUninit::uninit(); 3];
+    /// assert_eq!(deque.pop_front_into(&mut buf), 3);
+    /// // SAFETY: the first 3 slots are initialized
+    /// let popped = unsafe { buf.map(|x| x.assume_init()) };
+    /// assert_eq!(popped, [1, 2, 3]);
+    /// assert_eq!(deque, [4]);
+    /// ```
+    pub fn pop_front_into(&mut self, buf: &mut [MaybeUninit<T>]) -> usize {
+        let count = cmp::min(buf.len(), self.len());
+        let cap = self.cap();
+        let from_front = cmp::min(count, cap - self.tail);
+        let from_back = count - from_front;
+        let dst = buf.as_mut_ptr() as *mut T;
+        // SAFETY: [tail, tail + from_front) is part of the front stack and, if it is exhausted,
+        // [0, from_back) is part of the back stack. buf has room for count elements.
         unsafe {
-            ptr::write(self.buf_add(self.tail), value);
+            ptr::copy_nonoverlapping(self.buf_add(self.tail), dst, from_front);
+            self.tail += from_front;
+            if from_back != 0 {
+                ptr::copy_nonoverlapping(self.buf_add(0), dst.add(from_front), from_back);
+                let len = self.head - from_back;
+                let front_len = len / 2;
+                split_back_stack(self.buf_add(0), cap, from_back, self.head, front_len);
+                self.tail = cap - front_len;
+                self.head = len - front_len;
+                record!(self, rebalances += 1);
+                record!(self, elements_moved += len);
+                trace_event!(moved = len, "split back stack to pop from the front");
+            }
         }
+        check_invariants!(self);
+        count
     }
 
-    /// Appends an element to the back of the deque.
+    /// Moves up to `buf.len()` elements from the back of the deque into `buf` and returns how
+    /// many were moved. The first returned number of slots of `buf` are initialized afterwards,
+    /// in deque order, so the last slot holds the former back element.
+    ///
+    /// The elements are moved with at most two bulk copies. If the back stack runs dry, the
+    /// remaining elements are split between both stacks once, like [`pop_back`] does.
+    ///
+    /// [`pop_back`]: AltDeque::pop_back
     ///
     /// # Examples
     ///
     /// ```
     /// # use altdeque::AltDeque;
-    /// let mut deque = AltDeque::new();
-    /// deque.push_back(1);
-    /// deque.push_back(2);
-    /// deque.push_back(3);
-    /// assert_eq!(deque, [1, 2, 3]);
-    pub fn push_back(&mut self, value: T) {
-        if self.is_full() {
-            self.grow();
-        }
-        // SAFETY: head < tail because buf is not full
+    /// # use std::mem::MaybeUninit;
+    /// let mut deque = AltDeque::from(([1, 2], [3, 4]));
+    /// let mut buf = [MaybeUninit::uninit(); 3];
+    /// assert_eq!(deque.pop_back_into(&mut buf), 3);
+    /// // SAFETY: the first 3 slots are initialized
+    /// let popped = unsafe { buf.map(|x| x.assume_init()) };
+    /// assert_eq!(popped, [2, 3, 4]);
+    /// assert_eq!(deque, [1]);
+    /// ```
+    pub fn pop_back_into(&mut self, buf: &mut [MaybeUninit<T>]) -> usize {
+        let count = cmp::min(buf.len(), self.len());
+        let cap = self.cap();
+        let from_back = cmp::min(count, self.head);
+        let from_front = count - from_back;
+        let dst = buf.as_mut_ptr() as *mut T;
+        // SAFETY: [head - from_back, head) is part of the back stack and, if it is exhausted,
+        // [cap - from_front, cap) is part of the front stack. buf has room for count elements.
         unsafe {
-            ptr::write(self.buf_add(self.head), value);
+            self.head -= from_back;
+            ptr::copy_nonoverlapping(self.buf_add(self.head), dst.add(from_front), from_back);
+            if from_front != 0 {
+                let end = cap - from_front;
+                ptr::copy_nonoverlapping(self.buf_add(end), dst, from_front);
+                let len = end - self.tail;
+                let back_len = len / 2;
+                split_front_stack(self.buf_add(0), cap, self.tail, end, back_len);
+                self.head = back_len;
+                self.tail = cap - (len - back_len);
+                record!(self, rebalances += 1);
+                record!(self, elements_moved += len);
+                trace_event!(moved = len, "split front stack to pop from the back");
+            }
         }
-        self.head += 1;
+        check_invariants!(self);
+        count
+    }
+
+    /// Removes the first `N` elements and returns them as an array, or `None` if the deque has
+    /// fewer than `N` elements.
+    ///
+    /// This works like [`pop_front_into`], so the elements are moved with at most two bulk
+    /// copies.
+    ///
+    /// [`pop_front_into`]: AltDeque::pop_front_into
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([1, 2], [3, 4]));
+    /// assert_eq!(deque.pop_front_chunk(), Some([1, 2, 3]));
+    /// assert_eq!(deque.pop_front_chunk::<2>(), None);
+    /// assert_eq!(deque, [4]);
+    /// ```
+    pub fn pop_front_chunk<const N: usize>(&mut self) -> Option<[T; N]> {
+        if self.len() < N {
+            return None;
+        }
+        let mut chunk = [const { MaybeUninit::uninit() }; N];
+        self.pop_front_into(&mut chunk);
+        // SAFETY: the deque had at least N elements, so all of chunk is initialized
+        unsafe { Some(ptr::read(chunk.as_ptr() as *const [T; N])) }
+    }
+
+    /// Removes the last `N` elements and returns them as an array in deque order, or `None` if
+    /// the deque has fewer than `N` elements.
+    ///
+    /// This works like [`pop_back_into`], so the elements are moved with at most two bulk
+    /// copies.
+    ///
+    /// [`pop_back_into`]: AltDeque::pop_back_into
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([1, 2], [3, 4]));
+    /// assert_eq!(deque.pop_back_chunk(), Some([2, 3, 4]));
+    /// assert_eq!(deque.pop_back_chunk::<2>(), None);
+    /// assert_eq!(deque, [1]);
+    /// ```
+    pub fn pop_back_chunk<const N: usize>(&mut self) -> Option<[T; N]> {
+        if self.len() < N {
+            return None;
+        }
+        let mut chunk = [const { MaybeUninit::uninit() }; N];
+        self.pop_back_into(&mut chunk);
+        // SAFETY: the deque had at least N elements, so all of chunk is initialized
+        unsafe { Some(ptr::read(chunk.as_ptr() as *const [T; N])) }
+    }
+
+    /// Returns an iterator that removes elements from the front of the deque as long as `pred`
+    /// returns `true` for them.
+    ///
+    /// Elements are only removed when the iterator is advanced, so it can be stopped early, and
+    /// consuming it with [`count`] removes all matching elements. The first element for which
+    /// `pred` returns `false` stays in the deque.
+    ///
+    /// [`count`]: Iterator::count
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([1, 2, 3, 4, 1]);
+    /// let expired: Vec<_> = deque.pop_front_while(|&x| x < 3).collect();
+    /// assert_eq!(expired, [1, 2]);
+    /// assert_eq!(deque, [3, 4, 1]);
+    /// ```
+    pub fn pop_front_while<'a, F>(&'a mut self, mut pred: F) -> impl Iterator<Item = T> + 'a
+    where
+        F: FnMut(&T) -> bool + 'a,
+    {
+        iter::from_fn(move || {
+            if self.front().is_some_and(&mut pred) {
+                self.pop_front()
+            } else {
+                None
+            }
+        })
+        .fuse()
+    }
+
+    /// Returns an iterator that removes elements from the back of the deque as long as `pred`
+    /// returns `true` for them. The elements are yielded back to front.
+    ///
+    /// Elements are only removed when the iterator is advanced, so it can be stopped early, and
+    /// consuming it with [`count`] removes all matching elements. The last element for which
+    /// `pred` returns `false` stays in the deque.
+    ///
+    /// [`count`]: Iterator::count
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([4, 1, 2, 3, 4]);
+    /// assert_eq!(deque.pop_back_while(|&x| x > 2).count(), 2);
+    /// assert_eq!(deque, [4, 1, 2]);
+    /// ```
+    pub fn pop_back_while<'a, F>(&'a mut self, mut pred: F) -> impl Iterator<Item = T> + 'a
+    where
+        F: FnMut(&T) -> bool + 'a,
+    {
+        iter::from_fn(move || {
+            if self.back().is_some_and(&mut pred) {
+                self.pop_back()
+            } else {
+                None
+            }
+        })
+        .fuse()
+    }
+
+    /// Prepends an element to the front of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::new();
+    /// deque.push_front(1);
+    /// deque.push_front(2);
+    /// deque.push_front(3);
+    /// assert_eq!(deque, [3, 2, 1]);
+    #[cfg(not(no_global_oom_handling))]
+    pub fn push_front(&mut self, value: T) {
+        if self.is_full() {
+            self.grow();
+        }
+        self.tail -= 1;
+        // SAFETY: old tail was > 0 because buf is not full
+        unsafe {
+            ptr::write(self.buf_add(self.tail), value);
+        }
+        record!(self, peak_len);
+        check_invariants!(self);
+    }
+
+    /// Appends an element to the back of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::new();
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// deque.push_back(3);
+    /// assert_eq!(deque, [1, 2, 3]);
+    #[cfg(not(no_global_oom_handling))]
+    pub fn push_back(&mut self, value: T) {
+        if self.is_full() {
+            self.grow();
+        }
+        // SAFETY: head < tail because buf is not full
+        unsafe {
+            ptr::write(self.buf_add(self.head), value);
+        }
+        self.head += 1;
+        record!(self, peak_len);
+        check_invariants!(self);
+    }
+
+    /// Prepends an element to the front of the deque if there is spare capacity, otherwise the
+    /// element is returned.
+    ///
+    /// Unlike [`push_front`] this never allocates, so it can be combined with [`try_reserve`] to
+    /// handle allocation failures.
+    ///
+    /// [`push_front`]: AltDeque::push_front
+    /// [`try_reserve`]: AltDeque::try_reserve
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::try_with_capacity(1).unwrap();
+    /// let cap = deque.capacity();
+    /// for i in 0..cap {
+    ///     assert_eq!(deque.push_front_within_capacity(i), Ok(()));
+    /// }
+    /// assert_eq!(deque.push_front_within_capacity(cap), Err(cap));
+    /// assert_eq!(deque.len(), cap);
+    /// ```
+    pub fn push_front_within_capacity(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        self.tail -= 1;
+        // SAFETY: old tail was > 0 because buf is not full
+        unsafe {
+            ptr::write(self.buf_add(self.tail), value);
+        }
+        record!(self, peak_len);
+        check_invariants!(self);
+        Ok(())
+    }
+
+    /// Appends an element to the back of the deque if there is spare capacity, otherwise the
+    /// element is returned.
+    ///
+    /// Unlike [`push_back`] this never allocates, so it can be combined with [`try_reserve`] to
+    /// handle allocation failures.
+    ///
+    /// [`push_back`]: AltDeque::push_back
+    /// [`try_reserve`]: AltDeque::try_reserve
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::new();
+    /// assert_eq!(deque.push_back_within_capacity(1), Err(1));
+    /// deque.try_reserve(1).unwrap();
+    /// assert_eq!(deque.push_back_within_capacity(1), Ok(()));
+    /// assert_eq!(deque, [1]);
+    /// ```
+    pub fn push_back_within_capacity(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        // SAFETY: head < tail because buf is not full
+        unsafe {
+            ptr::write(self.buf_add(self.head), value);
+        }
+        self.head += 1;
+        record!(self, peak_len);
+        check_invariants!(self);
+        Ok(())
+    }
+
+    /// Prepends all elements of an array to the front of the deque, keeping their order.
+    ///
+    /// Capacity is reserved once and the array is moved with a single copy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([3]);
+    /// deque.push_front_chunk([1, 2]);
+    /// assert_eq!(deque, [1, 2, 3]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn push_front_chunk<const N: usize>(&mut self, chunk: [T; N]) {
+        self.reserve(N);
+        let chunk = ManuallyDrop::new(chunk);
+        self.tail -= N;
+        // SAFETY: we reserved space for N elements, so [tail, tail + N) was free, and chunk is
+        // not dropped
+        unsafe {
+            ptr::copy_nonoverlapping(chunk.as_ptr(), self.buf_add(self.tail), N);
+        }
+        record!(self, peak_len);
+        check_invariants!(self);
+    }
+
+    /// Appends all elements of an array to the back of the deque, keeping their order.
+    ///
+    /// Capacity is reserved once and the array is moved with a single copy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([1]);
+    /// deque.push_back_chunk([2, 3]);
+    /// assert_eq!(deque, [1, 2, 3]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn push_back_chunk<const N: usize>(&mut self, chunk: [T; N]) {
+        self.reserve(N);
+        let chunk = ManuallyDrop::new(chunk);
+        // SAFETY: we reserved space for N elements, so [head, head + N) is free, and chunk is
+        // not dropped
+        unsafe {
+            ptr::copy_nonoverlapping(chunk.as_ptr(), self.buf_add(self.head), N);
+        }
+        self.head += N;
+        record!(self, peak_len);
+        check_invariants!(self);
     }
 
     /// Swaps elements at indices `i` and `j`.
@@ -780,6 +2023,53 @@ impl<T> AltDeque<T> {
         }
     }
 
+    /// Swaps the elements in the ranges `a` and `b`, which must have the same length and must not
+    /// overlap.
+    ///
+    /// The ranges are swapped in at most three contiguous parts, as each of them can span the
+    /// boundary between the internal front and back stack.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a range is out of bounds, if the ranges have different lengths or if they
+    /// overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([1, 2, 3], [4, 5, 6]));
+    /// deque.swap_ranges(0..2, 3..5);
+    /// assert_eq!(deque, [4, 5, 3, 1, 2, 6]);
+    /// ```
+    pub fn swap_ranges(&mut self, a: Range<usize>, b: Range<usize>) {
+        let len = self.len();
+        let a = simplify_range(a, len);
+        let b = simplify_range(b, len);
+        assert!(a.len() == b.len(), "ranges must have the same length");
+        assert!(a.is_empty() || a.end <= b.start || b.end <= a.start, "ranges must not overlap");
+
+        let front_len = self.cap() - self.tail;
+        // the number of elements from index to the end of its stack
+        let contiguous = |index: usize| if index < front_len { front_len - index } else { len - index };
+        let (mut i, mut j, mut remaining) = (a.start, b.start, a.len());
+        while remaining > 0 {
+            let count = remaining.min(contiguous(i)).min(contiguous(j));
+            // SAFETY: both parts are in bounds, contiguous and do not overlap, as the ranges are
+            // disjoint
+            unsafe {
+                ptr::swap_nonoverlapping(
+                    self.buf_add(self.physical_index(i)),
+                    self.buf_add(self.physical_index(j)),
+                    count,
+                );
+            }
+            i += count;
+            j += count;
+            remaining -= count;
+        }
+    }
+
     /// Removes an element from anywhere in the deque and returns it, or `None` if the deque is
     /// empty. The removed element is replaced with the front element.
     ///
@@ -833,11 +2123,18 @@ impl<T> AltDeque<T> {
     /// of bounds. Either all the elements before or after the removed one will be shifted one
     /// place to close the gap.
     ///
+    /// Only the elements between the removed one and the free space in the middle of the buffer
+    /// are shifted: the ones before it if it lies in the front stack, the ones after it if it
+    /// lies in the back stack. The other side of a stack is fixed to its end of the buffer, so
+    /// removing an element next to the boundary between the two stacks shifts almost the whole
+    /// stack. Use [`rebalance`] first if many elements are removed around the same position.
+    ///
     /// This preserves ordering, but can take up to *O(n)*. If you do not care about ordering use
     /// [`swap_remove_front`] or [`swap_remove_back`].
     ///
     /// Element at index 0 is the front of the queue.
     ///
+    /// [`rebalance`]: AltDeque::rebalance
     /// [`swap_remove_back`]: AltDeque::swap_remove_back
     /// [`swap_remove_front`]: AltDeque::swap_remove_front
     ///
@@ -860,6 +2157,7 @@ impl<T> AltDeque<T> {
                 self.copy(self.tail, new_tail, index);
             }
             self.tail = new_tail;
+            check_invariants!(self);
             Some(el)
         } else {
             index -= front_len;
@@ -871,6 +2169,7 @@ impl<T> AltDeque<T> {
                     self.head -= 1;
                     self.copy(index + 1, index, self.head - index);
                 }
+                check_invariants!(self);
                 Some(el)
             } else {
                 None
@@ -895,18 +2194,21 @@ impl<T> AltDeque<T> {
     /// deque.insert(1, 5);
     /// assert_eq!(deque, [1, 5, 2, 3]);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     pub fn insert(&mut self, mut index: usize, value: T) {
         if self.is_full() {
             self.grow();
         }
 
         let front_len = self.cap() - self.tail;
-        if index < front_len {
-            // SAFETY: tail > 0 (buf !full) && index < front_len -> all elements from tail to tail + index (including)
-            // can be moved one to the left. The spot at tail + index is no free and can be written to
+        // at the boundary between the stacks the element can be added to either of them, so
+        // the smaller one is shifted
+        if index < front_len || (index == front_len && front_len < self.head) {
+            // SAFETY: tail > 0 (buf !full) && index <= front_len -> all elements from tail to tail + index (not including)
+            // can be moved one to the left. The spot at tail + index is then free and can be written to
             unsafe {
                 let new_tail = self.tail - 1;
-                self.copy(self.tail, new_tail, index + 1);
+                self.copy(self.tail, new_tail, index);
                 self.tail = new_tail;
                 ptr::write(self.buf_add(self.tail + index), value);
             }
@@ -924,6 +2226,8 @@ impl<T> AltDeque<T> {
                 index_out_of_bounds(self.len(), index + front_len);
             }
         }
+        record!(self, peak_len);
+        check_invariants!(self);
     }
 
     /// Splits the deque into two at the given index.
@@ -949,6 +2253,7 @@ impl<T> AltDeque<T> {
     /// assert_eq!(deque2, [3, 4, 5]);
     /// ```
     #[must_use = "use `.truncate()` if you don't need the other half"]
+    #[cfg(not(no_global_oom_handling))]
     pub fn split_off(&mut self, at: usize) -> Self {
         let front_len = self.cap() - self.tail;
         let len = front_len + self.head;
@@ -986,9 +2291,39 @@ impl<T> AltDeque<T> {
             }
         }
 
+        check_invariants!(self);
+        check_invariants!(other);
         other
     }
 
+    /// Splits the deque into two at the given index and returns the elements `[at, len)` as a
+    /// [`Vec`]. `self` keeps the elements `[0, at)`.
+    ///
+    /// This is the same as `Vec::from(deque.split_off(at))`, but the elements are copied directly
+    /// into the vector, so the returned vector is the only new allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the deque's length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([1, 2, 3], [4, 5]));
+    /// assert_eq!(deque.split_off_to_vec(2), [3, 4, 5]);
+    /// assert_eq!(deque, [1, 2]);
+    /// ```
+    #[must_use = "use `.truncate()` if you don't need the other half"]
+    #[cfg(not(no_global_oom_handling))]
+    pub fn split_off_to_vec(&mut self, at: usize) -> Vec<T> {
+        let len = self.len();
+        if at > len {
+            index_out_of_bounds(len, at);
+        }
+        self.drain_to_vec(at..)
+    }
+
     /// Moves all the elements of `other` into `self`, leaving `other` empty.
     ///
     /// # Panics
@@ -1005,6 +2340,7 @@ impl<T> AltDeque<T> {
     /// assert_eq!(deque, [1, 2, 3, 4, 5]);
     /// assert!(deque2.is_empty());
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     pub fn append(&mut self, other: &mut Self) {
         let other_front_len = other.cap() - other.tail;
         self.reserve(other_front_len + other.head);
@@ -1021,6 +2357,146 @@ impl<T> AltDeque<T> {
             other.head = 0;
             other.tail = other.cap();
         }
+        record!(self, peak_len);
+        check_invariants!(self);
+        check_invariants!(other);
+    }
+
+    /// Moves all the elements of the given deques to the back of `self`, in order.
+    ///
+    /// The items can be deques and [`Vec`]s, whose elements are moved, or slices, whose
+    /// elements are cloned, see [`ConcatSource`]. Unlike calling [`append`] in a loop, this
+    /// reserves space for all elements up front, so `self` reallocates at most once.
+    ///
+    /// [`append`]: AltDeque::append
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new number of elements in self overflows a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([1, 2]);
+    /// deque.concat_into([AltDeque::from([3]), AltDeque::from(([4], [5]))]);
+    /// assert_eq!(deque, [1, 2, 3, 4, 5]);
+    ///
+    /// deque.concat_into(vec![vec![6, 7], vec![8]]);
+    /// deque.concat_into([&[9][..], &[10, 11]]);
+    /// assert_eq!(deque, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn concat_into<I>(&mut self, deques: I)
+    where
+        I: IntoIterator,
+        I::Item: ConcatSource<T>,
+    {
+        let deques: Vec<I::Item> = deques.into_iter().collect();
+        let additional = deques
+            .iter()
+            .try_fold(0_usize, |sum, deque| sum.checked_add(deque.len()))
+            .expect("capacity overflow");
+        self.reserve(additional);
+        deques.into_iter().for_each(|deque| deque.append_to(self));
+    }
+
+    /// Merges the sorted deque `other` into the sorted deque `self`, leaving `other` empty.
+    ///
+    /// This takes *O*(*n* + *m*) time and reuses the allocation of `self`. The merge is stable,
+    /// equal elements of `self` stay before those of `other`.
+    ///
+    /// If one of the deques is not sorted, the order of the merged elements is unspecified, but
+    /// all elements are still moved to `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([1, 3, 5]);
+    /// let mut other = AltDeque::from([2, 3, 4]);
+    /// deque.merge(&mut other);
+    /// assert_eq!(deque, [1, 2, 3, 3, 4, 5]);
+    /// assert!(other.is_empty());
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn merge(&mut self, other: &mut Self)
+    where
+        T: Ord,
+    {
+        self.merge_by(other, T::cmp);
+    }
+
+    /// Merges the deque `other` into `self` using a comparator function, leaving `other` empty.
+    /// Both deques must be sorted with respect to the comparator.
+    ///
+    /// If the comparator panics, all elements are still moved to `self` in an unspecified order.
+    ///
+    /// See [`merge`] for more details.
+    ///
+    /// [`merge`]: AltDeque::merge
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// // deques are sorted in reversed order
+    /// let mut deque = AltDeque::from([5, 3, 1]);
+    /// let mut other = AltDeque::from([4, 2]);
+    /// deque.merge_by(&mut other, |a, b| b.cmp(a));
+    /// assert_eq!(deque, [5, 4, 3, 2, 1]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn merge_by<F>(&mut self, other: &mut Self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        // The elements of self at [0, i) and of other at [0, j) are not merged yet. The merged
+        // ones are at [i + j, len) in self, so the gap [i, i + j) is exactly large enough for
+        // the remaining elements of other, which are moved there when the guard is dropped.
+        struct MergeGuard<T> {
+            dst: *mut T,
+            src: *const T,
+            i: usize,
+            j: usize,
+        }
+
+        impl<T> Drop for MergeGuard<T> {
+            fn drop(&mut self) {
+                // SAFETY: see above
+                unsafe { ptr::copy_nonoverlapping(self.src, self.dst.add(self.i), self.j) }
+            }
+        }
+
+        let len = self.len();
+        let other_len = other.len();
+        self.make_contiguous_back();
+        self.reserve(other_len);
+        let src = other.make_contiguous().as_ptr();
+        // SAFETY: all elements of self are in its back stack, followed by space for other_len
+        // elements. The elements of other are owned by the guard from now on, so other is set to
+        // empty, and the guard makes sure that self owns len + other_len elements at the end.
+        unsafe {
+            other.head = 0;
+            other.tail = other.cap();
+            self.head = len + other_len;
+            let mut guard = MergeGuard { dst: self.buf_add(0), src, i: len, j: other_len };
+            while guard.i > 0 && guard.j > 0 {
+                let a = guard.dst.add(guard.i - 1);
+                let b = guard.src.add(guard.j - 1);
+                let hole = guard.dst.add(guard.i + guard.j - 1);
+                if compare(&*a, &*b) == Ordering::Greater {
+                    ptr::copy_nonoverlapping(a, hole, 1);
+                    guard.i -= 1;
+                } else {
+                    ptr::copy_nonoverlapping(b, hole, 1);
+                    guard.j -= 1;
+                }
+            }
+        }
+        record!(self, peak_len);
+        check_invariants!(self);
+        check_invariants!(other);
     }
 
     /// Retains only the elements specified by the predicate.
@@ -1063,33 +2539,117 @@ impl<T> AltDeque<T> {
     where
         F: FnMut(&mut T) -> bool,
     {
-        let len = self.len();
-        let mut cur = 0;
+        // Elements in [read, end) have not been visited yet, retained elements are moved
+        // down to [.., write). On drop, also when `f` panics, the gap is closed and the
+        // stack is fixed up.
+        struct BackshiftOnDrop<'a, T> {
+            deque: &'a mut AltDeque<T>,
+            read: usize,
+            write: usize,
+            end: usize,
+            front: bool,
+        }
 
-        // Stage 1: All values are retained.
-        loop {
-            if cur == len {
-                return;
-            }
-            if !f(&mut self[cur]) {
-                cur += 1;
-                break;
+        impl<'a, T> Drop for BackshiftOnDrop<'a, T> {
+            fn drop(&mut self) {
+                let unprocessed = self.end - self.read;
+                // SAFETY: [read, end) are valid elements and [write, read) was moved out or dropped
+                unsafe {
+                    if self.read != self.write {
+                        self.deque.copy(self.read, self.write, unprocessed);
+                    }
+                    let end = self.write + unprocessed;
+                    if self.front {
+                        // move the retained elements of the front stack back to the end of the buffer
+                        let len = end - self.deque.tail;
+                        let tail = self.deque.cap() - len;
+                        if tail != self.deque.tail {
+                            self.deque.copy(self.deque.tail, tail, len);
+                            self.deque.tail = tail;
+                        }
+                    } else {
+                        self.deque.head = end;
+                    }
+                }
             }
-            cur += 1;
-        }
-        // Stage 2: Swap retained value into current idx.
-        let mut idx = cur - 1; // cur > 0 at this point
-        while cur < len {
-            if !f(&mut self[cur]) {
-                cur += 1;
-                continue;
+        }
+
+        fn process<T, F>(g: &mut BackshiftOnDrop<'_, T>, f: &mut F)
+        where
+            F: FnMut(&mut T) -> bool,
+        {
+            while g.read != g.end {
+                // SAFETY: read < end, so it points to a valid element
+                let cur = unsafe { &mut *g.deque.buf_add(g.read) };
+                if f(cur) {
+                    if g.read != g.write {
+                        // SAFETY: write < read and the slot at write has been moved out or dropped
+                        unsafe { g.deque.copy(g.read, g.write, 1) };
+                    }
+                    g.write += 1;
+                    g.read += 1;
+                } else {
+                    // advance first, so the element is not touched again if its drop panics
+                    g.read += 1;
+                    // SAFETY: the element is valid and never accessed again
+                    unsafe { ptr::drop_in_place(cur) };
+                }
             }
-            self.swap(idx, cur);
-            cur += 1;
-            idx += 1;
         }
-        // Stage 3: Truncate all values after idx.
-        self.truncate(idx);
+
+        let (tail, cap, head) = (self.tail, self.cap(), self.head);
+        process(&mut BackshiftOnDrop { deque: self, read: tail, write: tail, end: cap, front: true }, &mut f);
+        process(&mut BackshiftOnDrop { deque: self, read: 0, write: 0, end: head, front: false }, &mut f);
+        check_invariants!(self);
+    }
+
+    /// Returns the contents of the deque as one slice if they are already contiguous, which is
+    /// the case if one of the internal stacks is empty, for example if only [`push_back`] was
+    /// used so far. Unlike [`make_contiguous`], this never moves any element.
+    ///
+    /// [`make_contiguous`]: AltDeque::make_contiguous
+    /// [`push_back`]: AltDeque::push_back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::new();
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// assert_eq!(deque.contiguous(), Some(&[1, 2][..]));
+    ///
+    /// deque.push_front(0);
+    /// assert_eq!(deque.contiguous(), None);
+    /// ```
+    pub fn contiguous(&self) -> Option<&[T]> {
+        match self.as_slices() {
+            (slice, &[]) | (&[], slice) => Some(slice),
+            _ => None,
+        }
+    }
+
+    /// Returns the contents of the deque as one mutable slice if they are already contiguous.
+    ///
+    /// See the non-mutable version [`contiguous`] for details.
+    ///
+    /// [`contiguous`]: AltDeque::contiguous
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([3, 1, 2]);
+    /// if let Some(slice) = deque.contiguous_mut() {
+    ///     slice.sort();
+    /// }
+    /// assert_eq!(deque, [1, 2, 3]);
+    /// ```
+    pub fn contiguous_mut(&mut self) -> Option<&mut [T]> {
+        match self.as_mut_slices() {
+            (slice, &mut []) | (&mut [], slice) => Some(slice),
+            _ => None,
+        }
     }
 
     /// Rearranges the internal storage of the deque so it is one contiguous slice, which is then
@@ -1101,8 +2661,12 @@ impl<T> AltDeque<T> {
     /// Once the internal storage is contiguous, the [`as_slices`] and [`as_mut_slices`] methods
     /// will return the entire contents of the deque in a single slice.
     ///
+    /// All elements are moved into the front stack, so the following pops from the front don't
+    /// need to move any elements. Use [`make_contiguous_back`] to move them into the back stack.
+    ///
     /// [`as_slices`]: AltDeque::as_slices
     /// [`as_mut_slices`]: AltDeque::as_mut_slices
+    /// [`make_contiguous_back`]: AltDeque::make_contiguous_back
     ///
     /// # Examples
     ///
@@ -1152,6 +2716,7 @@ impl<T> AltDeque<T> {
 
         let front_len = self.cap() - self.tail;
         let free = self.tail - self.head;
+        trace_event!(front_len, back_len = self.head, "making deque contiguous");
 
         if front_len == 0 {
             // SAFETY: front is empty we just need to shift back
@@ -1183,56 +2748,212 @@ impl<T> AltDeque<T> {
                 self.copy(0, free, front_len + self.head);
             }
         } else {
-            let mut count = front_len + free;
-            let mut left = self.head.saturating_sub(count);
-            let mut right = self.head;
-            loop {
-                for i in left..right {
-                    // SAFETY: this just swaps two elements in the range of the buffer
-                    // i + count < head + front_len + free = len
-                    unsafe {
-                        ptr::swap(self.buf_add(i), self.buf_add(i + count));
-                    }
-                }
-                if left != 0 {
-                    left = left.saturating_sub(count);
-                    right -= count;
-                } else if right < count {
-                    // head is not a multiple of count, there are now two cases:
-                    if right >= count - free {
-                        // SAFETY: the last swap swapped all occupied elements and we simply need a final move the remove the gap
-                        // example: CDEFGHI..AB
-                        //          CDE..ABFGHI
-                        //          .AB.CDEFGHI -> move: ..ABCDEFGHI
-                        unsafe {
-                            self.copy(0, free, right - left);
-                        }
-                        break;
-                    } else {
-                        // or we need to do more swaps
-                        // example: EFGHIJ.ABCD  +-> more swaps: C.ABDEFGHIJ
-                        //          E.ABCDFGHIJ  |               B.ACDEFGHIJ
-                        //          D.ABCEFGHIJ -+               A.BCDEFGHIJ -> move: .ABCDEFGHIJ
-                        // the swaps will end when count becomes <= right + free (2 <= 1 + 1 in the example)
-                        // then the final move is done
-                        count -= right;
-                    }
-                } else {
-                    // head is a multiple of count, meaning we are done here
-                    // example: CDEFGH.AB
-                    //          CDE.ABFGH
-                    //          .ABCDEFGH
-                    break;
-                }
+            // Not enough free space for a simple copy, rotate the whole buffer left by head.
+            // The free slots are moved around with the elements.
+            // from: CDEFGH.AB
+            // to:   .ABCDEFGH
+            // SAFETY: the buffer is valid for cap elements, as MaybeUninit the free slots may be
+            // moved around as well
+            unsafe {
+                slice::from_raw_parts_mut(self.buf.ptr() as *mut MaybeUninit<T>, self.cap()).rotate_left(self.head);
             }
         }
 
         self.head = 0;
         self.tail = free;
+        check_invariants!(self);
 
         self.as_mut_slices().0
     }
 
+    /// Rearranges the internal storage of the deque so it is one contiguous slice in the back
+    /// stack, which is then returned.
+    ///
+    /// This works like [`make_contiguous`], but moves all elements into the back stack instead of
+    /// the front stack. This is the better choice if the deque is mostly popped from the back
+    /// afterwards, as those pops then don't need to move any elements.
+    ///
+    /// [`make_contiguous`]: AltDeque::make_contiguous
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([1, 2], [3, 4]));
+    ///
+    /// deque.make_contiguous_back().sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(deque.as_slices(), (&[] as &[_], &[4, 3, 2, 1] as &[_]));
+    /// assert_eq!(deque.back_stack_len(), 4);
+    /// ```
+    pub fn make_contiguous_back(&mut self) -> &mut [T] {
+        if self.tail == self.cap() {
+            return self.as_mut_slices().1;
+        }
+
+        let front_len = self.cap() - self.tail;
+        let free = self.tail - self.head;
+
+        if self.head == 0 {
+            // SAFETY: back is empty we just need to shift front
+            // from: ...ABCDEF
+            // to:   ABCDEF...
+            unsafe {
+                self.copy(self.tail, 0, front_len);
+            }
+        } else if free >= front_len {
+            // SAFETY: there is enough free space to copy the front
+            // first shift the back into position and then copy the front
+            // from: CDEF...AB
+            //       ..CDEF.AB
+            // to:   ABCDEF...
+            unsafe {
+                self.copy(0, front_len, self.head);
+                ptr::copy_nonoverlapping(self.buf_add(self.tail), self.buf_add(0), front_len);
+            }
+        } else if free >= self.head {
+            // SAFETY: there is enough free space to copy the back
+            // first shift the front into position and then copy the back
+            // from: EF...ABCD
+            //       EF.ABCD..
+            //       ...ABCDEF
+            // to:   ABCDEF...
+            // finally move everything to the back stack
+            unsafe {
+                self.copy(self.tail, self.tail - self.head, front_len);
+                ptr::copy_nonoverlapping(self.buf_add(0), self.buf_add(self.cap() - self.head), self.head);
+                self.copy(free, 0, front_len + self.head);
+            }
+        } else {
+            // Not enough free space for a simple copy, rotate the whole buffer right by the
+            // length of the front. The free slots are moved around with the elements.
+            // from: CDEFGH.AB
+            // to:   ABCDEFGH.
+            // SAFETY: the buffer is valid for cap elements, as MaybeUninit the free slots may be
+            // moved around as well
+            unsafe {
+                slice::from_raw_parts_mut(self.buf.ptr() as *mut MaybeUninit<T>, self.cap()).rotate_right(front_len);
+            }
+        }
+
+        self.head += front_len;
+        self.tail = self.cap();
+        check_invariants!(self);
+
+        self.as_mut_slices().1
+    }
+
+    /// Redistributes the elements between the two internal stacks, so that the first `front_len`
+    /// elements are in the front stack and the rest is in the back stack.
+    ///
+    /// Pops only move elements when their stack runs empty. If a burst of pops from one end is
+    /// expected, this can be used to pay for the move in advance at a convenient time.
+    ///
+    /// This method does not allocate and does not change the order of the elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `front_len` is greater than the deque's length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(deque.front_stack_len(), 6);
+    ///
+    /// deque.rebalance(2);
+    /// assert_eq!(deque.as_slices(), (&[1, 2][..], &[3, 4, 5, 6][..]));
+    /// // the next four pops from the back don't move any elements
+    /// assert_eq!(deque.pop_back(), Some(6));
+    /// ```
+    pub fn rebalance(&mut self, front_len: usize) {
+        let old_front_len = self.cap() - self.tail;
+        let len = old_front_len + self.head;
+        if front_len > len {
+            index_out_of_bounds(len, front_len);
+        }
+        let free = self.tail - self.head;
+
+        if front_len < old_front_len {
+            // move the last `count` elements of the front stack to the start of the back stack
+            let count = old_front_len - front_len;
+            if free >= count {
+                // SAFETY: there is enough free space to shift the back stack
+                // from: CD..ABXY
+                // to:   XYCD..AB
+                unsafe {
+                    self.copy(0, count, self.head);
+                    ptr::copy_nonoverlapping(self.buf_add(self.cap() - count), self.buf_add(0), count);
+                    self.copy(self.tail, self.tail + count, front_len);
+                }
+            } else {
+                // SAFETY: the buffer is valid for cap elements, as MaybeUninit the free slots may
+                // be moved around as well
+                // from: CDE.ABXY
+                // to:   XYCDE.AB
+                unsafe {
+                    slice::from_raw_parts_mut(self.buf.ptr() as *mut MaybeUninit<T>, self.cap()).rotate_right(count);
+                }
+            }
+            self.head += count;
+            self.tail += count;
+            record!(self, rebalances += 1);
+            record!(self, elements_moved += count);
+            trace_event!(moved = count, "rebalanced from the front to the back stack");
+        } else if front_len > old_front_len {
+            // move the first `count` elements of the back stack to the end of the front stack
+            let count = front_len - old_front_len;
+            if free >= count {
+                // SAFETY: there is enough free space to shift the front stack
+                // from: XYCD..AB
+                // to:   CD..ABXY
+                unsafe {
+                    self.copy(self.tail, self.tail - count, old_front_len);
+                    ptr::copy_nonoverlapping(self.buf_add(0), self.buf_add(self.cap() - count), count);
+                    self.copy(count, 0, self.head - count);
+                }
+            } else {
+                // SAFETY: the buffer is valid for cap elements, as MaybeUninit the free slots may
+                // be moved around as well
+                // from: XYCDE.AB
+                // to:   CDE.ABXY
+                unsafe {
+                    slice::from_raw_parts_mut(self.buf.ptr() as *mut MaybeUninit<T>, self.cap()).rotate_left(count);
+                }
+            }
+            self.head -= count;
+            self.tail -= count;
+            record!(self, rebalances += 1);
+            record!(self, elements_moved += count);
+            trace_event!(moved = count, "rebalanced from the back to the front stack");
+        }
+        check_invariants!(self);
+    }
+
+    /// Reverses the order of the elements in the deque, in place.
+    ///
+    /// Every element is mirrored to the opposite end of the buffer, so the front stack becomes
+    /// the back stack and vice versa. This touches every element once and does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([1, 2, 3], [4, 5]));
+    /// deque.reverse();
+    /// assert_eq!(deque.as_slices(), (&[5, 4][..], &[3, 2, 1][..]));
+    /// ```
+    pub fn reverse(&mut self) {
+        let (cap, head, tail) = (self.cap(), self.head, self.tail);
+        // SAFETY: [0, head) and [tail, cap) are the initialized stacks of the buffer
+        unsafe {
+            reverse_stacks(self.buf.ptr(), cap, head, tail);
+        }
+        self.head = cap - tail;
+        self.tail = cap - head;
+        check_invariants!(self);
+    }
+
     /// Rotates the deque `mid` places to the left.
     ///
     /// Equivalently,
@@ -1240,6 +2961,12 @@ impl<T> AltDeque<T> {
     /// - Pops the first `mid` items and pushes them to the end.
     /// - Rotates `len() - mid` places to the right.
     ///
+    /// Elements are moved from one stack directly onto the other one and every freed slot is
+    /// reused right away, so this never allocates and does not need any spare capacity. Either
+    /// the first `mid` or the last `len() - mid` elements are moved, whichever is cheaper and
+    /// possible with the current free space. If the deque is full, the stacks are just
+    /// relabelled and no element is moved.
+    ///
     /// # Panics
     ///
     /// If `mid` is greater than `len()`. Note that `mid == len()`
@@ -1254,29 +2981,33 @@ impl<T> AltDeque<T> {
     /// deque.rotate_left(3);
     /// assert_eq!(deque, [3, 4, 5, 6, 7, 8, 9, 0, 1, 2]);
     /// ```
-    pub fn rotate_left(&mut self, mut mid: usize) {
+    pub fn rotate_left(&mut self, mid: usize) {
+        let len = self.len();
+        if mid > len {
+            index_out_of_bounds(len, mid);
+        }
         let front_len = self.cap() - self.tail;
-        if mid < front_len {
-            // SAFETY: mid < front_len -> we an moce mid elements from tail to head
-            unsafe {
-                self.copy(self.tail, self.head, mid);
-                self.head += mid;
-                self.tail += mid;
-            }
+        let free = self.tail - self.head;
+        if free == 0 {
+            // the buffer is a ring, any element can become the start of the front stack
+            let idx = self.physical_index(mid);
+            self.head = idx;
+            self.tail = idx;
+            check_invariants!(self);
+            return;
+        }
+
+        // Moving elements across the free space must leave it in one piece, which rules out
+        // moving the first mid elements if front_len < mid < front_len + free, and moving the
+        // last len - mid elements if front_len - free < mid < front_len.
+        let front_possible = mid <= front_len || mid >= front_len + free;
+        let back_possible = mid + free <= front_len || mid >= front_len;
+        if front_possible && (!back_possible || mid <= len - mid) {
+            self.rotate_front_to_back(mid);
         } else {
-            mid -= front_len;
-            if mid <= self.head {
-                // SAFETY: mid <= head -> we can move head - mid elements from mid to tail - (head - mid)
-                unsafe {
-                    let count = self.head - mid;
-                    self.head = mid;
-                    self.tail -= count;
-                    self.copy(mid, self.tail, count);
-                }
-            } else {
-                index_out_of_bounds(self.len(), mid + front_len);
-            }
+            self.rotate_back_to_front(len - mid);
         }
+        check_invariants!(self);
     }
 
     /// Rotates the deque `k` places to the right.
@@ -1286,6 +3017,12 @@ impl<T> AltDeque<T> {
     /// - Pops the last `k` items and pushes them to the front.
     /// - Rotates `len() - k` places to the left.
     ///
+    /// Elements are moved from one stack directly onto the other one and every freed slot is
+    /// reused right away, so this never allocates and does not need any spare capacity. See
+    /// [`rotate_left`] for which elements are moved.
+    ///
+    /// [`rotate_left`]: AltDeque::rotate_left
+    ///
     /// # Panics
     ///
     /// If `k` is greater than `len()`. Note that `k == len()`
@@ -1300,31 +3037,195 @@ impl<T> AltDeque<T> {
     /// deque.rotate_right(3);
     /// assert_eq!(deque, [7, 8, 9, 0, 1, 2, 3, 4, 5, 6]);
     /// ```
-    pub fn rotate_right(&mut self, mut k: usize) {
-        if k <= self.head {
-            // SAFETY: k <= head -> we can move k elements from head - k to tail - k
+    pub fn rotate_right(&mut self, k: usize) {
+        let len = self.len();
+        if k > len {
+            index_out_of_bounds(len, k);
+        }
+        self.rotate_left(len - k);
+    }
+
+    /// Moves the first `count` elements to the back. Requires that `count <= front_len` or
+    /// `count >= front_len + free`.
+    fn rotate_front_to_back(&mut self, count: usize) {
+        let cap = self.cap();
+        let front_len = cap - self.tail;
+        if count <= front_len {
+            // SAFETY: count <= front_len -> we can move count elements from tail to head, the
+            // ranges may overlap if there is less free space than count
             unsafe {
-                self.head -= k;
-                self.tail -= k;
-                self.copy(self.head, self.tail, k);
+                self.copy(self.tail, self.head, count);
+                self.head += count;
+                self.tail += count;
             }
         } else {
-            let front_len = self.cap() - self.tail;
-            k -= self.head;
-            if k <= front_len {
-                // SAFETY: k <= front_len -> we can move front_len - k elements from tail to head
-                unsafe {
-                    let count = front_len - k;
-                    self.copy(self.tail, self.head, count);
-                    self.head += count;
-                    self.tail += count;
-                }
-            } else {
-                index_out_of_bounds(self.len(), k + self.head);
+            let free = self.tail - self.head;
+            let moved_back = count - front_len;
+            // SAFETY: free <= moved_back <= head
+            // * the front stack is moved down right after the back stack, leaving free slots at
+            //   [cap - free, cap)
+            // * the first free elements of the back stack are moved into these slots
+            // * the next moved_back - free elements of the back stack are moved down to 0
+            // * the front stack now is [moved_back, cap) and the back stack [0, moved_back - free)
+            unsafe {
+                self.copy(self.tail, self.head, front_len);
+                ptr::copy_nonoverlapping(self.buf_add(0), self.buf_add(cap - free), free);
+                self.copy(free, 0, moved_back - free);
+            }
+            self.tail = moved_back;
+            self.head = moved_back - free;
+        }
+    }
+
+    /// Moves the last `count` elements to the front. Requires that `count <= head` or
+    /// `count >= head + free`.
+    fn rotate_back_to_front(&mut self, count: usize) {
+        if count <= self.head {
+            // SAFETY: count <= head -> we can move count elements from head - count to
+            // tail - count, the ranges may overlap
+            unsafe {
+                self.head -= count;
+                self.tail -= count;
+                self.copy(self.head, self.tail, count);
+            }
+        } else {
+            let cap = self.cap();
+            let free = self.tail - self.head;
+            let moved_front = count - self.head;
+            // SAFETY: free <= moved_front <= front_len
+            // * the back stack is moved up right before the front stack, leaving free slots at
+            //   [0, free)
+            // * the last free elements of the front stack are moved into these slots
+            // * the previous moved_front - free elements of the front stack are moved up to cap
+            // * the back stack now is [0, cap - moved_front) and the front stack
+            //   [cap - moved_front + free, cap)
+            unsafe {
+                self.copy(0, free, self.head);
+                ptr::copy_nonoverlapping(self.buf_add(cap - free), self.buf_add(0), free);
+                let start = cap - moved_front;
+                self.copy(start, start + free, moved_front - free);
+                self.head = start;
+                self.tail = start + free;
             }
         }
     }
 
+    /// Sorts the deque.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements). Both internal stacks are
+    /// sorted separately and then merged across the seam, so the number of elements in each stack
+    /// stays the same. The merge allocates a buffer of the size of the smaller stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([5, 4, 1], [3, 2]));
+    /// deque.sort();
+    /// assert_eq!(deque.as_slices(), (&[1, 2, 3][..], &[4, 5][..]));
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sorts the deque with a comparator function.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements). See [`sort`] for details.
+    ///
+    /// [`sort`]: AltDeque::sort
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([5, 4, 1], [3, 2]));
+    /// deque.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(deque, [5, 4, 3, 2, 1]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let (front, back) = self.as_mut_slices();
+        front.sort_by(&mut compare);
+        back.sort_by(&mut compare);
+        self.merge_stacks(|a, b| compare(a, b) == Ordering::Less);
+    }
+
+    /// Sorts the deque with a key extraction function.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements). See [`sort`] for details.
+    ///
+    /// [`sort`]: AltDeque::sort
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([-5, 4, 1], [-3, 2]));
+    /// deque.sort_by_key(|k: &i32| k.abs());
+    /// assert_eq!(deque, [1, 2, -3, 4, -5]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Sorts the deque, but might not preserve the order of equal elements.
+    ///
+    /// Both internal stacks are sorted separately and then merged across the seam, so the number
+    /// of elements in each stack stays the same. The merge allocates a buffer of the size of the
+    /// smaller stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([5, 4, 1], [3, 2]));
+    /// deque.sort_unstable();
+    /// assert_eq!(deque, [1, 2, 3, 4, 5]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_unstable_by(T::cmp);
+    }
+
+    /// Sorts the deque with a comparator function, but might not preserve the order of equal
+    /// elements. See [`sort_unstable`] for details.
+    ///
+    /// [`sort_unstable`]: AltDeque::sort_unstable
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([5, 4, 1], [3, 2]));
+    /// deque.sort_unstable_by(|a, b| b.cmp(a));
+    /// assert_eq!(deque, [5, 4, 3, 2, 1]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn sort_unstable_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let (front, back) = self.as_mut_slices();
+        front.sort_unstable_by(&mut compare);
+        back.sort_unstable_by(&mut compare);
+        self.merge_stacks(|a, b| compare(a, b) == Ordering::Less);
+    }
+
     /// Binary searches the deque for a given element. This behaves similarly to [`contains`] if
     /// the deque is sorted but is faster.
     ///
@@ -1413,7 +3314,7 @@ impl<T> AltDeque<T> {
         F: FnMut(&'a T) -> Ordering,
     {
         let (front, back) = self.as_slices();
-        let cmp_back = back.first().map(|elem| f(elem));
+        let cmp_back = back.first().map(&mut f);
 
         if let Some(Ordering::Equal) = cmp_back {
             Ok(front.len())
@@ -1511,40 +3412,134 @@ impl<T> AltDeque<T> {
     {
         let (front, back) = self.as_slices();
 
-        if let Some(true) = back.first().map(|v| pred(v)) {
+        if let Some(true) = back.first().map(&mut pred) {
             back.partition_point(pred) + front.len()
         } else {
             front.partition_point(pred)
         }
     }
 
-    /// Returns a front-to-back iterator over the deque.
+    /// Returns the index of the first element that is not less than `x` in a sorted deque, or
+    /// the length if there is none. This is the first index where `x` could be inserted while
+    /// maintaining sorted order.
+    ///
+    /// If the deque is not sorted, the returned result is unspecified and meaningless.
     ///
     /// # Examples
     ///
     /// ```
     /// # use altdeque::AltDeque;
-    /// let deque = AltDeque::from([1, 2, 3]);
-    /// let mut iter = deque.iter();
-    /// assert_eq!(iter.next(), Some(&1));
-    /// assert_eq!(iter.next(), Some(&2));
-    /// assert_eq!(iter.next(), Some(&3));
-    /// assert_eq!(iter.next(), None);
+    /// let deque = AltDeque::from([1, 2, 2, 2, 3]);
+    ///
+    /// assert_eq!(deque.lower_bound(&2), 1);
+    /// assert_eq!(deque.lower_bound(&0), 0);
+    /// assert_eq!(deque.lower_bound(&4), 5);
     /// ```
-    pub fn iter(&self) -> Iter<'_, T> {
-        let (front, back) = self.as_slices();
-        front.iter().chain(back.iter())
+    pub fn lower_bound(&self, x: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.partition_point(|e| e < x)
     }
 
-    /// Returns a front-to-back iterator over the deque that returns mutable references.
+    /// Returns the index of the first element that is greater than `x` in a sorted deque, or
+    /// the length if there is none. This is the last index where `x` could be inserted while
+    /// maintaining sorted order.
+    ///
+    /// If the deque is not sorted, the returned result is unspecified and meaningless.
     ///
     /// # Examples
     ///
     /// ```
     /// # use altdeque::AltDeque;
-    /// let mut deque = AltDeque::from([1, 2, 3]);
-    /// for el in deque.iter_mut() {
-    ///     *el += 10;
+    /// let deque = AltDeque::from([1, 2, 2, 2, 3]);
+    ///
+    /// assert_eq!(deque.upper_bound(&2), 4);
+    /// assert_eq!(deque.upper_bound(&0), 0);
+    /// assert_eq!(deque.upper_bound(&3), 5);
+    /// ```
+    pub fn upper_bound(&self, x: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.partition_point(|e| e <= x)
+    }
+
+    /// Returns the index of the first element for which the comparator function does not return
+    /// `Less`, or the length if there is none.
+    ///
+    /// Like for [`binary_search_by`], the comparator function should return an order code that
+    /// indicates whether its argument is `Less`, `Equal` or `Greater` than the desired target.
+    ///
+    /// [`binary_search_by`]: AltDeque::binary_search_by
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from([(1, 'a'), (2, 'b'), (2, 'c'), (3, 'd')]);
+    ///
+    /// assert_eq!(deque.lower_bound_by(|&(k, _)| k.cmp(&2)), 1);
+    /// assert_eq!(deque.upper_bound_by(|&(k, _)| k.cmp(&2)), 3);
+    /// ```
+    pub fn lower_bound_by<F>(&self, mut f: F) -> usize
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        self.partition_point(|e| f(e) == Ordering::Less)
+    }
+
+    /// Returns the index of the first element for which the comparator function returns
+    /// `Greater`, or the length if there is none.
+    ///
+    /// See [`lower_bound_by`] for the meaning of the comparator function.
+    ///
+    /// [`lower_bound_by`]: AltDeque::lower_bound_by
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// // deque is sorted in reversed order
+    /// let deque = AltDeque::from([3, 2, 2, 1]);
+    ///
+    /// assert_eq!(deque.lower_bound_by(|x| 2.cmp(x)), 1);
+    /// assert_eq!(deque.upper_bound_by(|x| 2.cmp(x)), 3);
+    /// ```
+    pub fn upper_bound_by<F>(&self, mut f: F) -> usize
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        self.partition_point(|e| f(e) != Ordering::Greater)
+    }
+
+    /// Returns a front-to-back iterator over the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from([1, 2, 3]);
+    /// let mut iter = deque.iter();
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        front.iter().chain(back.iter())
+    }
+
+    /// Returns a front-to-back iterator over the deque that returns mutable references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([1, 2, 3]);
+    /// for el in deque.iter_mut() {
+    ///     *el += 10;
     /// }
     /// assert_eq!(deque, [11, 12, 13]);
     /// ```
@@ -1553,6 +3548,156 @@ impl<T> AltDeque<T> {
         front.iter_mut().chain(back.iter_mut())
     }
 
+    /// Returns an iterator over `chunk_size` elements of the deque at a time, starting at the
+    /// front. Each chunk is a pair of slices, of which only the one that spans the two
+    /// internal stacks uses the second slice. The last chunk is shorter if the length is not
+    /// divisible by `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2, 3], [4, 5]));
+    /// let mut chunks = deque.chunks(2);
+    /// assert_eq!(chunks.next(), Some((&[1, 2][..], &[][..])));
+    /// assert_eq!(chunks.next(), Some((&[3][..], &[4][..])));
+    /// assert_eq!(chunks.next(), Some((&[5][..], &[][..])));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    pub fn chunks(&self, chunk_size: usize) -> Chunks<'_, T> {
+        let (front, back) = self.as_slices();
+        Chunks::new(front, back, chunk_size)
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the deque at a time, starting at the
+    /// back. The chunks are pairs of slices like for [`chunks`], and the last chunk, which
+    /// holds the front of the deque, is shorter if the length is not divisible by
+    /// `chunk_size`.
+    ///
+    /// [`chunks`]: AltDeque::chunks
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2, 3], [4, 5]));
+    /// let mut chunks = deque.rchunks(2);
+    /// assert_eq!(chunks.next(), Some((&[4, 5][..], &[][..])));
+    /// assert_eq!(chunks.next(), Some((&[2, 3][..], &[][..])));
+    /// assert_eq!(chunks.next(), Some((&[1][..], &[][..])));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    pub fn rchunks(&self, chunk_size: usize) -> RChunks<'_, T> {
+        let (front, back) = self.as_slices();
+        RChunks::new(front, back, chunk_size)
+    }
+
+    /// Returns an iterator over all contiguous windows of `size` elements, starting at the
+    /// front. Each window is a pair of slices, of which only the windows that span the two
+    /// internal stacks use the second slice. There are no windows if the deque is shorter than
+    /// `size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2, 3], [4]));
+    /// let mut windows = deque.windows(2);
+    /// assert_eq!(windows.next(), Some((&[1, 2][..], &[][..])));
+    /// assert_eq!(windows.next(), Some((&[2, 3][..], &[][..])));
+    /// assert_eq!(windows.next(), Some((&[3][..], &[4][..])));
+    /// assert_eq!(windows.next(), None);
+    ///
+    /// let sums: Vec<i32> = deque.windows(3).map(|(a, b)| a.iter().chain(b).sum()).collect();
+    /// assert_eq!(sums, [6, 9]);
+    /// ```
+    pub fn windows(&self, size: usize) -> Windows<'_, T> {
+        let (front, back) = self.as_slices();
+        Windows::new(front, back, size)
+    }
+
+    /// Returns an iterator over the runs of the deque in which `pred` returns `true` for every
+    /// pair of neighbouring elements. Each run is a pair of slices, of which only a run that
+    /// spans the two internal stacks uses the second slice.
+    ///
+    /// Like [`slice::chunk_by`], every run is as long as possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 1, 2], [2, 2, 3]));
+    /// let runs: Vec<Vec<i32>> = deque.chunk_by(|a, b| a == b).map(|(a, b)| [a, b].concat()).collect();
+    /// assert_eq!(runs, [&[1, 1][..], &[2, 2, 2], &[3]]);
+    /// ```
+    pub fn chunk_by<P>(&self, pred: P) -> ChunkBy<'_, T, P>
+    where
+        P: FnMut(&T, &T) -> bool,
+    {
+        let (front, back) = self.as_slices();
+        ChunkBy::new(front, back, pred)
+    }
+
+    /// Returns an iterator over the parts of the deque that are separated by elements matching
+    /// `pred`. The matching elements are not part of any part. Each part is a pair of slices,
+    /// of which only a part that spans the two internal stacks uses the second slice.
+    ///
+    /// Like [`slice::split`], two adjacent matches or a match at either end yield an empty part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from((*b"ab\ncd", *b"e\n\nf"));
+    /// let lines: Vec<Vec<u8>> = deque.split(|&b| b == b'\n').map(|(a, b)| [a, b].concat()).collect();
+    /// assert_eq!(lines, [&b"ab"[..], b"cde", b"", b"f"]);
+    /// ```
+    pub fn split<P>(&self, pred: P) -> Split<'_, T, P>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let (front, back) = self.as_slices();
+        Split::new(front, back, pred)
+    }
+
+    /// Returns an iterator over the parts of the deque that end with elements matching `pred`.
+    /// The matching elements are kept at the end of their parts. Each part is a pair of slices
+    /// like for [`split`].
+    ///
+    /// Like [`slice::split_inclusive`], a match at the back of the deque does not yield an empty
+    /// part after it.
+    ///
+    /// [`split`]: AltDeque::split
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from((*b"ab\ncd", *b"e\n"));
+    /// let lines: Vec<Vec<u8>> =
+    ///     deque.split_inclusive(|&b| b == b'\n').map(|(a, b)| [a, b].concat()).collect();
+    /// assert_eq!(lines, [&b"ab\n"[..], b"cde\n"]);
+    /// ```
+    pub fn split_inclusive<P>(&self, pred: P) -> SplitInclusive<'_, T, P>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let (front, back) = self.as_slices();
+        SplitInclusive::new(front, back, pred)
+    }
+
     /// Creates an iterator that covers the specified range in the deque.
     ///
     /// # Examples
@@ -1563,6 +3708,56 @@ impl<T> AltDeque<T> {
     /// assert_eq!(deque.range(1..4).collect::<Vec<_>>(), [&2, &3, &4]);
     /// ```
     pub fn range<R>(&self, range: R) -> Iter<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (front, back) = self.range_slices(range);
+        front.iter().chain(back.iter())
+    }
+
+    /// Creates an iterator that covers the specified mutable range in the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([1, 2, 3, 4, 5, 6]);
+    /// for el in deque.range_mut(1..4) {
+    ///     *el += 10;
+    /// }
+    /// assert_eq!(deque, [1, 12, 13, 14, 5, 6]);
+    /// ```
+    pub fn range_mut<R>(&mut self, range: R) -> IterMut<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (front, back) = self.range_slices_mut(range);
+        front.iter_mut().chain(back.iter_mut())
+    }
+
+    /// Returns the specified range of the deque as a pair of slices, like [`as_slices`] returns
+    /// the whole deque. If the range lies in one of the internal stacks, it is in the first slice
+    /// and the second one is empty.
+    ///
+    /// This is the deque version of indexing a slice with a range. [`Index`] can not be used for
+    /// it, as it has to return a single reference.
+    ///
+    /// [`as_slices`]: AltDeque::as_slices
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater
+    /// than the length of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2, 3], [4, 5, 6]));
+    /// assert_eq!(deque.range_slices(1..5), (&[2, 3][..], &[4, 5][..]));
+    /// assert_eq!(deque.range_slices(4..), (&[5, 6][..], &[][..]));
+    /// ```
+    pub fn range_slices<R>(&self, range: R) -> (&[T], &[T])
     where
         R: RangeBounds<usize>,
     {
@@ -1571,163 +3766,1154 @@ impl<T> AltDeque<T> {
         let front_len = front.len();
 
         if start >= front_len {
-            back[start - front_len..end - front_len].iter().chain(front[..0].iter())
+            (&back[start - front_len..end - front_len], &[])
+        } else if end <= front_len {
+            (&front[start..end], &[])
+        } else {
+            (&front[start..], &back[..end - front_len])
+        }
+    }
+
+    /// Returns the specified range of the deque as a pair of mutable slices.
+    ///
+    /// See the non-mutable version [`range_slices`] for details.
+    ///
+    /// [`range_slices`]: AltDeque::range_slices
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater
+    /// than the length of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([1, 2, 3], [4, 5, 6]));
+    /// let (front, back) = deque.range_slices_mut(..4);
+    /// front.fill(0);
+    /// back.fill(9);
+    /// assert_eq!(deque, [0, 0, 0, 9, 5, 6]);
+    /// ```
+    pub fn range_slices_mut<R>(&mut self, range: R) -> (&mut [T], &mut [T])
+    where
+        R: RangeBounds<usize>,
+    {
+        let Range { start, end } = simplify_range(range, self.len());
+        let (front, back) = self.as_mut_slices();
+        let front_len = front.len();
+
+        if start >= front_len {
+            (&mut back[start - front_len..end - front_len], &mut [])
         } else if end <= front_len {
-            front[start..end].iter().chain(back[..0].iter())
+            (&mut front[start..end], &mut [])
         } else {
-            front[start..].iter().chain(back[..end - front_len].iter())
+            (&mut front[start..], &mut back[..end - front_len])
         }
     }
 
-    /// Creates an iterator that covers the specified mutable range in the deque.
+    /// Removes the specified range from the deque in bulk, returning all removed elements as an
+    /// iterator. If the iterator is dropped before being fully consumed, it drops the remaining
+    /// removed elements.
+    ///
+    /// The returned iterator keeps a mutable borrow on the queue to optimize its implementation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater
+    /// than the length of the deque.
+    ///
+    /// # Leaking
+    ///
+    /// If the returned iterator goes out of scope without being dropped (due to [`mem::forget`],
+    /// for example), the elements in the range are leaked. The remaining elements stay in the
+    /// deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(deque.drain(1..4).collect::<Vec<_>>(), [2, 3, 4]);
+    /// assert_eq!(deque, [1, 5, 6]);
+    /// ```
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = simplify_range(range, self.len());
+        Drain::new(self, range)
+    }
+
+    /// Removes the specified range from the deque and returns the removed elements as a [`Vec`].
+    ///
+    /// This is the same as `deque.drain(range).collect::<Vec<_>>()`, but the elements are
+    /// copied into the vector in at most two bulk copies, and the remaining elements are moved
+    /// only once to close the gap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater
+    /// than the length of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([1, 2, 3], [4, 5, 6]));
+    /// assert_eq!(deque.drain_to_vec(1..5), [2, 3, 4, 5]);
+    /// assert_eq!(deque, [1, 6]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn drain_to_vec<R>(&mut self, range: R) -> Vec<T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let mut vec = Vec::new();
+        self.drain_into(range, &mut vec);
+        vec
+    }
+
+    /// Removes the specified range from the deque and appends the removed elements to `vec`.
+    ///
+    /// Like [`drain_to_vec`], this moves the elements with bulk copies, but reuses the
+    /// allocation of `vec` if it has enough spare capacity.
+    ///
+    /// [`drain_to_vec`]: AltDeque::drain_to_vec
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater
+    /// than the length of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([1, 2, 3], [4, 5, 6]));
+    /// let mut vec = vec![0];
+    /// deque.drain_into(2..4, &mut vec);
+    /// assert_eq!(vec, [0, 3, 4]);
+    /// assert_eq!(deque, [1, 2, 5, 6]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn drain_into<R>(&mut self, range: R, vec: &mut Vec<T>)
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = simplify_range(range, self.len());
+        vec.reserve(range.len());
+        // SAFETY: we reserved space for all drained elements after the elements of vec, and the
+        // length is only increased after the elements are written
+        unsafe {
+            self.drain_segments(range, |src, count| {
+                ptr::copy_nonoverlapping(src, vec.as_mut_ptr().add(vec.len()), count);
+                vec.set_len(vec.len() + count);
+            });
+        }
+    }
+
+    /// Moves the elements in `range` from the deque to the back of `other`.
+    ///
+    /// `other` reserves space for all elements at once, the elements are moved with at most two
+    /// bulk copies and the remaining elements of `self` are moved only once to close the gap.
+    /// This is the same as [`drain_into`] for a deque as target.
+    ///
+    /// [`drain_into`]: AltDeque::drain_into
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater
+    /// than the length of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([1, 2, 3], [4, 5, 6]));
+    /// let mut other = AltDeque::from([0]);
+    /// deque.transfer(..3, &mut other);
+    /// assert_eq!(other, [0, 1, 2, 3]);
+    /// assert_eq!(deque, [4, 5, 6]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn transfer<R>(&mut self, range: R, other: &mut AltDeque<T>)
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = simplify_range(range, self.len());
+        other.reserve(range.len());
+        // SAFETY: we reserved space for all drained elements after the back stack of other, and
+        // head is only increased after the elements are written
+        unsafe {
+            self.drain_segments(range, |src, count| {
+                ptr::copy_nonoverlapping(src, other.buf_add(other.head), count);
+                other.head += count;
+            });
+        }
+        record!(other, peak_len);
+        check_invariants!(other);
+    }
+
+    /// Removes the elements in `range` from the deque. The drained elements are passed to
+    /// `take` in at most two contiguous parts, first the one from the front stack, as a pointer
+    /// and a length.
+    ///
+    /// # Safety
+    ///
+    /// `take` must move all the elements out of each part, as they are not owned by the deque
+    /// anymore and will be overwritten afterwards.
+    #[cfg(not(no_global_oom_handling))]
+    unsafe fn drain_segments(&mut self, range: Range<usize>, mut take: impl FnMut(*const T, usize)) {
+        let Range { start, end } = range;
+        let front_len = self.cap() - self.tail;
+
+        if start < front_len {
+            let front_end = end.min(front_len);
+            let drained = front_end - start;
+            // SAFETY:
+            // * the elements at [tail + start, tail + front_end) are moved out by take
+            // * then the start elements before them are moved up to close the gap
+            unsafe {
+                take(self.buf_add(self.tail + start), drained);
+                self.copy(self.tail, self.tail + drained, start);
+                self.tail += drained;
+            }
+        }
+        if end > front_len {
+            let back_start = start.max(front_len) - front_len;
+            let back_end = end - front_len;
+            // SAFETY:
+            // * the elements at [back_start, back_end) are moved out by take
+            // * then the elements after them are moved down to close the gap
+            unsafe {
+                take(self.buf_add(back_start), back_end - back_start);
+                self.copy(back_end, back_start, self.head - back_end);
+                self.head -= back_end - back_start;
+            }
+        }
+
+        check_invariants!(self);
+    }
+
+    #[inline]
+    fn cap(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    /// Returns the buffer index of the element at `index`, which must be less than the length.
+    ///
+    /// The front stack holds the elements at tail + index, the back stack continues at 0, which
+    /// is the same as wrapping tail + index around the capacity. Written as a conditional
+    /// subtraction this compiles to a conditional move instead of a branch.
+    #[inline]
+    fn physical_index(&self, index: usize) -> usize {
+        // wrapping_add only matters for zero-sized types, where the capacity is usize::MAX and
+        // any index is fine as the pointer is never offset
+        let idx = self.tail.wrapping_add(index);
+        let cap = self.cap();
+        if idx >= cap { idx - cap } else { idx }
+    }
+
+    /// Returns the buffer index of the element at `index` counted from the back, or `None` if
+    /// `index` is not less than the length.
+    ///
+    /// The back stack holds the last elements in reverse order before head, the front stack the
+    /// remaining ones before the end of the buffer.
+    #[inline]
+    fn physical_index_from_back(&self, index: usize) -> Option<usize> {
+        if index < self.head {
+            Some(self.head - 1 - index)
+        } else {
+            let index = index - self.head;
+            let cap = self.cap();
+            (index < cap - self.tail).then(|| cap - 1 - index)
+        }
+    }
+
+    #[inline]
+    fn is_full(&self) -> bool {
+        self.tail == self.head
+    }
+
+    #[inline]
+    unsafe fn buf_add(&self, offset: usize) -> *mut T {
+        self.buf.ptr().add(offset)
+    }
+
+    #[inline]
+    unsafe fn copy(&mut self, from: usize, to: usize, len: usize) {
+        self.buf.copy(from, to, len);
+    }
+
+    /// Merges the sorted front and back stacks, so that the whole deque is sorted while both
+    /// stacks keep their length. Elements of the front stack come first if they are equal to
+    /// elements of the back stack.
+    #[cfg(not(no_global_oom_handling))]
+    fn merge_stacks<F>(&mut self, mut is_less: F)
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        // Copies elements to the logical positions [pos, pos + count), which may span both stacks.
+        struct MergeHole<T> {
+            front: *mut T,
+            back: *mut T,
+            front_len: usize,
+            src: *const T,
+            pos: usize,
+            count: usize,
+        }
+
+        impl<T> MergeHole<T> {
+            #[inline]
+            unsafe fn slot(&self, pos: usize) -> *mut T {
+                if pos < self.front_len {
+                    self.front.add(pos)
+                } else {
+                    self.back.add(pos - self.front_len)
+                }
+            }
+        }
+
+        impl<T> Drop for MergeHole<T> {
+            fn drop(&mut self) {
+                // SAFETY: the hole is exactly as large as the remaining buffered elements
+                unsafe {
+                    let in_front = self.front_len.saturating_sub(self.pos).min(self.count);
+                    ptr::copy_nonoverlapping(self.src, self.slot(self.pos), in_front);
+                    let rest = self.count - in_front;
+                    ptr::copy_nonoverlapping(self.src.add(in_front), self.slot(self.pos + in_front), rest);
+                }
+            }
+        }
+
+        let front_len = self.cap() - self.tail;
+        let back_len = self.head;
+        if front_len == 0 || back_len == 0 {
+            return;
+        }
+        // SAFETY: both stacks are non-empty
+        let (front, back) = unsafe { (self.buf_add(self.tail), self.buf_add(0)) };
+        // SAFETY: both pointers point to valid elements
+        if unsafe { !is_less(&*back, &*front.add(front_len - 1)) } {
+            return;
+        }
+
+        let mut buf = Vec::<T>::with_capacity(cmp::min(front_len, back_len));
+        let tmp = buf.as_mut_ptr();
+        // SAFETY: elements are moved into the hole only once their slot has been vacated, and
+        // whatever is left in `tmp` is moved back by the hole, even if `is_less` panics.
+        unsafe {
+            if front_len <= back_len {
+                // buffer the front stack and merge from the start
+                ptr::copy_nonoverlapping(front, tmp, front_len);
+                let mut hole = MergeHole { front, back, front_len, src: tmp, pos: 0, count: front_len };
+                let mut j = 0;
+                while hole.count > 0 && j < back_len {
+                    let dst = hole.slot(hole.pos);
+                    if is_less(&*back.add(j), &*hole.src) {
+                        ptr::copy_nonoverlapping(back.add(j), dst, 1);
+                        j += 1;
+                    } else {
+                        ptr::copy_nonoverlapping(hole.src, dst, 1);
+                        hole.src = hole.src.add(1);
+                        hole.count -= 1;
+                    }
+                    hole.pos += 1;
+                }
+            } else {
+                // buffer the back stack and merge from the end
+                ptr::copy_nonoverlapping(back, tmp, back_len);
+                let mut hole = MergeHole { front, back, front_len, src: tmp, pos: front_len, count: back_len };
+                while hole.count > 0 && hole.pos > 0 {
+                    let dst = hole.slot(hole.pos + hole.count - 1);
+                    let last = hole.src.add(hole.count - 1);
+                    if is_less(&*last, &*front.add(hole.pos - 1)) {
+                        ptr::copy_nonoverlapping(front.add(hole.pos - 1), dst, 1);
+                        hole.pos -= 1;
+                    } else {
+                        ptr::copy_nonoverlapping(last, dst, 1);
+                        hole.count -= 1;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Extends the deque from an iterator of unknown length, reserving capacity by its size
+    /// hint.
+    #[cfg(not(no_global_oom_handling))]
+    fn extend_desugared<I: Iterator<Item = T>>(&mut self, mut iter: I) {
+        let (lower, _) = iter.size_hint();
+        self.reserve(lower);
+        loop {
+            // Fill the free space without checking for a full buffer on every element. For
+            // sources with an exact size hint, like `Vec::IntoIter`, this is the only round.
+            let free = self.tail - self.head;
+            let ptr = self.buf.ptr();
+            let head = &mut self.head;
+            iter.by_ref().take(free).for_each(|element| {
+                // SAFETY: head < tail as at most `free` elements are written
+                unsafe { ptr::write(ptr.add(*head), element) };
+                *head += 1;
+            });
+
+            match iter.next() {
+                Some(element) => {
+                    let (lower, _) = iter.size_hint();
+                    self.reserve(lower.saturating_add(1));
+                    // SAFETY: head < tail because we just reserved space
+                    unsafe { ptr::write(self.buf_add(self.head), element) };
+                    self.head += 1;
+                }
+                None => {
+                    record!(self, peak_len);
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Double the buffer size. This method is inline(never), so we expect it to only be called in
+    /// cold paths. This may panic or abort.
+    #[cfg(not(no_global_oom_handling))]
+    #[inline(never)]
+    fn grow(&mut self) {
+        debug_assert!(self.is_full());
+        let old_cap = self.cap();
+        // this call will panic on overflow or if T is zero-sized
+        self.buf.reserve_for_push(old_cap);
+        // SAFETY: old_cap is correct
+        unsafe { self.handle_capacity_increase(old_cap); }
+        debug_assert!(!self.is_full());
+    }
+
+    /// Moves the tail to the back to handle the fact that we just reallocated.
+    /// Unsafe because it trusts old_cap.
+    unsafe fn handle_capacity_increase(&mut self, old_cap: usize) {
+        debug_assert!(old_cap >= self.tail);
+        debug_assert!(old_cap <= self.cap());
+
+        if old_cap == self.cap() {
+            return;
+        }
+
+        let growth = self.cap() - old_cap;
+        let front_len = old_cap - self.tail;
+        let new_tail = self.tail + growth;
+
+        if growth >= front_len {
+            // SAFETY: buf was grown by growth >= front_len so we can move front_len elements from tail to tail + growth without overlap
+            unsafe {
+                ptr::copy_nonoverlapping(self.buf_add(self.tail), self.buf_add(new_tail), front_len);
+            }
+        } else {
+            // SAFETY: buf was grown by growth so we can move front_len elements from tail to tail + growth
+            unsafe {
+                ptr::copy(self.buf_add(self.tail), self.buf_add(new_tail), front_len);
+            }
+        }
+        self.tail = new_tail;
+        record!(self, reallocations += 1);
+        record!(self, elements_moved += front_len);
+        trace_event!(old_capacity = old_cap, new_capacity = self.cap(), moved = front_len, "grew buffer");
+        check_invariants!(self);
+    }
+
+    /// Hashes the length and then every element, for the `Hash` impl.
+    fn hash_elements<H: Hasher>(&self, state: &mut H)
+    where
+        T: Hash,
+    {
+        // write_length_prefix is currently unstable, see https://github.com/rust-lang/rust/issues/96762
+        // state.write_length_prefix(self.len());
+
+        state.write_usize(self.len());
+        self.iter().for_each(|elem| elem.hash(state));
+    }
+
+    /// Drops all elements in place without deallocating the buffer, for the `Drop` impl.
+    fn drop_elements(&mut self) {
+        let (front, back) = self.as_mut_slices();
+        unsafe {
+            let _back_dropper = Dropper(back);
+            // use drop for [T]
+            ptr::drop_in_place(front);
+        }
+        // RawVec handles deallocation
+    }
+
+    /// Panics if one of the internal invariants does not hold.
+    #[cfg(feature = "debug-invariants")]
+    #[track_caller]
+    fn check_invariants(&self) {
+        assert!(self.head <= self.tail, "invariant violated: head {} > tail {}", self.head, self.tail);
+        assert!(self.tail <= self.cap(), "invariant violated: tail {} > capacity {}", self.tail, self.cap());
+        if mem::size_of::<T>() != 0 {
+            let (front, back) = self.as_slices();
+            assert!(
+                back.as_ptr_range().end <= front.as_ptr_range().start,
+                "invariant violated: front and back stack overlap"
+            );
+            assert!(
+                front.as_ptr_range().end == self.buf.ptr().wrapping_add(self.cap()).cast_const(),
+                "invariant violated: front stack does not end at the end of the buffer"
+            );
+        }
+    }
+}
+
+impl<T: Clone> AltDeque<T> {
+    /// Modifies the deque in-place so that `len()` is equal to new_len, either by removing excess
+    /// elements from the back or by appending clones of `value` to the back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([1, 2, 3]);
+    ///
+    /// deque.resize(2, 5);
+    /// assert_eq!(deque, [1, 2]);
+    ///
+    /// deque.resize(5, 5);
+    /// assert_eq!(deque, [1, 2, 5, 5, 5]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn resize(&mut self, new_len: usize, value: T) {
+        self.resize_with(new_len, || value.clone());
+    }
+
+    /// Clones and appends all elements in a slice to the back of the deque.
+    ///
+    /// This reserves space for all elements once and then writes them without any further
+    /// capacity checks. For `Copy` types, [`extend_from_slice_copy`] copies them in one go.
+    ///
+    /// [`extend_from_slice_copy`]: AltDeque::extend_from_slice_copy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([1]);
+    /// deque.extend_from_slice(&[2, 3, 4]);
+    /// assert_eq!(deque, [1, 2, 3, 4]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        self.reserve(other.len());
+        let ptr = self.buf.ptr();
+        let head = &mut self.head;
+        other.iter().for_each(|element| {
+            // SAFETY: we reserved space for all elements, so head < tail
+            unsafe { ptr::write(ptr.add(*head), element.clone()) };
+            *head += 1;
+        });
+        record!(self, peak_len);
+    }
+
+    /// Clones the elements of the deque into a new [`Vec`], in order.
+    ///
+    /// This allocates once and clones both internal slices in bulk, which are plain copies for
+    /// `Copy` types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2], [3]));
+    /// assert_eq!(deque.to_vec(), [1, 2, 3]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn to_vec(&self) -> Vec<T> {
+        let (front, back) = self.as_slices();
+        let mut vec = Vec::with_capacity(front.len() + back.len());
+        vec.extend_from_slice(front);
+        vec.extend_from_slice(back);
+        vec
+    }
+}
+
+impl<T: Copy> AltDeque<T> {
+    /// Clones the deque with two bulk copies instead of cloning element by element.
+    ///
+    /// Unlike [`clone`], this preserves the split of the elements between the internal front and
+    /// back stack.
+    ///
+    /// [`clone`]: Clone::clone
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2], [3]));
+    /// let copy = deque.clone_copy();
+    /// assert_eq!(copy.as_slices(), (&[1, 2][..], &[3][..]));
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn clone_copy(&self) -> Self {
+        let (front, back) = self.as_slices();
+        let mut deque = Self::with_capacity(self.len());
+        deque.tail = deque.cap() - front.len();
+        deque.head = back.len();
+        // SAFETY: with_capacity ensures that there is enough space for both stacks, and T is Copy
+        unsafe {
+            ptr::copy_nonoverlapping(front.as_ptr(), deque.buf_add(deque.tail), front.len());
+            ptr::copy_nonoverlapping(back.as_ptr(), deque.buf_add(0), back.len());
+        }
+        deque
+    }
+
+    /// Appends all elements in a slice to the back of the deque with a single bulk copy.
+    ///
+    /// This is [`extend_from_slice`] for `Copy` types, without cloning element by element.
+    ///
+    /// [`extend_from_slice`]: AltDeque::extend_from_slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([1], [2]));
+    /// deque.extend_from_slice_copy(&[3, 4]);
+    /// assert_eq!(deque.as_slices(), (&[1][..], &[2, 3, 4][..]));
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn extend_from_slice_copy(&mut self, other: &[T]) {
+        self.reserve(other.len());
+        // SAFETY: we reserved space for all elements, so [head, head + len) is free, and T is
+        // Copy, so the elements can be duplicated bitwise
+        unsafe {
+            ptr::copy_nonoverlapping(other.as_ptr(), self.buf_add(self.head), other.len());
+        }
+        self.head += other.len();
+        record!(self, peak_len);
+        check_invariants!(self);
+    }
+
+    /// Copies the elements starting at index `start` into `dst`, without removing them from the
+    /// deque. Exactly `dst.len()` elements are copied, with at most two bulk copies.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start + dst.len()` is greater than the length of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2], [3, 4]));
+    /// let mut buf = [0; 3];
+    /// deque.copy_to_slice(1, &mut buf);
+    /// assert_eq!(buf, [2, 3, 4]);
+    /// ```
+    pub fn copy_to_slice(&self, start: usize, dst: &mut [T]) {
+        let Range { start, end } = simplify_range(start..start + dst.len(), self.len());
+        let (a, b) = self.as_slices();
+        if end <= a.len() {
+            dst.copy_from_slice(&a[start..end]);
+        } else if start >= a.len() {
+            dst.copy_from_slice(&b[start - a.len()..end - a.len()]);
+        } else {
+            let (dst_a, dst_b) = dst.split_at_mut(a.len() - start);
+            dst_a.copy_from_slice(&a[start..]);
+            dst_b.copy_from_slice(&b[..end - a.len()]);
+        }
+    }
+
+    /// Copies the elements in `src` to the position starting at `dest`, like
+    /// [`slice::copy_within`]. The ranges may overlap.
+    ///
+    /// The copy is split into at most three bulk copies, one for each part of the ranges that
+    /// lies in the same internal stack.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater
+    /// than the length of the deque, or if `dest + src.len()` is greater than the length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(([1, 2, 3], [4, 5]));
+    /// deque.copy_within(0..3, 2);
+    /// assert_eq!(deque, [1, 2, 1, 2, 3]);
+    /// ```
+    pub fn copy_within<R>(&mut self, src: R, dest: usize)
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let Range { start, end } = simplify_range(src, len);
+        let count = end - start;
+        assert!(dest <= len - count, "dest is out of bounds");
+        let front_len = self.cap() - self.tail;
+
+        if dest <= start {
+            // copy front to back, the number of contiguous elements starting at i is limited by
+            // the end of the front stack
+            let run = |i: usize| if i < front_len { front_len - i } else { usize::MAX };
+            let mut done = 0;
+            while done < count {
+                let (s, d) = (start + done, dest + done);
+                let n = cmp::min(count - done, cmp::min(run(s), run(d)));
+                // SAFETY: [s, s + n) and [d, d + n) are in bounds and each lies in one stack
+                unsafe {
+                    ptr::copy(self.buf_add(self.physical_index(s)), self.buf_add(self.physical_index(d)), n);
+                }
+                done += n;
+            }
+        } else {
+            // copy back to front, the number of contiguous elements ending at i is limited by
+            // the start of the back stack
+            let run = |i: usize| if i > front_len { i - front_len } else { i };
+            let mut left = count;
+            while left > 0 {
+                let (s, d) = (start + left, dest + left);
+                let n = cmp::min(left, cmp::min(run(s), run(d)));
+                // SAFETY: [s - n, s) and [d - n, d) are in bounds and each lies in one stack
+                unsafe {
+                    ptr::copy(self.buf_add(self.physical_index(s - n)), self.buf_add(self.physical_index(d - n)), n);
+                }
+                left -= n;
+            }
+        }
+    }
+
+    /// Returns a copy of the first `N` elements, or `None` if the deque has fewer than `N`
+    /// elements. Unlike [`front_chunk`], this works no matter how the elements are stored.
+    ///
+    /// [`front_chunk`]: AltDeque::front_chunk
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1], [2, 3]));
+    /// assert_eq!(deque.front_chunk_copied(), Some([1, 2]));
+    /// assert_eq!(deque.front_chunk_copied::<4>(), None);
+    /// ```
+    pub fn front_chunk_copied<const N: usize>(&self) -> Option<[T; N]> {
+        if self.len() < N {
+            return None;
+        }
+        Some(array::from_fn(|i| self[i]))
+    }
+
+    /// Returns a copy of the last `N` elements, or `None` if the deque has fewer than `N`
+    /// elements. Unlike [`back_chunk`], this works no matter how the elements are stored.
+    ///
+    /// [`back_chunk`]: AltDeque::back_chunk
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2], [3]));
+    /// assert_eq!(deque.back_chunk_copied(), Some([2, 3]));
+    /// assert_eq!(deque.back_chunk_copied::<4>(), None);
+    /// ```
+    pub fn back_chunk_copied<const N: usize>(&self) -> Option<[T; N]> {
+        let start = self.len().checked_sub(N)?;
+        Some(array::from_fn(|i| self[start + i]))
+    }
+
+    /// Returns an iterator over `N` elements of the deque at a time, starting at the front.
+    /// Chunks that lie in one of the internal stacks are borrowed, the chunk that spans both
+    /// stacks is copied. If the length is not divisible by `N`, the last elements are left out.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// # use std::borrow::Cow;
+    /// let deque = AltDeque::from(([1, 2, 3], [4, 5]));
+    /// let mut chunks = deque.array_chunks::<2>();
+    /// assert_eq!(chunks.next(), Some(Cow::Borrowed(&[1, 2])));
+    /// assert_eq!(chunks.next(), Some(Cow::Owned([3, 4])));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    pub fn array_chunks<const N: usize>(&self) -> ArrayChunks<'_, T, N> {
+        let (front, back) = self.as_slices();
+        ArrayChunks::new(front, back)
+    }
+
+    /// Returns an iterator over all contiguous windows of `N` elements, starting at the front.
+    /// Windows that lie in one of the internal stacks are borrowed, the windows that span both
+    /// stacks are copied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2], [3, 4]));
+    /// let diffs: Vec<i32> = deque.array_windows::<2>().map(|w| w[1] - w[0]).collect();
+    /// assert_eq!(diffs, [1, 1, 1]);
+    /// ```
+    pub fn array_windows<const N: usize>(&self) -> ArrayWindows<'_, T, N> {
+        let (front, back) = self.as_slices();
+        ArrayWindows::new(front, back)
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl<T: fmt::Display> AltDeque<T> {
+    /// Formats the elements from front to back into a string, with `separator` between each
+    /// pair of neighbouring elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1, 2], [3]));
+    /// assert_eq!(deque.join(", "), "1, 2, 3");
+    /// assert_eq!(AltDeque::<i32>::new().join(", "), "");
+    /// ```
+    pub fn join(&self, separator: &str) -> String {
+        use std::fmt::Write;
+
+        let mut string = String::new();
+        let mut iter = self.iter();
+        if let Some(first) = iter.next() {
+            // writing to a String never fails
+            write!(string, "{first}").unwrap();
+            iter.for_each(|elem| write!(string, "{separator}{elem}").unwrap());
+        }
+        string
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl<T: AsRef<str>> AltDeque<T> {
+    /// Concatenates the string elements from front to back into one string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from(["b", "c"]);
+    /// deque.push_front("a");
+    /// assert_eq!(deque.concat(), "abc");
+    /// ```
+    pub fn concat(&self) -> String {
+        let len = self.iter().map(|elem| elem.as_ref().len()).sum();
+        let mut string = String::with_capacity(len);
+        self.iter().for_each(|elem| string.push_str(elem.as_ref()));
+        string
+    }
+}
+
+#[cfg(feature = "memchr")]
+impl AltDeque<u8> {
+    /// Returns the index of the first occurrence of `byte`, or `None` if there is none.
+    ///
+    /// Both internal slices are searched with [`memchr`](https://docs.rs/memchr), which uses
+    /// SIMD instructions where available. [`contains`] on a byte deque is already vectorized
+    /// by the standard library.
+    ///
+    /// [`contains`]: AltDeque::contains
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([b'a', b'\r'], [b'\n', b'\r', b'\n']));
+    ///
+    /// assert_eq!(deque.find_byte(b'\n'), Some(2));
+    /// assert_eq!(deque.find_byte(b'x'), None);
+    /// ```
+    pub fn find_byte(&self, byte: u8) -> Option<usize> {
+        let (a, b) = self.as_slices();
+        match memchr::memchr(byte, a) {
+            Some(idx) => Some(idx),
+            None => memchr::memchr(byte, b).map(|idx| a.len() + idx),
+        }
+    }
+
+    /// Returns the index of the last occurrence of `byte`, or `None` if there is none.
+    ///
+    /// Like [`find_byte`], this searches with `memchr`.
+    ///
+    /// [`find_byte`]: AltDeque::find_byte
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([b'a', b'\r'], [b'\n', b'\r', b'\n']));
+    ///
+    /// assert_eq!(deque.rfind_byte(b'\r'), Some(3));
+    /// assert_eq!(deque.rfind_byte(b'a'), Some(0));
+    /// assert_eq!(deque.rfind_byte(b'x'), None);
+    /// ```
+    pub fn rfind_byte(&self, byte: u8) -> Option<usize> {
+        let (a, b) = self.as_slices();
+        match memchr::memrchr(byte, b) {
+            Some(idx) => Some(a.len() + idx),
+            None => memchr::memrchr(byte, a),
+        }
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<T: bytemuck::Pod> AltDeque<T> {
+    /// Returns the bytes of the elements as a pair of slices, like [`as_slices`] does for the
+    /// elements themselves.
+    ///
+    /// [`as_slices`]: AltDeque::as_slices
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([0x0102_u16], [0x0304]));
+    /// let (front, back) = deque.as_byte_slices();
+    /// assert_eq!(front, 0x0102_u16.to_ne_bytes());
+    /// assert_eq!(back, 0x0304_u16.to_ne_bytes());
+    /// ```
+    pub fn as_byte_slices(&self) -> (&[u8], &[u8]) {
+        let (a, b) = self.as_slices();
+        (bytemuck::cast_slice(a), bytemuck::cast_slice(b))
+    }
+
+    /// Reinterprets the elements of the deque as elements of type `U`, reusing the allocation.
+    ///
+    /// This only works if `T` and `U` have the same size and alignment, otherwise the deque is
+    /// returned unchanged in an `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let deque = AltDeque::from(([1_u32], [2]));
+    /// let deque = deque.try_cast_deque::<f32>().unwrap();
+    /// assert_eq!(deque, [f32::from_bits(1), f32::from_bits(2)]);
+    ///
+    /// let deque = AltDeque::from([1_u32, 2]);
+    /// assert!(deque.try_cast_deque::<u64>().is_err());
+    /// ```
+    pub fn try_cast_deque<U: bytemuck::Pod>(self) -> Result<AltDeque<U>, Self> {
+        if mem::size_of::<T>() != mem::size_of::<U>()
+            || mem::align_of::<T>() != mem::align_of::<U>()
+        {
+            return Err(self);
+        }
+        let this = ManuallyDrop::new(self);
+        // SAFETY: the buffer was allocated with the layout of [T; cap], which is the same as the
+        // layout of [U; cap], and every bit pattern of T is a valid U.
+        let buf = unsafe { RawVec::from_raw_parts(this.buf.ptr() as *mut U, this.buf.capacity()) };
+        Ok(AltDeque {
+            tail: this.tail,
+            head: this.head,
+            buf,
+            #[cfg(feature = "metrics")]
+            stats: this.stats,
+        })
+    }
+
+    /// Creates a deque from the bytes of its elements with a single copy. `bytes` does not need
+    /// to be aligned for `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PodCastError::OutputSliceWouldHaveSlop`] if the length of `bytes` is not a
+    /// multiple of the size of `T` and [`PodCastError::SizeMismatch`] if `T` is zero-sized.
+    ///
+    /// [`PodCastError::OutputSliceWouldHaveSlop`]: bytemuck::PodCastError::OutputSliceWouldHaveSlop
+    /// [`PodCastError::SizeMismatch`]: bytemuck::PodCastError::SizeMismatch
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let bytes = [1_u16.to_ne_bytes(), 2_u16.to_ne_bytes()].concat();
+    /// let deque = AltDeque::<u16>::from_bytes(&bytes).unwrap();
+    /// assert_eq!(deque, [1, 2]);
+    ///
+    /// assert!(AltDeque::<u16>::from_bytes(&bytes[1..]).is_err());
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, bytemuck::PodCastError> {
+        let size = mem::size_of::<T>();
+        if size == 0 {
+            return Err(bytemuck::PodCastError::SizeMismatch);
+        }
+        if !bytes.len().is_multiple_of(size) {
+            return Err(bytemuck::PodCastError::OutputSliceWouldHaveSlop);
+        }
+        let len = bytes.len() / size;
+        let mut deque = Self::with_capacity(len);
+        // SAFETY: the buffer has room for len elements and every bit pattern is a valid T.
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), deque.buf.ptr() as *mut u8, bytes.len());
+        }
+        deque.head = len;
+        record!(deque, peak_len);
+        check_invariants!(deque);
+        Ok(deque)
+    }
+}
+
+impl<T, const N: usize> AltDeque<[T; N]> {
+    /// Takes an `AltDeque<[T; N]>` and flattens it into an `AltDeque<T>`, reusing the
+    /// allocation. Both stacks keep their place in the buffer, only scaled by `N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the resulting deque would overflow a `usize`.
+    ///
+    /// This is only possible when flattening a deque of arrays of zero-sized types, and thus
+    /// tends to be irrelevant in practice. If `size_of::<T>() > 0`, this will never panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// let mut deque = AltDeque::from([[3, 4], [5, 6]]);
+    /// deque.push_front([1, 2]);
+    ///
+    /// let flattened = deque.into_flattened();
+    /// assert_eq!(flattened, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn into_flattened(self) -> AltDeque<T> {
+        let this = ManuallyDrop::new(self);
+        let (tail, head, buf) = if mem::size_of::<T>() == 0 {
+            let len = this.len().checked_mul(N).expect("deque len overflow");
+            (usize::MAX, len, RawVec::new())
+        } else if N == 0 {
+            (0, 0, RawVec::new())
+        } else {
+            // SAFETY: the buffer was allocated with the layout of [[T; N]; cap], which is the
+            // same as the layout of [T; cap * N], and cap * N cannot overflow because the
+            // allocation is at most isize::MAX bytes large.
+            let buf = unsafe { RawVec::from_raw_parts(this.buf.ptr() as *mut T, this.cap() * N) };
+            (this.tail * N, this.head * N, buf)
+        };
+        let deque = AltDeque {
+            tail,
+            head,
+            buf,
+            #[cfg(feature = "metrics")]
+            stats: Stats { peak_len: this.stats.peak_len.saturating_mul(N), ..this.stats },
+        };
+        check_invariants!(deque);
+        deque
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T> AltDeque<T> {
+    /// Shuffles the deque in place, so that every permutation is equally likely.
+    ///
+    /// This is a Fisher–Yates shuffle over the logical indices, so unlike shuffling the slices
+    /// of [`make_contiguous`] it does not move the elements between the stacks first.
+    ///
+    /// [`make_contiguous`]: AltDeque::make_contiguous
     ///
     /// # Examples
     ///
     /// ```
     /// # use altdeque::AltDeque;
-    /// let mut deque = AltDeque::from([1, 2, 3, 4, 5, 6]);
-    /// for el in deque.range_mut(1..4) {
-    ///     *el += 10;
-    /// }
-    /// assert_eq!(deque, [1, 12, 13, 14, 5, 6]);
+    /// use rand::rngs::SmallRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(42);
+    /// let mut deque = AltDeque::from(([1, 2], [3, 4, 5]));
+    /// deque.shuffle(&mut rng);
+    /// let mut sorted = Vec::from(deque);
+    /// sorted.sort();
+    /// assert_eq!(sorted, [1, 2, 3, 4, 5]);
     /// ```
-    pub fn range_mut<R>(&mut self, range: R) -> IterMut<'_, T>
-    where
-        R: RangeBounds<usize>,
-    {
-        let Range { start, end } = simplify_range(range, self.len());
-        let (front, back) = self.as_mut_slices();
-        let front_len = front.len();
-
-        if start >= front_len {
-            back[start - front_len..end - front_len].iter_mut().chain(front[..0].iter_mut())
-        } else if end <= front_len {
-            front[start..end].iter_mut().chain(back[..0].iter_mut())
-        } else {
-            front[start..].iter_mut().chain(back[..end - front_len].iter_mut())
-        }
+    pub fn shuffle<R: rand::Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.partial_shuffle(rng, self.len());
     }
 
-    /// Removes the specified range from the deque in bulk, returning all removed elements as an
-    /// iterator. If the iterator is dropped before being fully consumed, it drops the remaining
-    /// removed elements.
-    ///
-    /// The returned iterator keeps a mutable borrow on the queue to optimize its implementation.
+    /// Moves `amount` randomly chosen elements in random order to the front of the deque and
+    /// returns how many were moved, which is less than `amount` if the deque is shorter.
     ///
-    /// # Panics
+    /// This takes *O(amount)* time and is an efficient way to pick several distinct elements
+    /// at random. The elements behind the chosen ones are left in an unspecified order.
     ///
-    /// Panics if the starting point is greater than the end point or if the end point is greater
-    /// than the length of the deque.
+    /// # Examples
     ///
-    /// # Leaking
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// use rand::rngs::SmallRng;
+    /// use rand::SeedableRng;
     ///
-    /// If the returned iterator goes out of scope without being dropped (due to [`mem::forget`],
-    /// for example), the deque may have lost and leaked elements arbitrarily, including elements
-    /// outside the range and possibly all elements.
+    /// let mut rng = SmallRng::seed_from_u64(42);
+    /// let mut deque: AltDeque<_> = (0..10).collect();
+    /// assert_eq!(deque.partial_shuffle(&mut rng, 3), 3);
+    /// let picked: Vec<_> = deque.drain(..3).collect();
+    /// assert!(picked.iter().all(|&x| x < 10));
+    /// assert_eq!(deque.len(), 7);
+    /// ```
+    pub fn partial_shuffle<R: rand::Rng + ?Sized>(&mut self, rng: &mut R, amount: usize) -> usize {
+        let len = self.len();
+        let amount = cmp::min(amount, len);
+        for i in 0..amount {
+            let j = rng.random_range(i..len);
+            self.swap(i, j);
+        }
+        amount
+    }
+
+    /// Returns a reference to a random element, or `None` if the deque is empty.
     ///
     /// # Examples
     ///
     /// ```
     /// # use altdeque::AltDeque;
-    /// let mut deque = AltDeque::from([1, 2, 3, 4, 5, 6]);
-    /// assert_eq!(deque.drain(1..4).collect::<Vec<_>>(), [2, 3, 4]);
-    /// assert_eq!(deque, [1, 5, 6]);
+    /// use rand::rngs::SmallRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(42);
+    /// let deque = AltDeque::from(([1, 2], [3]));
+    /// assert!(deque.choose(&mut rng).is_some_and(|x| deque.contains(x)));
+    /// assert_eq!(AltDeque::<i32>::new().choose(&mut rng), None);
     /// ```
-    pub fn drain<R>(&mut self, range: R) -> Drain<T>
-    where
-        R: RangeBounds<usize>,
-    {
-        let range = simplify_range(range, self.len());
-        let old_head = self.head;
-        let old_tail = self.tail;
-        self.head = 0;
-        self.tail = self.cap();
-        Drain::new(self, old_head, old_tail, range)
-    }
-
-    #[inline]
-    fn cap(&self) -> usize {
-        self.buf.capacity()
-    }
-
-    #[inline]
-    fn is_full(&self) -> bool {
-        self.tail == self.head
-    }
-
-    #[inline]
-    unsafe fn buf_add(&self, offset: usize) -> *mut T {
-        self.buf.ptr().add(offset)
-    }
-
-    #[inline]
-    unsafe fn copy(&mut self, from: usize, to: usize, len: usize) {
-        ptr::copy(self.buf_add(from), self.buf_add(to), len);
-    }
-
-    /// Double the buffer size. This method is inline(never), so we expect it to only be called in
-    /// cold paths. This may panic or abort.
-    #[inline(never)]
-    fn grow(&mut self) {
-        debug_assert!(self.is_full());
-        let old_cap = self.cap();
-        // this call will panic on overflow or if T is zero-sized
-        self.buf.reserve_for_push(old_cap);
-        // SAFETY: old_cap is correct
-        unsafe { self.handle_capacity_increase(old_cap); }
-        debug_assert!(!self.is_full());
-    }
-
-    /// Moves the tail to the back to handle the fact that we just reallocated.
-    /// Unsafe because it trusts old_cap.
-    unsafe fn handle_capacity_increase(&mut self, old_cap: usize) {
-        debug_assert!(old_cap >= self.tail);
-        debug_assert!(old_cap <= self.cap());
-
-        if old_cap == self.cap() {
-            return;
-        }
-
-        let growth = self.cap() - old_cap;
-        let front_len = old_cap - self.tail;
-        let new_tail = self.tail + growth;
-
-        if growth >= front_len {
-            // SAFETY: buf was grown by growth >= front_len so we can move front_len elements from tail to tail + growth without overlap
-            unsafe {
-                ptr::copy_nonoverlapping(self.buf_add(self.tail), self.buf_add(new_tail), front_len);
-            }
+    pub fn choose<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Option<&T> {
+        if self.is_empty() {
+            None
         } else {
-            // SAFETY: buf was grown by growth so we can move front_len elements from tail to tail + growth
-            unsafe {
-                ptr::copy(self.buf_add(self.tail), self.buf_add(new_tail), front_len);
-            }
+            self.get(rng.random_range(..self.len()))
         }
-        self.tail = new_tail;
     }
-}
 
-impl<T: Clone> AltDeque<T> {
-    /// Modifies the deque in-place so that `len()` is equal to new_len, either by removing excess
-    /// elements from the back or by appending clones of `value` to the back.
+    /// Returns a mutable reference to a random element, or `None` if the deque is empty.
     ///
     /// # Examples
     ///
     /// ```
     /// # use altdeque::AltDeque;
-    /// let mut deque = AltDeque::from([1, 2, 3]);
-    ///
-    /// deque.resize(2, 5);
-    /// assert_eq!(deque, [1, 2]);
+    /// use rand::rngs::SmallRng;
+    /// use rand::SeedableRng;
     ///
-    /// deque.resize(5, 5);
-    /// assert_eq!(deque, [1, 2, 5, 5, 5]);
+    /// let mut rng = SmallRng::seed_from_u64(42);
+    /// let mut deque = AltDeque::from([0; 3]);
+    /// *deque.choose_mut(&mut rng).unwrap() += 1;
+    /// assert_eq!(deque.iter().sum::<i32>(), 1);
     /// ```
-    pub fn resize(&mut self, new_len: usize, value: T) {
-        self.resize_with(new_len, || value.clone());
+    pub fn choose_mut<R: rand::Rng + ?Sized>(&mut self, rng: &mut R) -> Option<&mut T> {
+        if self.is_empty() {
+            None
+        } else {
+            let index = rng.random_range(..self.len());
+            self.get_mut(index)
+        }
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
 impl<T: Clone> Clone for AltDeque<T> {
     fn clone(&self) -> Self {
         let mut deque = Self::with_capacity(self.len());
@@ -1748,6 +4934,29 @@ impl<T: fmt::Debug> fmt::Debug for AltDeque<T> {
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
+impl fmt::Write for AltDeque<u8> {
+    /// Appends the UTF-8 encoding of `s` to the back of the deque.
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl fmt::Write for AltDeque<char> {
+    /// Appends the chars of `s` to the back of the deque.
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.extend(s.chars());
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.push_back(c);
+        Ok(())
+    }
+}
+
 impl<T> Default for AltDeque<T> {
     /// Creates an empty deque.
     #[inline]
@@ -1756,40 +4965,117 @@ impl<T> Default for AltDeque<T> {
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
+impl<T> Deque<T> for AltDeque<T> {
+    type Iter<'a>
+        = Iter<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        AltDeque::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        AltDeque::get(self, index)
+    }
+
+    fn push_front(&mut self, value: T) {
+        AltDeque::push_front(self, value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        AltDeque::push_back(self, value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        AltDeque::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        AltDeque::pop_back(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        AltDeque::iter(self)
+    }
+}
+
+#[cfg(not(feature = "nightly"))]
 impl<T> Drop for AltDeque<T> {
     fn drop(&mut self) {
-        let (front, back) = self.as_mut_slices();
-        unsafe {
-            let _back_dropper = Dropper(back);
-            // use drop for [T]
-            ptr::drop_in_place(front);
-        }
-        // RawVec handles deallocation
+        self.drop_elements();
+    }
+}
+
+// SAFETY: drop only drops the elements and does not access them otherwise. The buffer owns its
+// elements through the `PhantomData<T>` in `RawVec`, so dropck still checks that their drop
+// glue can run.
+#[cfg(feature = "nightly")]
+unsafe impl<#[may_dangle] T> Drop for AltDeque<T> {
+    fn drop(&mut self) {
+        self.drop_elements();
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
 impl<T> Extend<T> for AltDeque<T> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        #[cfg(feature = "nightly")]
+        spec_extend::SpecExtend::spec_extend(self, iter.into_iter());
+        #[cfg(not(feature = "nightly"))]
+        self.extend_desugared(iter.into_iter());
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl<'a, T: 'a + Copy> Extend<&'a T> for AltDeque<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        #[cfg(feature = "nightly")]
+        spec_extend::SpecExtend::spec_extend(self, iter.into_iter());
+        #[cfg(not(feature = "nightly"))]
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl<T> ExtendFront<T> for AltDeque<T> {
+    fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         let mut iter = iter.into_iter();
-        while let Some(element) = iter.next() {
-            if self.is_full() {
-                let (lower, _) = iter.size_hint();
-                self.reserve(lower.max(1));
-            }
+        let (lower, _) = iter.size_hint();
+        self.reserve(lower);
+        loop {
+            // Same as in `extend`, but the front stack grows downwards from tail.
+            let free = self.tail - self.head;
+            let ptr = self.buf.ptr();
+            let tail = &mut self.tail;
+            iter.by_ref().take(free).for_each(|element| {
+                *tail -= 1;
+                // SAFETY: head <= tail as at most `free` elements are written
+                unsafe { ptr::write(ptr.add(*tail), element) };
+            });
 
-            let head = self.head;
-            self.head += 1;
-            // SAFETY: head < tail because buf is not full
-            unsafe {
-                ptr::write(self.buf_add(head), element);
+            match iter.next() {
+                Some(element) => {
+                    let (lower, _) = iter.size_hint();
+                    self.reserve(lower.saturating_add(1));
+                    self.tail -= 1;
+                    // SAFETY: head <= tail because we just reserved space
+                    unsafe { ptr::write(self.buf_add(self.tail), element) };
+                }
+                None => {
+                    record!(self, peak_len);
+                    return;
+                }
             }
         }
     }
 }
 
-impl<'a, T: 'a + Copy> Extend<&'a T> for AltDeque<T> {
-    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
-        self.extend(iter.into_iter().copied());
+#[cfg(not(no_global_oom_handling))]
+impl<'a, T: 'a + Copy> ExtendFront<&'a T> for AltDeque<T> {
+    fn extend_front<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend_front(iter.into_iter().copied());
     }
 }
 
@@ -1805,7 +5091,13 @@ impl<T> From<Vec<T>> for AltDeque<T> {
             let mut other = ManuallyDrop::new(other);
             let (other_buf, len, capacity) = (other.as_mut_ptr(), other.len(), other.capacity());
             let buf = RawVec::from_raw_parts(other_buf, capacity);
-            Self { buf, head: len, tail: capacity }
+            Self {
+                buf,
+                head: len,
+                tail: capacity,
+                #[cfg(feature = "metrics")]
+                stats: Stats { peak_len: len, ..Stats::default() },
+            }
         }
     }
 }
@@ -1837,6 +5129,62 @@ impl<T> From<AltDeque<T>> for Vec<T> {
     }
 }
 
+impl<T> From<Box<[T]>> for AltDeque<T> {
+    /// Turns a [`Box<[T]>`] into an [`AltDeque<T>`] without reallocating.
+    ///
+    /// [`AltDeque<T>`]: crate::AltDeque
+    fn from(other: Box<[T]>) -> Self {
+        Vec::from(other).into()
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl<T: Clone> From<&[T]> for AltDeque<T> {
+    /// Allocates an [`AltDeque<T>`] with the exact length of the slice and fills it by
+    /// cloning the slice's items.
+    ///
+    /// [`AltDeque<T>`]: crate::AltDeque
+    fn from(other: &[T]) -> Self {
+        other.to_vec().into()
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl<T: Clone> From<&mut [T]> for AltDeque<T> {
+    /// Allocates an [`AltDeque<T>`] with the exact length of the slice and fills it by
+    /// cloning the slice's items.
+    ///
+    /// [`AltDeque<T>`]: crate::AltDeque
+    fn from(other: &mut [T]) -> Self {
+        other.to_vec().into()
+    }
+}
+
+impl<T> From<VecDeque<T>> for AltDeque<T> {
+    /// Turns a [`VecDeque<T>`] into an [`AltDeque<T>`].
+    ///
+    /// This never needs to re-allocate, but does need to do *O(n)* data movement if
+    /// the ring buffer of the `VecDeque` is not contiguous.
+    ///
+    /// [`AltDeque<T>`]: crate::AltDeque
+    fn from(other: VecDeque<T>) -> Self {
+        Vec::from(other).into()
+    }
+}
+
+impl<T> From<AltDeque<T>> for VecDeque<T> {
+    /// Turns an [`AltDeque<T>`] into a [`VecDeque<T>`].
+    ///
+    /// This never needs to re-allocate, but does need to do *O(n)* data movement if
+    /// the internal front stack is not empty.
+    ///
+    /// [`AltDeque<T>`]: crate::AltDeque
+    fn from(other: AltDeque<T>) -> Self {
+        Vec::from(other).into()
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
 impl<T, const N: usize> From<[T; N]> for AltDeque<T> {
     /// Converts a `[T; N]` into a `AltDeque<T>`.
     ///
@@ -1855,6 +5203,7 @@ impl<T, const N: usize> From<[T; N]> for AltDeque<T> {
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
 impl<T, const N: usize, const M: usize> From<([T; N], [T; M])> for AltDeque<T> {
     /// Creates a deque from a tuple of arrays. The first one will be used as the
     /// internal front stack and the second as the back stack.
@@ -1878,6 +5227,7 @@ impl<T, const N: usize, const M: usize> From<([T; N], [T; M])> for AltDeque<T> {
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
 impl<T> FromIterator<T> for AltDeque<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let iter = iter.into_iter();
@@ -1888,13 +5238,15 @@ impl<T> FromIterator<T> for AltDeque<T> {
     }
 }
 
+/// Equal deques produce the same hash, no matter how their elements are split between the two
+/// internal stacks. With the `nightly` feature, deques of `u8` and `i8` write their bytes in
+/// chunks instead of one by one, so their hashes differ from the ones without the feature.
 impl<T: Hash> Hash for AltDeque<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        // write_length_prefix is currently unstable, see https://github.com/rust-lang/rust/issues/96762
-        // state.write_length_prefix(self.len());
-
-        state.write_usize(self.len());
-        self.iter().for_each(|elem| elem.hash(state));
+        #[cfg(feature = "nightly")]
+        spec_hash::SpecHash::spec_hash(self, state);
+        #[cfg(not(feature = "nightly"))]
+        self.hash_elements(state);
     }
 }
 
@@ -1945,47 +5297,52 @@ impl<'a, T> IntoIterator for &'a mut AltDeque<T> {
 
 impl<T: PartialOrd> PartialOrd for AltDeque<T> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.iter().partial_cmp(other.iter())
+        for (a, b) in aligned_slices(self.as_slices(), other.as_slices()) {
+            match a.partial_cmp(b) {
+                Some(Ordering::Equal) => {}
+                non_eq => return non_eq,
+            }
+        }
+        self.len().partial_cmp(&other.len())
     }
 }
 
 impl<T: Ord> Ord for AltDeque<T> {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.iter().cmp(other.iter())
+        for (a, b) in aligned_slices(self.as_slices(), other.as_slices()) {
+            match a.cmp(b) {
+                Ordering::Equal => {}
+                non_eq => return non_eq,
+            }
+        }
+        self.len().cmp(&other.len())
     }
 }
 
-impl<T: PartialEq> PartialEq for AltDeque<T> {
-    fn eq(&self, other: &Self) -> bool {
-        if self.len() != other.len() {
-            return false;
-        }
-        let (sa, sb) = self.as_slices();
-        let (oa, ob) = other.as_slices();
-        if sa.len() == oa.len() {
-            sa == oa && sb == ob
-        } else if sa.len() < oa.len() {
-            // Always divisible in three sections, for example:
-            // self:  [a b c|d e f]
-            // other: [0 1 2 3|4 5]
-            // front = 3, mid = 1,
-            // [a b c] == [0 1 2] && [d] == [3] && [e f] == [4 5]
-            let front = sa.len();
-            let mid = oa.len() - front;
-
-            let (oa_front, oa_mid) = oa.split_at(front);
-            let (sb_mid, sb_back) = sb.split_at(mid);
-
-            sa == oa_front && sb_mid == oa_mid && sb_back == ob
-        } else {
-            let front = oa.len();
-            let mid = sa.len() - front;
+impl<T, U> PartialEq<AltDeque<U>> for AltDeque<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &AltDeque<U>) -> bool {
+        self.len() == other.len() && slices_eq(self.as_slices(), other.as_slices())
+    }
+}
 
-            let (sa_front, sa_mid) = sa.split_at(front);
-            let (ob_mid, ob_back) = ob.split_at(mid);
+impl<T, U> PartialEq<VecDeque<U>> for AltDeque<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &VecDeque<U>) -> bool {
+        self.len() == other.len() && slices_eq(self.as_slices(), other.as_slices())
+    }
+}
 
-            sa_front == oa && sa_mid == ob_mid && sb == ob_back
-        }
+impl<T, U> PartialEq<AltDeque<U>> for VecDeque<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &AltDeque<U>) -> bool {
+        self.len() == other.len() && slices_eq(self.as_slices(), other.as_slices())
     }
 }
 
@@ -1997,11 +5354,145 @@ __impl_slice_eq! { [] AltDeque<T>, &mut [U], }
 __impl_slice_eq! { [const N: usize] AltDeque<T>, [U; N], }
 __impl_slice_eq! { [const N: usize] AltDeque<T>, &[U; N], }
 __impl_slice_eq! { [const N: usize] AltDeque<T>, &mut [U; N], }
+__impl_slice_ord! { [] AltDeque<T>, Vec<U>, }
+__impl_slice_ord! { [] AltDeque<T>, &[U], }
+__impl_slice_ord! { [const N: usize] AltDeque<T>, [U; N], }
+
+/// Compares two sequences of the same length that are both split into two slices.
+fn slices_eq<T, U>((sa, sb): (&[T], &[T]), (oa, ob): (&[U], &[U])) -> bool
+where
+    T: PartialEq<U>,
+{
+    if sa.len() == oa.len() {
+        sa == oa && sb == ob
+    } else if sa.len() < oa.len() {
+        // Always divisible in three sections, for example:
+        // self:  [a b c|d e f]
+        // other: [0 1 2 3|4 5]
+        // front = 3, mid = 1,
+        // [a b c] == [0 1 2] && [d] == [3] && [e f] == [4 5]
+        let front = sa.len();
+        let mid = oa.len() - front;
+
+        let (oa_front, oa_mid) = oa.split_at(front);
+        let (sb_mid, sb_back) = sb.split_at(mid);
+
+        sa == oa_front && sb_mid == oa_mid && sb_back == ob
+    } else {
+        let front = oa.len();
+        let mid = sa.len() - front;
+
+        let (sa_front, sa_mid) = sa.split_at(front);
+        let (ob_mid, ob_back) = ob.split_at(mid);
+
+        sa_front == oa && sa_mid == ob_mid && sb == ob_back
+    }
+}
+
+/// Returns an iterator over pairs of equally long slices, the first from `a` and the second
+/// from `b`, that cover both sequences in order until the shorter one ends. As both sequences are
+/// split into two slices, there are at most three pairs, for example:
+///
+/// a: [a b c|d e f g]
+/// b: [0 1|2 3 4]
+/// pairs: ([a b], [0 1]), ([c], [2]), ([d e], [3 4])
+fn aligned_slices<'a, T>(
+    (mut a, mut a_rest): (&'a [T], &'a [T]),
+    (mut b, mut b_rest): (&'a [T], &'a [T]),
+) -> impl Iterator<Item = (&'a [T], &'a [T])> {
+    iter::from_fn(move || {
+        if a.is_empty() {
+            a = mem::take(&mut a_rest);
+        }
+        if b.is_empty() {
+            b = mem::take(&mut b_rest);
+        }
+        let len = a.len().min(b.len());
+        if len == 0 {
+            return None;
+        }
+        let (a_part, a_tail) = a.split_at(len);
+        let (b_part, b_tail) = b.split_at(len);
+        a = a_tail;
+        b = b_tail;
+        Some((a_part, b_part))
+    })
+}
 
 fn index_out_of_bounds(len: usize, index: usize) -> ! {
     panic!("index out of bounds: the len is {} but the index is {}", len, index);
 }
 
+/// Splits the back stack `[start, head)` of a buffer with an empty front stack into two
+/// halves. The first `front_len` elements become the new front stack at the end of the buffer,
+/// the rest become the new back stack at the start of it.
+///
+/// # Safety
+///
+/// `ptr` must point to a buffer of `cap` elements where `[start, head)` is initialized and
+/// everything outside of it can be overwritten.
+unsafe fn split_back_stack<T>(ptr: *mut T, cap: usize, start: usize, head: usize, front_len: usize) {
+    let back_len = head - start - front_len;
+    if cap - front_len >= head {
+        // the new front stack does not overlap the elements that are still to be moved
+        ptr::copy(ptr.add(start), ptr.add(cap - front_len), front_len);
+        ptr::copy(ptr.add(start + front_len), ptr.add(0), back_len);
+    } else {
+        // not enough free space, rotate `[front, back, free]` into `[back, free, front]`
+        let slice = slice::from_raw_parts_mut(ptr.add(start) as *mut MaybeUninit<T>, cap - start);
+        slice.rotate_left(front_len);
+        ptr::copy(ptr.add(start), ptr.add(0), back_len);
+    }
+}
+
+/// Splits the front stack `[tail, end)` of a buffer with an empty back stack into two
+/// halves. The last `back_len` elements become the new back stack at the start of the buffer,
+/// the rest become the new front stack at the end of it.
+///
+/// # Safety
+///
+/// `ptr` must point to a buffer of `cap` elements where `[tail, end)` is initialized and
+/// everything outside of it can be overwritten.
+unsafe fn split_front_stack<T>(ptr: *mut T, cap: usize, tail: usize, end: usize, back_len: usize) {
+    let front_len = end - tail - back_len;
+    if back_len <= tail {
+        // the new back stack does not overlap the elements that are still to be moved
+        ptr::copy(ptr.add(end - back_len), ptr.add(0), back_len);
+        ptr::copy(ptr.add(tail), ptr.add(cap - front_len), front_len);
+    } else {
+        // not enough free space, rotate `[free, front, back]` into `[back, free, front]`
+        let slice = slice::from_raw_parts_mut(ptr as *mut MaybeUninit<T>, end);
+        slice.rotate_right(back_len);
+        ptr::copy(ptr.add(end - front_len), ptr.add(cap - front_len), front_len);
+    }
+}
+
+/// Mirrors every element of the stacks `[0, head)` and `[tail, cap)` to the opposite end of the
+/// buffer, which reverses the logical order and swaps the roles of the stacks. Afterwards the
+/// back stack is `[0, cap - tail)` and the front stack `[cap - head, cap)`.
+///
+/// # Safety
+///
+/// `ptr` must point to a buffer of `cap` elements where exactly `[0, head)` and `[tail, cap)`
+/// are initialized.
+unsafe fn reverse_stacks<T>(ptr: *mut T, cap: usize, head: usize, tail: usize) {
+    let front_len = cap - tail;
+    let common = cmp::min(front_len, head);
+    // the outermost elements of both stacks swap places
+    for i in 0..common {
+        ptr::swap(ptr.add(i), ptr.add(cap - 1 - i));
+    }
+    if head > common {
+        // the rest of the back stack is reversed and moved to the end of the buffer
+        slice::from_raw_parts_mut(ptr.add(common), head - common).reverse();
+        ptr::copy(ptr.add(common), ptr.add(cap - head), head - common);
+    } else if front_len > common {
+        // the rest of the front stack is reversed and moved to the start of the buffer
+        slice::from_raw_parts_mut(ptr.add(tail), front_len - common).reverse();
+        ptr::copy(ptr.add(tail), ptr.add(common), front_len - common);
+    }
+}
+
 fn simplify_range(range: impl RangeBounds<usize>, len: usize) -> Range<usize> {
     // we later check for start > end so ignore here if start > len
     let start = match range.start_bound() {
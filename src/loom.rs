@@ -0,0 +1,38 @@
+//! The synchronization primitives of the [`spsc`] and [`concurrent`] queues.
+//!
+//! They come from the standard library, or from [loom](https://docs.rs/loom) when building with
+//! `RUSTFLAGS="--cfg loom"`, so that the model checks in `loom_tests.rs` can explore every
+//! interleaving of the threads that share a queue.
+//!
+//! [`spsc`]: crate::spsc
+//! [`concurrent`]: crate::concurrent
+
+#[cfg(loom)]
+pub(crate) use ::loom::cell::UnsafeCell;
+#[cfg(loom)]
+pub(crate) use ::loom::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(loom)]
+pub(crate) use ::loom::sync::{Arc, Mutex, MutexGuard};
+
+#[cfg(not(loom))]
+pub(crate) use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(not(loom))]
+pub(crate) use std::sync::{Arc, Mutex, MutexGuard};
+
+/// An [`std::cell::UnsafeCell`] with the closure-based access of loom's `UnsafeCell`, which
+/// lets loom check that no slot is read and written at the same time.
+#[cfg(not(loom))]
+pub(crate) struct UnsafeCell<T>(std::cell::UnsafeCell<T>);
+
+#[cfg(not(loom))]
+impl<T> UnsafeCell<T> {
+    #[inline]
+    pub(crate) fn new(value: T) -> Self {
+        Self(std::cell::UnsafeCell::new(value))
+    }
+
+    #[inline]
+    pub(crate) fn with_mut<R>(&self, f: impl FnOnce(*mut T) -> R) -> R {
+        f(self.0.get())
+    }
+}
@@ -0,0 +1,101 @@
+//! Model checks of the concurrent queues with [loom](https://docs.rs/loom), which runs every test
+//! under all interleavings of its threads. Run them with
+//! `RUSTFLAGS="--cfg loom" cargo test --release --lib`.
+
+use loom::sync::Arc;
+use loom::thread;
+
+use crate::concurrent::TwoLockQueue;
+use crate::spsc;
+
+#[test]
+fn spsc_handoff() {
+    loom::model(|| {
+        let (mut producer, mut consumer) = spsc::channel(2);
+        let handle = thread::spawn(move || {
+            for i in 0..3 {
+                let mut value = i;
+                while let Err(rejected) = producer.push(value) {
+                    value = rejected;
+                    thread::yield_now();
+                }
+            }
+        });
+        let mut received = Vec::new();
+        while received.len() < 3 {
+            match consumer.pop() {
+                Some(value) => received.push(value),
+                None => thread::yield_now(),
+            }
+        }
+        handle.join().unwrap();
+        assert_eq!(received, [0, 1, 2]);
+        assert_eq!(consumer.pop(), None);
+    });
+}
+
+#[test]
+fn spsc_peek_and_len() {
+    loom::model(|| {
+        let (mut producer, mut consumer) = spsc::channel(1);
+        let handle = thread::spawn(move || {
+            producer.push(1).unwrap();
+            assert!(producer.len() <= 1);
+        });
+        let peeked = consumer.peek_with(|&x| x);
+        assert!(consumer.len() <= 1);
+        handle.join().unwrap();
+        assert_eq!(peeked.or(consumer.peek_with(|&x| x)), Some(1));
+        assert_eq!(consumer.pop(), Some(1));
+    });
+}
+
+#[test]
+fn spsc_drop_remaining() {
+    loom::model(|| {
+        let (mut producer, mut consumer) = spsc::channel(2);
+        let element = Arc::new(());
+        producer.push(Arc::clone(&element)).unwrap();
+        let handle = thread::spawn(move || {
+            // the second push and the drop of the producer race with the consumer
+            let _ = producer.push(Arc::new(()));
+        });
+        drop(consumer.pop());
+        drop(consumer);
+        handle.join().unwrap();
+        assert_eq!(Arc::strong_count(&element), 1);
+    });
+}
+
+#[test]
+fn two_lock_queue_push_pop() {
+    loom::model(|| {
+        let queue = Arc::new(TwoLockQueue::new());
+        let handles: Vec<_> = (0..2)
+            .map(|id| {
+                let queue = Arc::clone(&queue);
+                thread::spawn(move || {
+                    queue.push((id, 0));
+                    queue.push((id, 1));
+                })
+            })
+            .collect();
+        let mut received = Vec::new();
+        while let Some(value) = queue.pop() {
+            received.push(value);
+        }
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        while let Some(value) = queue.pop() {
+            received.push(value);
+        }
+        assert_eq!(received.len(), 4);
+        for id in 0..2 {
+            // the elements of one producer arrive in order, even across a flip
+            let order: Vec<_> = received.iter().filter(|x| x.0 == id).map(|x| x.1).collect();
+            assert_eq!(order, [0, 1]);
+        }
+        assert!(queue.is_empty());
+    });
+}
@@ -16,3 +16,54 @@ macro_rules! __impl_slice_eq {
         }
     }
 }
+
+macro_rules! __impl_slice_ord {
+    ([$($vars:tt)*] $lhs:ty, $rhs:ty, $($constraints:tt)*) => {
+        impl<T, U, $($vars)*> PartialOrd<$rhs> for $lhs
+        where
+            T: PartialOrd<U>,
+            $($constraints)*
+        {
+            fn partial_cmp(&self, other: &$rhs) -> Option<::core::cmp::Ordering> {
+                let (sa, sb) = self.as_slices();
+                sa.iter().chain(sb).partial_cmp(other[..].iter())
+            }
+        }
+    }
+}
+
+/// Checks the internal invariants of a deque. Expands to nothing unless the `debug-invariants`
+/// feature is enabled.
+macro_rules! check_invariants {
+    ($deque:expr) => {
+        #[cfg(feature = "debug-invariants")]
+        $deque.check_invariants();
+    };
+}
+
+/// Emits a `tracing` event about the internals of a deque, see the `tracing` feature. Expands to
+/// nothing unless that feature is enabled.
+macro_rules! trace_event {
+    ($($args:tt)*) => {
+        #[cfg(feature = "tracing")]
+        ::tracing::debug!(target: "altdeque", $($args)*);
+    };
+}
+
+/// Updates the operation counters of a deque. Expands to nothing unless the `metrics` feature is
+/// enabled.
+macro_rules! record {
+    ($deque:expr, peak_len) => {
+        #[cfg(feature = "metrics")]
+        {
+            let len = $deque.len();
+            $deque.stats.peak_len = ::core::cmp::max($deque.stats.peak_len, len);
+        }
+    };
+    ($deque:expr, $counter:ident += $n:expr) => {
+        #[cfg(feature = "metrics")]
+        {
+            $deque.stats.$counter += $n as u64;
+        }
+    };
+}
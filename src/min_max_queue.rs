@@ -0,0 +1,357 @@
+use std::fmt;
+
+use crate::{AltDeque, Iter};
+
+/// A deque that can report its minimum and maximum element in *O(1)* time.
+///
+/// This is the classic two-stack min-queue built on top of an [`AltDeque`]. Next to every
+/// element, the position of the minimum and maximum of all elements below it in the same stack
+/// is recorded, so the minimum of the deque is the smaller one of the two stack tops. A push
+/// only computes the entry of the new element. Only when a pop finds its stack empty and
+/// [`AltDeque`] splits the other stack, the entries of all elements are computed anew, which
+/// keeps all operations amortized *O(1)* for the same reason as in [`AltDeque`].
+///
+/// This makes sliding-window minima and maxima cheap: push new elements to the back, pop old
+/// ones from the front and look at [`min`] and [`max`] in between.
+///
+/// [`min`]: MinMaxQueue::min
+/// [`max`]: MinMaxQueue::max
+///
+/// # Examples
+///
+/// ```
+/// # use altdeque::MinMaxQueue;
+/// let mut window = MinMaxQueue::new();
+/// let mut maxima = Vec::new();
+/// for x in [1, 3, 2, 5, 4, 1, 0] {
+///     window.push_back(x);
+///     if window.len() > 3 {
+///         window.pop_front();
+///     }
+///     maxima.push(*window.max().unwrap());
+/// }
+/// assert_eq!(maxima, [1, 3, 3, 5, 5, 5, 4]);
+/// ```
+pub struct MinMaxQueue<T> {
+    deque: AltDeque<T>,
+    // Positions of the minimum and maximum of the elements up to the one at the same position,
+    // counted from the bottom of the stack. The bottom of the front stack is its last element,
+    // the bottom of the back stack its first one.
+    front: Vec<(usize, usize)>,
+    back: Vec<(usize, usize)>,
+}
+
+impl<T> MinMaxQueue<T> {
+    /// Creates an empty queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MinMaxQueue;
+    /// let queue: MinMaxQueue<i32> = MinMaxQueue::new();
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self { deque: AltDeque::new(), front: Vec::new(), back: Vec::new() }
+    }
+
+    /// Creates an empty queue with space for at least `capacity` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MinMaxQueue;
+    /// let queue: MinMaxQueue<i32> = MinMaxQueue::with_capacity(10);
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { deque: AltDeque::with_capacity(capacity), front: Vec::new(), back: Vec::new() }
+    }
+
+    /// Returns the number of elements in the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MinMaxQueue;
+    /// let mut queue = MinMaxQueue::new();
+    /// queue.push_back(1);
+    /// assert_eq!(queue.len(), 1);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.deque.len()
+    }
+
+    /// Returns `true` if the queue is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MinMaxQueue;
+    /// let mut queue = MinMaxQueue::new();
+    /// assert!(queue.is_empty());
+    /// queue.push_back(1);
+    /// assert!(!queue.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.deque.is_empty()
+    }
+
+    /// Provides a reference to the front element, or `None` if the queue is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MinMaxQueue;
+    /// let queue: MinMaxQueue<_> = [1, 2].into_iter().collect();
+    /// assert_eq!(queue.front(), Some(&1));
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        self.deque.front()
+    }
+
+    /// Provides a reference to the back element, or `None` if the queue is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MinMaxQueue;
+    /// let queue: MinMaxQueue<_> = [1, 2].into_iter().collect();
+    /// assert_eq!(queue.back(), Some(&2));
+    /// ```
+    pub fn back(&self) -> Option<&T> {
+        self.deque.back()
+    }
+
+    /// Returns a front-to-back iterator over the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MinMaxQueue;
+    /// let queue: MinMaxQueue<_> = [1, 2].into_iter().collect();
+    /// assert!(queue.iter().eq(&[1, 2]));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.deque.iter()
+    }
+
+    /// Removes all elements from the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MinMaxQueue;
+    /// let mut queue: MinMaxQueue<_> = [1, 2].into_iter().collect();
+    /// queue.clear();
+    /// assert_eq!(queue.min(), None);
+    /// ```
+    pub fn clear(&mut self) {
+        self.deque.clear();
+        self.front.clear();
+        self.back.clear();
+    }
+
+    /// Returns the element at position `pos` counted from the bottom of the front stack.
+    fn front_at(&self, pos: usize) -> &T {
+        let front = self.deque.as_slices().0;
+        &front[front.len() - 1 - pos]
+    }
+
+    /// Returns the element at position `pos` counted from the bottom of the back stack.
+    fn back_at(&self, pos: usize) -> &T {
+        &self.deque.as_slices().1[pos]
+    }
+}
+
+impl<T: Ord> MinMaxQueue<T> {
+    /// Prepends an element to the front of the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MinMaxQueue;
+    /// let mut queue = MinMaxQueue::new();
+    /// queue.push_front(2);
+    /// queue.push_front(1);
+    /// assert_eq!(queue.front(), Some(&1));
+    /// assert_eq!(queue.max(), Some(&2));
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        self.deque.push_front(value);
+        let entry = next_entry(self.front.last(), self.front.len(), |pos| self.front_at(pos));
+        self.front.push(entry);
+    }
+
+    /// Appends an element to the back of the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MinMaxQueue;
+    /// let mut queue = MinMaxQueue::new();
+    /// queue.push_back(1);
+    /// queue.push_back(2);
+    /// assert_eq!(queue.back(), Some(&2));
+    /// assert_eq!(queue.min(), Some(&1));
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        self.deque.push_back(value);
+        let entry = next_entry(self.back.last(), self.back.len(), |pos| self.back_at(pos));
+        self.back.push(entry);
+    }
+
+    /// Removes the first element and returns it, or `None` if the queue is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MinMaxQueue;
+    /// let mut queue: MinMaxQueue<_> = [1, 2].into_iter().collect();
+    /// assert_eq!(queue.pop_front(), Some(1));
+    /// assert_eq!(queue.min(), Some(&2));
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        let split = self.front.is_empty();
+        let value = self.deque.pop_front()?;
+        if split {
+            self.rebuild();
+        } else {
+            self.front.pop();
+        }
+        Some(value)
+    }
+
+    /// Removes the last element and returns it, or `None` if the queue is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MinMaxQueue;
+    /// let mut queue: MinMaxQueue<_> = [1, 2].into_iter().collect();
+    /// assert_eq!(queue.pop_back(), Some(2));
+    /// assert_eq!(queue.max(), Some(&1));
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        let split = self.back.is_empty();
+        let value = self.deque.pop_back()?;
+        if split {
+            self.rebuild();
+        } else {
+            self.back.pop();
+        }
+        Some(value)
+    }
+
+    /// Returns the smallest element, or `None` if the queue is empty. If several elements are
+    /// equally minimal, any of them may be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MinMaxQueue;
+    /// let queue: MinMaxQueue<_> = [3, 1, 2].into_iter().collect();
+    /// assert_eq!(queue.min(), Some(&1));
+    /// ```
+    pub fn min(&self) -> Option<&T> {
+        let front = self.front.last().map(|&(min, _)| self.front_at(min));
+        let back = self.back.last().map(|&(min, _)| self.back_at(min));
+        front.into_iter().chain(back).min()
+    }
+
+    /// Returns the largest element, or `None` if the queue is empty. If several elements are
+    /// equally maximal, any of them may be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MinMaxQueue;
+    /// let queue: MinMaxQueue<_> = [3, 1, 2].into_iter().collect();
+    /// assert_eq!(queue.max(), Some(&3));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        let front = self.front.last().map(|&(_, max)| self.front_at(max));
+        let back = self.back.last().map(|&(_, max)| self.back_at(max));
+        front.into_iter().chain(back).max()
+    }
+
+    /// Recomputes the entries of both stacks after the deque moved elements between them.
+    fn rebuild(&mut self) {
+        let (front_len, back_len) = {
+            let (front, back) = self.deque.as_slices();
+            (front.len(), back.len())
+        };
+        self.front.clear();
+        self.back.clear();
+        for pos in 0..front_len {
+            let entry = next_entry(self.front.last(), pos, |pos| self.front_at(pos));
+            self.front.push(entry);
+        }
+        for pos in 0..back_len {
+            let entry = next_entry(self.back.last(), pos, |pos| self.back_at(pos));
+            self.back.push(entry);
+        }
+    }
+}
+
+/// Computes the entry for the element at `pos` from the entry of the element below it.
+fn next_entry<'a, T: Ord + 'a>(
+    prev: Option<&(usize, usize)>,
+    pos: usize,
+    get: impl Fn(usize) -> &'a T,
+) -> (usize, usize) {
+    match prev {
+        None => (pos, pos),
+        Some(&(min, max)) => {
+            let value = get(pos);
+            let min = if value < get(min) { pos } else { min };
+            let max = if value > get(max) { pos } else { max };
+            (min, max)
+        }
+    }
+}
+
+impl<T: Clone> Clone for MinMaxQueue<T> {
+    fn clone(&self) -> Self {
+        // the entries refer to positions in the stacks, so the split between them is kept
+        let (front, back) = self.deque.as_slices();
+        let mut deque = AltDeque::with_capacity(self.len());
+        front.iter().rev().for_each(|value| deque.push_front(value.clone()));
+        back.iter().for_each(|value| deque.push_back(value.clone()));
+        Self { deque, front: self.front.clone(), back: self.back.clone() }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MinMaxQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Default for MinMaxQueue<T> {
+    /// Creates an empty queue.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> Extend<T> for MinMaxQueue<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push_back(value));
+    }
+}
+
+impl<T: Ord> FromIterator<T> for MinMaxQueue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut queue = Self::new();
+        queue.extend(iter);
+        queue
+    }
+}
@@ -0,0 +1,649 @@
+use core::hash::{Hash, Hasher};
+use core::ops::{Index, IndexMut};
+
+use std::cmp;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io;
+use std::marker::PhantomData;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::ptr::{self, NonNull};
+use std::slice;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use memmap2::MmapMut;
+
+use crate::{index_out_of_bounds, split_back_stack, split_front_stack, Dropper, Iter, IterMut};
+
+/// Mappings start at a page boundary, and no supported platform has pages smaller than this.
+const MIN_PAGE_SIZE: usize = 4096;
+
+/// A deque whose buffer is a memory-mapped file, for queues that do not fit into memory.
+///
+/// `MmapAltDeque` uses the same two-stack layout as [`AltDeque`], but its buffer is a shared
+/// mapping of a temporary file instead of an allocation. The operating system writes pages to
+/// that file when memory gets tight and reads them back on access, so the working set can
+/// exceed the memory without the process being killed. The back stack is only written at its
+/// end and the front stack only read at its start, so the pages in between stay cold, which
+/// is the access pattern that page eviction handles well.
+///
+/// The file is created in [`std::env::temp_dir`] or the directory given to [`new_in`] and
+/// deleted again when the deque is dropped, on Unix right away after opening it. It is only
+/// scratch space, not a persistent queue. Elements that own heap memory, like a `String`, only
+/// store their pointers in the file, so this is meant for plain data.
+///
+/// Growing the file and mapping it again can fail, which [`reserve`] reports as an
+/// [`io::Error`]. The push methods panic in that case instead.
+///
+/// [`AltDeque`]: crate::AltDeque
+/// [`new_in`]: MmapAltDeque::new_in
+/// [`reserve`]: MmapAltDeque::reserve
+///
+/// # Examples
+///
+/// ```
+/// # use altdeque::MmapAltDeque;
+/// let mut deque = MmapAltDeque::new()?;
+/// deque.extend(0..100_000_u64);
+/// deque.push_front(42);
+/// assert_eq!(deque.pop_front(), Some(42));
+/// assert_eq!(deque.pop_back(), Some(99_999));
+/// assert_eq!(deque.len(), 99_999);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct MmapAltDeque<T> {
+    // Same meaning as in AltDeque, with the length of the mapping as the capacity.
+    // 0 <= head <= tail <= cap
+    tail: usize,
+    head: usize,
+    ptr: NonNull<T>,
+    cap: usize,
+    // None until the first element is pushed, and always for zero-sized elements
+    map: Option<MmapMut>,
+    file: File,
+    // the file to delete on drop, on Unix it is deleted right away
+    path: Option<PathBuf>,
+    _marker: PhantomData<T>,
+}
+
+// SAFETY: MmapAltDeque owns its elements and its mapping, like a Vec<T> owns its buffer.
+unsafe impl<T: Send> Send for MmapAltDeque<T> {}
+unsafe impl<T: Sync> Sync for MmapAltDeque<T> {}
+
+impl<T> MmapAltDeque<T> {
+    /// Creates an empty deque backed by a new file in the temporary directory of the system.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MmapAltDeque;
+    /// let deque: MmapAltDeque<u32> = MmapAltDeque::new()?;
+    /// assert!(deque.is_empty());
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn new() -> io::Result<Self> {
+        Self::new_in(std::env::temp_dir())
+    }
+
+    /// Creates an empty deque backed by a new file in `dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` needs an alignment of more than 4096 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MmapAltDeque;
+    /// let dir = std::env::temp_dir();
+    /// let deque: MmapAltDeque<u32> = MmapAltDeque::new_in(&dir)?;
+    /// assert_eq!(deque.capacity(), 0);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn new_in<P: AsRef<Path>>(dir: P) -> io::Result<Self> {
+        assert!(mem::align_of::<T>() <= MIN_PAGE_SIZE, "alignment of T is larger than a page");
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let name = format!(".altdeque-{}-{}", process::id(), COUNTER.fetch_add(1, Ordering::Relaxed));
+        let path = dir.as_ref().join(name);
+        let file = OpenOptions::new().read(true).write(true).create_new(true).open(&path)?;
+        // an open file can be deleted on Unix, it stays around until it is closed
+        let path = if cfg!(unix) { fs::remove_file(&path).err().map(|_| path) } else { Some(path) };
+        let cap = if mem::size_of::<T>() == 0 { usize::MAX } else { 0 };
+        let ptr = NonNull::dangling();
+        Ok(Self { tail: cap, head: 0, ptr, cap, map: None, file, path, _marker: PhantomData })
+    }
+
+    /// Returns the number of elements the deque can hold without growing the file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MmapAltDeque;
+    /// let mut deque: MmapAltDeque<u32> = MmapAltDeque::new()?;
+    /// deque.reserve(10)?;
+    /// assert!(deque.capacity() >= 10);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    /// Returns the number of elements in the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MmapAltDeque;
+    /// let mut deque = MmapAltDeque::new()?;
+    /// deque.push_back(1);
+    /// assert_eq!(deque.len(), 1);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn len(&self) -> usize {
+        // this cannot overflow because head <= tail <= cap
+        self.cap - self.tail + self.head
+    }
+
+    /// Returns wether the deque is empty or not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MmapAltDeque;
+    /// let mut deque = MmapAltDeque::new()?;
+    /// assert!(deque.is_empty());
+    /// deque.push_back(42);
+    /// assert!(!deque.is_empty());
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.head == 0 && self.tail == self.cap
+    }
+
+    /// Reserves capacity for at least `additional` more elements. The file grows at least to
+    /// double its size, so that pushing stays amortized *O(1)*.
+    ///
+    /// Growing maps the file again, which needs no copy of the elements, only the front stack
+    /// is moved to the new end of the mapping.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the capacity overflows or the file cannot be grown or mapped. The
+    /// deque is unchanged in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MmapAltDeque;
+    /// let mut deque = MmapAltDeque::new()?;
+    /// deque.push_back(1_u64);
+    /// deque.reserve(1000)?;
+    /// assert!(deque.capacity() >= 1001);
+    /// assert!(deque.reserve(usize::MAX).is_err());
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn reserve(&mut self, additional: usize) -> io::Result<()> {
+        let len = self.len();
+        if self.cap - len >= additional {
+            return Ok(());
+        }
+        let overflow = || io::Error::new(io::ErrorKind::OutOfMemory, "capacity overflow");
+        if mem::size_of::<T>() == 0 {
+            return Err(overflow());
+        }
+        let min_cap = cmp::max(MIN_PAGE_SIZE / mem::size_of::<T>(), 1);
+        let new_cap = len
+            .checked_add(additional)
+            .map(|required| cmp::max(cmp::max(self.cap * 2, required), min_cap))
+            .filter(|&new_cap| new_cap <= isize::MAX as usize / mem::size_of::<T>())
+            .ok_or_else(overflow)?;
+
+        let old_len = self.file.metadata()?.len();
+        self.file.set_len((new_cap * mem::size_of::<T>()) as u64)?;
+        // SAFETY: the file was created by this deque and is only accessed through the mapping
+        let mut map = match unsafe { MmapMut::map_mut(&self.file) } {
+            Ok(map) => map,
+            Err(err) => {
+                let _ = self.file.set_len(old_len);
+                return Err(err);
+            }
+        };
+        // both mappings are shared, so the new one already sees the elements of the old one
+        self.ptr = NonNull::new(map.as_mut_ptr()).unwrap().cast();
+        self.map = Some(map);
+        let front_len = self.cap - self.tail;
+        let new_tail = new_cap - front_len;
+        // SAFETY: the front stack fits at the end of the larger buffer
+        unsafe { ptr::copy(self.buf_add(self.tail), self.buf_add(new_tail), front_len) };
+        self.tail = new_tail;
+        self.cap = new_cap;
+        Ok(())
+    }
+
+    /// Returns a pair of slices which contain, in order, the contents of the deque. These are
+    /// equal the front stack and the back stack used internally.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MmapAltDeque;
+    /// let mut deque = MmapAltDeque::new()?;
+    /// deque.push_back(1);
+    /// deque.push_front(0);
+    /// assert_eq!(deque.as_slices(), (&[0][..], &[1][..]));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        // SAFETY: all elements in the ranges [0, head) and [tail, cap) are valid
+        unsafe {
+            let front = slice::from_raw_parts(self.buf_add(self.tail), self.cap - self.tail);
+            let back = slice::from_raw_parts(self.buf_add(0), self.head);
+            (front, back)
+        }
+    }
+
+    /// Returns a mutable pair of slices which contain, in order, the contents of the deque.
+    ///
+    /// See the non-mutable version [`as_slices`] for details and examples.
+    ///
+    /// [`as_slices`]: MmapAltDeque::as_slices
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        // SAFETY: all elements in the ranges [0, head) and [tail, cap) are valid and do not overlap
+        unsafe {
+            let front = slice::from_raw_parts_mut(self.buf_add(self.tail), self.cap - self.tail);
+            let back = slice::from_raw_parts_mut(self.buf_add(0), self.head);
+            (front, back)
+        }
+    }
+
+    /// Provides a reference to the element at the given index.
+    ///
+    /// Element at index 0 is the front of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MmapAltDeque;
+    /// let mut deque = MmapAltDeque::new()?;
+    /// deque.extend([1, 2]);
+    /// assert_eq!(deque.get(1), Some(&2));
+    /// assert_eq!(deque.get(2), None);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let (front, back) = self.as_slices();
+        if index < front.len() {
+            front.get(index)
+        } else {
+            back.get(index - front.len())
+        }
+    }
+
+    /// Provides a mutable reference to the element at the given index.
+    ///
+    /// Element at index 0 is the front of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MmapAltDeque;
+    /// let mut deque = MmapAltDeque::new()?;
+    /// deque.extend([1, 2]);
+    /// *deque.get_mut(1).unwrap() += 40;
+    /// assert_eq!(deque.get(1), Some(&42));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let (front, back) = self.as_mut_slices();
+        if index < front.len() {
+            front.get_mut(index)
+        } else {
+            back.get_mut(index - front.len())
+        }
+    }
+
+    /// Provides a reference to the front element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MmapAltDeque;
+    /// let mut deque = MmapAltDeque::new()?;
+    /// assert_eq!(deque.front(), None);
+    /// deque.extend([1, 2]);
+    /// assert_eq!(deque.front(), Some(&1));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Provides a reference to the back element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MmapAltDeque;
+    /// let mut deque = MmapAltDeque::new()?;
+    /// assert_eq!(deque.back(), None);
+    /// deque.extend([1, 2]);
+    /// assert_eq!(deque.back(), Some(&2));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len().wrapping_sub(1))
+    }
+
+    /// Removes the first element and returns it, or `None` if the deque is empty.
+    ///
+    /// If the front stack is empty, half of the other stack is moved over first, like in
+    /// [`AltDeque::pop_front`]. That touches every page of the back stack.
+    ///
+    /// [`AltDeque::pop_front`]: crate::AltDeque::pop_front
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MmapAltDeque;
+    /// let mut deque = MmapAltDeque::new()?;
+    /// deque.extend([1, 2]);
+    /// assert_eq!(deque.pop_front(), Some(1));
+    /// assert_eq!(deque.pop_front(), Some(2));
+    /// assert_eq!(deque.pop_front(), None);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.tail != self.cap {
+            let tail = self.tail;
+            self.tail += 1;
+            // SAFETY: tail < cap
+            unsafe { Some(ptr::read(self.buf_add(tail))) }
+        } else if self.head != 0 {
+            let len = self.head;
+            let front_len = len / 2;
+            // SAFETY: head > 0 and the front stack is empty
+            unsafe {
+                let value = ptr::read(self.buf_add(0));
+                // ignore the first element because we return it
+                split_back_stack(self.buf_add(0), self.cap, 1, len, front_len);
+                self.tail = self.cap - front_len;
+                self.head = len - 1 - front_len;
+                Some(value)
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Removes the last element from the deque and returns it, or `None` if the deque is empty.
+    ///
+    /// If the back stack is empty, half of the other stack is moved over first, like in
+    /// [`AltDeque::pop_back`].
+    ///
+    /// [`AltDeque::pop_back`]: crate::AltDeque::pop_back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MmapAltDeque;
+    /// let mut deque = MmapAltDeque::new()?;
+    /// deque.extend([1, 2]);
+    /// assert_eq!(deque.pop_back(), Some(2));
+    /// assert_eq!(deque.pop_back(), Some(1));
+    /// assert_eq!(deque.pop_back(), None);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.head != 0 {
+            self.head -= 1;
+            // SAFETY: old head was > 0
+            unsafe { Some(ptr::read(self.buf_add(self.head))) }
+        } else if self.tail != self.cap {
+            let len = self.cap - self.tail;
+            let back_len = len / 2;
+            // SAFETY: tail < cap and the back stack is empty
+            unsafe {
+                let value = ptr::read(self.buf_add(self.cap - 1));
+                // ignore the last element because we return it
+                split_front_stack(self.buf_add(0), self.cap, self.tail, self.cap - 1, back_len);
+                self.head = back_len;
+                self.tail = self.cap - (len - 1 - back_len);
+                Some(value)
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Prepends an element to the deque.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the deque is full and the file cannot be grown, see [`reserve`].
+    ///
+    /// [`reserve`]: MmapAltDeque::reserve
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MmapAltDeque;
+    /// let mut deque = MmapAltDeque::new()?;
+    /// deque.push_front(1);
+    /// deque.push_front(2);
+    /// assert_eq!(deque, [2, 1]);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        if self.tail == self.head {
+            self.grow();
+        }
+        self.tail -= 1;
+        // SAFETY: old tail was > head because buf is not full
+        unsafe {
+            ptr::write(self.buf_add(self.tail), value);
+        }
+    }
+
+    /// Appends an element to the back of the deque.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the deque is full and the file cannot be grown, see [`reserve`].
+    ///
+    /// [`reserve`]: MmapAltDeque::reserve
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MmapAltDeque;
+    /// let mut deque = MmapAltDeque::new()?;
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// assert_eq!(deque, [1, 2]);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        if self.tail == self.head {
+            self.grow();
+        }
+        // SAFETY: head < tail because buf is not full
+        unsafe {
+            ptr::write(self.buf_add(self.head), value);
+        }
+        self.head += 1;
+    }
+
+    /// Removes all elements. The file keeps its size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MmapAltDeque;
+    /// let mut deque = MmapAltDeque::new()?;
+    /// deque.extend([1, 2]);
+    /// deque.clear();
+    /// assert!(deque.is_empty());
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn clear(&mut self) {
+        let (front, back) = self.as_mut_slices();
+        let (front, back) = (front as *mut [T], back as *mut [T]);
+        self.head = 0;
+        self.tail = self.cap;
+        // SAFETY: the elements are no longer part of the deque, if a destructor panics the
+        // remaining ones are still dropped
+        unsafe {
+            let _back_dropper = Dropper(&mut *back);
+            ptr::drop_in_place(front);
+        }
+    }
+
+    /// Returns a front-to-back iterator over the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MmapAltDeque;
+    /// let mut deque = MmapAltDeque::new()?;
+    /// deque.push_back(2);
+    /// deque.push_front(1);
+    /// assert_eq!(deque.iter().collect::<Vec<_>>(), [&1, &2]);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        front.iter().chain(back.iter())
+    }
+
+    /// Returns a front-to-back iterator over the deque that returns mutable references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::MmapAltDeque;
+    /// let mut deque = MmapAltDeque::new()?;
+    /// deque.extend([1, 2]);
+    /// deque.iter_mut().for_each(|el| *el += 10);
+    /// assert_eq!(deque, [11, 12]);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+        front.iter_mut().chain(back.iter_mut())
+    }
+
+    /// Doubles the capacity. This method is inline(never), so we expect it to only be called in
+    /// cold paths.
+    #[inline(never)]
+    fn grow(&mut self) {
+        if let Err(err) = self.reserve(1) {
+            panic!("failed to grow the file of an MmapAltDeque: {err}");
+        }
+    }
+
+    #[inline]
+    unsafe fn buf_add(&self, offset: usize) -> *mut T {
+        self.ptr.as_ptr().add(offset)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MmapAltDeque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T> Drop for MmapAltDeque<T> {
+    fn drop(&mut self) {
+        self.clear();
+        if let Some(path) = &self.path {
+            // the mapping and the file must be closed first on Windows
+            self.map = None;
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+impl<T> Extend<T> for MmapAltDeque<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        if let Err(err) = self.reserve(iter.size_hint().0) {
+            panic!("failed to grow the file of an MmapAltDeque: {err}");
+        }
+        iter.for_each(|elem| self.push_back(elem));
+    }
+}
+
+impl<T: Hash> Hash for MmapAltDeque<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        self.iter().for_each(|elem| elem.hash(state));
+    }
+}
+
+impl<T> Index<usize> for MmapAltDeque<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).unwrap_or_else(|| index_out_of_bounds(self.len(), index))
+    }
+}
+
+impl<T> IndexMut<usize> for MmapAltDeque<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len();
+        self.get_mut(index).unwrap_or_else(|| index_out_of_bounds(len, index))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a MmapAltDeque<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut MmapAltDeque<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: PartialEq> PartialEq for MmapAltDeque<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for MmapAltDeque<T> {}
+
+impl<T: PartialEq<U>, U> PartialEq<[U]> for MmapAltDeque<T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for MmapAltDeque<T> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        *self == other[..]
+    }
+}
@@ -0,0 +1,185 @@
+//! Differential tests that replay long random operation sequences against both `AltDeque` and
+//! `VecDeque` and compare their observable state after every step.
+
+use std::collections::VecDeque;
+
+use crate::AltDeque;
+
+/// A small xorshift generator, so the tests are reproducible without extra dependencies.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn coin(&mut self) -> bool {
+        self.next() >> 63 == 1
+    }
+
+    /// Returns a number in `0..=max`.
+    fn up_to(&mut self, max: usize) -> usize {
+        (self.next() % (max as u64 + 1)) as usize
+    }
+}
+
+fn assert_same(deque: &AltDeque<u32>, model: &VecDeque<u32>, step: usize, op: &str) {
+    assert_eq!(deque.len(), model.len(), "len differs after step {step} ({op})");
+    assert!(deque.iter().eq(model.iter()), "elements differ after step {step} ({op}): {deque:?} != {model:?}");
+    assert!(deque.iter().rev().eq(model.iter().rev()), "reverse iteration differs after step {step} ({op})");
+    assert_eq!(deque.front(), model.front(), "front differs after step {step} ({op})");
+    assert_eq!(deque.back(), model.back(), "back differs after step {step} ({op})");
+    assert!(deque.capacity() >= deque.len());
+}
+
+fn run(seed: u64, steps: usize) {
+    let mut rng = Rng(seed);
+    let mut deque = AltDeque::new();
+    let mut model = VecDeque::new();
+    let mut next_value = 0u32;
+
+    for step in 0..steps {
+        let len = model.len();
+        let op = match rng.up_to(15) {
+            0 | 1 => {
+                deque.push_back(next_value);
+                model.push_back(next_value);
+                next_value += 1;
+                "push_back"
+            }
+            2 | 3 => {
+                deque.push_front(next_value);
+                model.push_front(next_value);
+                next_value += 1;
+                "push_front"
+            }
+            4 => {
+                assert_eq!(deque.pop_back(), model.pop_back());
+                "pop_back"
+            }
+            5 => {
+                assert_eq!(deque.pop_front(), model.pop_front());
+                "pop_front"
+            }
+            6 => {
+                let index = rng.up_to(len);
+                deque.insert(index, next_value);
+                model.insert(index, next_value);
+                next_value += 1;
+                "insert"
+            }
+            7 => {
+                let index = rng.up_to(len);
+                assert_eq!(deque.remove(index), model.remove(index));
+                "remove"
+            }
+            8 => {
+                let mid = rng.up_to(len);
+                if rng.coin() {
+                    deque.rotate_left(mid);
+                    model.rotate_left(mid);
+                } else {
+                    deque.rotate_right(mid);
+                    model.rotate_right(mid);
+                }
+                "rotate"
+            }
+            9 => {
+                let start = rng.up_to(len);
+                let end = start + rng.up_to(len - start);
+                let taken = rng.up_to(end - start);
+                let mut drain = deque.drain(start..end);
+                let mut model_drain = model.drain(start..end);
+                for _ in 0..taken {
+                    if rng.coin() {
+                        assert_eq!(drain.next(), model_drain.next());
+                    } else {
+                        assert_eq!(drain.next_back(), model_drain.next_back());
+                    }
+                }
+                assert_eq!(drain.len(), model_drain.len());
+                "drain"
+            }
+            10 => {
+                let at = rng.up_to(len);
+                let other = deque.split_off(at);
+                let model_other = model.split_off(at);
+                assert_same(&other, &model_other, step, "split_off other");
+                if rng.coin() {
+                    // put it back so the deque does not only shrink
+                    let mut other = other;
+                    let mut model_other = model_other;
+                    deque.append(&mut other);
+                    model.append(&mut model_other);
+                    assert!(other.is_empty());
+                }
+                "split_off"
+            }
+            11 => {
+                let mut other: AltDeque<_> = (0..rng.up_to(8) as u32).map(|i| i + next_value).collect();
+                other.push_front(next_value + 100);
+                next_value += 200;
+                let mut model_other: VecDeque<_> = other.iter().copied().collect();
+                deque.append(&mut other);
+                model.append(&mut model_other);
+                "append"
+            }
+            12 => {
+                let new_len = rng.up_to(len);
+                deque.truncate(new_len);
+                model.truncate(new_len);
+                "truncate"
+            }
+            13 => {
+                let modulus = rng.up_to(4) as u32 + 2;
+                deque.retain(|x| x % modulus != 0);
+                model.retain(|x| x % modulus != 0);
+                "retain"
+            }
+            14 => {
+                deque.make_contiguous();
+                "make_contiguous"
+            }
+            _ => {
+                if rng.coin() {
+                    deque.shrink_to_fit();
+                } else {
+                    deque.reserve(rng.up_to(16));
+                }
+                "capacity"
+            }
+        };
+        assert_same(&deque, &model, step, op);
+
+        if !model.is_empty() {
+            let index = rng.up_to(model.len() - 1);
+            assert_eq!(deque.get(index), model.get(index));
+            assert_eq!(deque[index], model[index]);
+        }
+    }
+}
+
+#[test]
+fn test_model_random_ops() {
+    for seed in 1..=64u64 {
+        run(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15), 2_000);
+    }
+}
+
+#[test]
+fn test_model_alternating_pops() {
+    let mut deque: AltDeque<_> = (0..500).collect();
+    let mut model: VecDeque<_> = (0..500).collect();
+    let mut rng = Rng(0xDEAD_BEEF);
+    while !model.is_empty() {
+        if rng.coin() {
+            assert_eq!(deque.pop_front(), model.pop_front());
+        } else {
+            assert_eq!(deque.pop_back(), model.pop_back());
+        }
+        assert_same(&deque, &model, model.len(), "pop");
+    }
+}
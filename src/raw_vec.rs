@@ -4,19 +4,42 @@
 //!
 //! credits to contributors of the [rust project](https://github.com/rust-lang/rust/)
 
-use std::alloc::{self, handle_alloc_error, Layout, LayoutError};
+#[cfg(not(no_global_oom_handling))]
+use std::alloc::handle_alloc_error;
+use std::alloc::{self, Layout, LayoutError};
 use std::boxed::Box;
 use std::cmp;
+use std::error::Error;
+use std::fmt;
 use std::marker::PhantomData;
 use std::mem::{self, ManuallyDrop, MaybeUninit};
-use std::ptr::NonNull;
+use std::ptr::{self, NonNull};
 use std::slice;
 
-pub(crate) enum TryReserveError {
+/// The error type for the fallible allocation methods like [`AltDeque::try_reserve`].
+///
+/// [`AltDeque::try_reserve`]: crate::AltDeque::try_reserve
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// The required capacity exceeds the maximum of `isize::MAX` bytes, or the number of
+    /// elements overflows a `usize`.
     CapacityOverflow,
+    /// The allocator returned an error for the given layout.
     AllocError(Layout),
 }
 
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("memory allocation failed")?;
+        match self {
+            Self::CapacityOverflow => f.write_str(" because the computed capacity exceeded the collection's maximum"),
+            Self::AllocError(_) => f.write_str(" because the memory allocator returned an error"),
+        }
+    }
+}
+
+impl Error for TryReserveError {}
+
 /// A low-level utility for more ergonomically allocating, reallocating, and deallocating
 /// a buffer of memory on the heap without having to worry about all the corner cases
 /// involved. This type is excellent for building your own data structures like Vec and VecDeque.
@@ -46,6 +69,11 @@ pub(crate) struct RawVec<T> {
     _marker: PhantomData<T>,
 }
 
+// SAFETY: RawVec owns its buffer like a Box<[T]> does, so it can be sent and shared between
+// threads exactly when T can be. std's RawVec gets the same impls through Unique.
+unsafe impl<T: Send> Send for RawVec<T> {}
+unsafe impl<T: Sync> Sync for RawVec<T> {}
+
 impl<T> RawVec<T> {
     // Tiny Vecs are dumb. Skip to:
     // - 8 if the element size is 1, because any heap allocators is likely
@@ -84,10 +112,32 @@ impl<T> RawVec<T> {
     /// # Aborts
     ///
     /// Aborts on OOM.
+    #[cfg(not(no_global_oom_handling))]
     #[must_use]
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
-        Self::allocate(capacity)
+        handle_reserve(Self::try_with_capacity(capacity))
+    }
+
+    /// The same as `with_capacity`, but returns on errors instead of panicking or aborting.
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        // Don't allocate here because `Drop` will not deallocate when `capacity` is 0.
+        if mem::size_of::<T>() == 0 || capacity == 0 {
+            return Ok(Self::new());
+        }
+        let layout = Layout::array::<T>(capacity).map_err(|_| TryReserveError::CapacityOverflow)?;
+        alloc_guard(layout.size())?;
+        let ptr = unsafe { alloc::alloc(layout) };
+        let ptr = NonNull::new(ptr as *mut T).ok_or(TryReserveError::AllocError(layout))?;
+
+        // Allocators currently return a `NonNull<[u8]>` whose length
+        // matches the size requested. If that ever changes, the capacity
+        // here should change to `ptr.len() / mem::size_of::<T>()`.
+        Ok(Self {
+            ptr,
+            cap: capacity,
+            _marker: PhantomData,
+        })
     }
 
     /// Converts the entire buffer into `Box<[MaybeUninit<T>]>` with the specified `len`.
@@ -117,39 +167,6 @@ impl<T> RawVec<T> {
         }
     }
 
-    fn allocate(capacity: usize) -> Self {
-        // Don't allocate here because `Drop` will not deallocate when `capacity` is 0.
-        if mem::size_of::<T>() == 0 || capacity == 0 {
-            Self::new()
-        } else {
-            // We avoid `unwrap_or_else` here because it bloats the amount of
-            // LLVM IR generated.
-            let layout = match Layout::array::<T>(capacity) {
-                Ok(layout) => layout,
-                Err(_) => capacity_overflow(),
-            };
-            match alloc_guard(layout.size()) {
-                Ok(_) => {}
-                Err(_) => capacity_overflow(),
-            }
-            let ptr = unsafe { alloc::alloc(layout) };
-            // If allocation fails, `new_ptr` will be null, in which case we abort.
-            let ptr = match NonNull::new(ptr as *mut T) {
-                Some(p) => p,
-                None => alloc::handle_alloc_error(layout),
-            };
-
-            // Allocators currently return a `NonNull<[u8]>` whose length
-            // matches the size requested. If that ever changes, the capacity
-            // here should change to `ptr.len() / mem::size_of::<T>()`.
-            Self {
-                ptr,
-                cap: capacity,
-                _marker: PhantomData,
-            }
-        }
-    }
-
     /// Reconstitutes a `RawVec` from a pointer and capacity.
     ///
     /// # Safety
@@ -172,6 +189,17 @@ impl<T> RawVec<T> {
         self.ptr.as_ptr()
     }
 
+    /// Copies `len` elements from offset `from` to offset `to` in the buffer. The source and
+    /// destination may overlap.
+    ///
+    /// # Safety
+    ///
+    /// Both `from + len` and `to + len` must not exceed the capacity.
+    #[inline]
+    pub unsafe fn copy(&mut self, from: usize, to: usize, len: usize) {
+        unsafe { ptr::copy(self.ptr().add(from), self.ptr().add(to), len) }
+    }
+
     /// Gets the capacity of the allocation.
     ///
     /// This will always be `usize::MAX` if `T` is zero-sized.
@@ -212,6 +240,7 @@ impl<T> RawVec<T> {
     /// # Aborts
     ///
     /// Aborts on OOM.
+    #[cfg(not(no_global_oom_handling))]
     #[inline]
     pub fn reserve(&mut self, len: usize, additional: usize) {
         // Callers expect this function to be very cheap when there is already sufficient capacity.
@@ -234,13 +263,13 @@ impl<T> RawVec<T> {
 
     /// A specialized version of `reserve()` used only by the hot and
     /// oft-instantiated `Vec::push()`, which does its own capacity check.
+    #[cfg(not(no_global_oom_handling))]
     #[inline(never)]
     pub fn reserve_for_push(&mut self, len: usize) {
         handle_reserve(self.grow_amortized(len, 1));
     }
 
     /// The same as `reserve`, but returns on errors instead of panicking or aborting.
-    #[allow(unused)]
     pub fn try_reserve(&mut self, len: usize, additional: usize) -> Result<(), TryReserveError> {
         if self.needs_to_grow(len, additional) {
             self.grow_amortized(len, additional)
@@ -266,6 +295,7 @@ impl<T> RawVec<T> {
     /// # Aborts
     ///
     /// Aborts on OOM.
+    #[cfg(not(no_global_oom_handling))]
     pub fn reserve_exact(&mut self, len: usize, additional: usize) {
         handle_reserve(self.try_reserve_exact(len, additional));
     }
@@ -280,7 +310,8 @@ impl<T> RawVec<T> {
     }
 
     /// Shrinks the buffer down to the specified capacity. If the given amount
-    /// is 0, actually completely deallocates.
+    /// is 0, actually completely deallocates. Unlike when growing, the capacity
+    /// is exactly `cap` afterwards, even if the allocator returned more.
     ///
     /// # Panics
     ///
@@ -289,10 +320,17 @@ impl<T> RawVec<T> {
     /// # Aborts
     ///
     /// Aborts on OOM.
+    #[cfg(not(no_global_oom_handling))]
     pub fn shrink_to_fit(&mut self, cap: usize) {
         handle_reserve(self.shrink(cap));
     }
 
+    fn deallocate(&mut self) {
+        if let Some((ptr, layout)) = self.current_memory() {
+            unsafe { alloc::dealloc(ptr.as_ptr(), layout) }
+        }
+    }
+
     /// Returns if the buffer needs to grow to fulfill the needed extra capacity.
     /// Mainly used to make inlining reserve-calls possible without inlining `grow`.
     fn needs_to_grow(&self, len: usize, additional: usize) -> bool {
@@ -359,11 +397,20 @@ impl<T> RawVec<T> {
         Ok(())
     }
 
+    #[cfg(not(no_global_oom_handling))]
     fn shrink(&mut self, cap: usize) -> Result<(), TryReserveError> {
         assert!(cap <= self.capacity(), "Tried to shrink to a larger capacity");
 
         let (ptr, layout) = if let Some(mem) = self.current_memory() { mem } else { return Ok(()) };
 
+        if cap == 0 {
+            // `realloc` must not be called with a size of zero, drop the allocation instead
+            unsafe { alloc::dealloc(ptr.as_ptr(), layout) };
+            self.ptr = NonNull::dangling();
+            self.cap = 0;
+            return Ok(());
+        }
+
         let ptr = unsafe {
             // `Layout::array` cannot overflow here because it would have
             // overflowed earlier when capacity was larger.
@@ -401,22 +448,31 @@ fn finish_grow(
     NonNull::new(ptr).ok_or(TryReserveError::AllocError(new_layout))
 }
 
+#[cfg(not(feature = "nightly"))]
 impl<T> Drop for RawVec<T> {
     /// Frees the memory owned by the `RawVec` *without* trying to drop its contents.
     fn drop(&mut self) {
-        if let Some((ptr, layout)) = self.current_memory() {
-            unsafe { alloc::dealloc(ptr.as_ptr(), layout) }
-        }
+        self.deallocate();
+    }
+}
+
+// SAFETY: the elements are neither accessed nor dropped here
+#[cfg(feature = "nightly")]
+unsafe impl<#[may_dangle] T> Drop for RawVec<T> {
+    /// Frees the memory owned by the `RawVec` *without* trying to drop its contents.
+    fn drop(&mut self) {
+        self.deallocate();
     }
 }
 
 // Central function for reserve error handling.
+#[cfg(not(no_global_oom_handling))]
 #[inline]
-fn handle_reserve(result: Result<(), TryReserveError>) {
+fn handle_reserve<R>(result: Result<R, TryReserveError>) -> R {
     match result {
         Err(TryReserveError::CapacityOverflow) => capacity_overflow(),
         Err(TryReserveError::AllocError(layout)) => handle_alloc_error(layout),
-        Ok(()) => { /* yay */ }
+        Ok(value) => value,
     }
 }
 
@@ -441,6 +497,7 @@ fn alloc_guard(alloc_size: usize) -> Result<(), TryReserveError> {
 // One central function responsible for reporting capacity overflows. This'll
 // ensure that the code generation related to these panics is minimal as there's
 // only one location which panics rather than a bunch throughout the module.
+#[cfg(not(no_global_oom_handling))]
 fn capacity_overflow() -> ! {
     panic!("capacity overflow");
 }
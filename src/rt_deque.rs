@@ -0,0 +1,841 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut};
+use std::fmt;
+use std::ptr;
+
+use crate::index_out_of_bounds;
+use crate::raw_vec::RawVec;
+
+/// How many elements a rebuild copies into the new buffer per operation.
+const STEP: usize = 4;
+
+/// A growable deque where every operation takes *O(1)* time in the worst case.
+///
+/// [`AltDeque`] pays for a pop on an empty stack by moving half of the other stack over, and
+/// for a push into a full buffer by copying all elements into a bigger one. Both are cheap on
+/// average but make single operations take *O(n)* time. `RtAltDeque` keeps the same two stacks
+/// in one buffer but never does either of them at once:
+///
+/// - a pop on an empty stack takes the element from the bottom of the other stack and leaves
+///   its slot unused, so no elements are moved
+/// - long before the free space between the stacks runs out, a rebuild into a new buffer
+///   starts, which copies a few elements per operation and splits them evenly between the
+///   two stacks. Operations keep working on the old buffer until all elements are copied.
+///
+/// The price is memory: the buffer is kept at about three times the length after a rebuild,
+/// and both buffers are alive while a rebuild runs. This is meant for latency sensitive code
+/// like audio or control loops, where a single slow operation matters more than the average
+/// throughput or the memory use. Allocations can still take as long as the allocator needs.
+///
+/// [`AltDeque`]: crate::AltDeque
+///
+/// # Examples
+///
+/// ```
+/// # use altdeque::RtAltDeque;
+/// let mut deque = RtAltDeque::new();
+/// deque.extend(0..1000);
+/// // no single pop moves the remaining elements over to the empty front stack
+/// assert_eq!(deque.pop_front(), Some(0));
+/// assert_eq!(deque.pop_back(), Some(999));
+/// deque.push_front(-1);
+/// assert_eq!(deque[0], -1);
+/// assert_eq!(deque.len(), 999);
+/// ```
+pub struct RtAltDeque<T> {
+    stacks: Stacks<T>,
+    // the buffer the elements are being copied into, if a rebuild runs
+    rebuild: Option<Rebuild<T>>,
+}
+
+/// A buffer with a back stack in `[back_bottom, head)` that grows upwards and a front stack in
+/// `[tail, front_bottom)` that grows downwards. The slots below `back_bottom` and above
+/// `front_bottom` were freed by pops that ran past the bottom of a stack.
+struct Stacks<T> {
+    buf: RawVec<T>,
+    back_bottom: usize,
+    head: usize,
+    tail: usize,
+    front_bottom: usize,
+}
+
+impl<T> Stacks<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        let buf = RawVec::with_capacity(capacity);
+        let cap = buf.capacity();
+        Self { buf, back_bottom: 0, head: 0, tail: cap, front_bottom: cap }
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.front_bottom - self.tail + self.head - self.back_bottom
+    }
+
+    #[inline]
+    fn free(&self) -> usize {
+        self.tail - self.head
+    }
+
+    /// Returns the slot of the element at `index`, which must be in bounds.
+    #[inline]
+    fn slot(&self, index: usize) -> usize {
+        let front_len = self.front_bottom - self.tail;
+        if index < front_len { self.tail + index } else { self.back_bottom + index - front_len }
+    }
+
+    #[inline]
+    fn ptr(&self, slot: usize) -> *mut T {
+        // SAFETY: slots are always inside the buffer
+        unsafe { self.buf.ptr().add(slot) }
+    }
+
+    /// Reserves the slot before the first element, there must be free space.
+    #[inline]
+    fn push_front_slot(&mut self) -> usize {
+        debug_assert!(self.free() > 0);
+        self.tail -= 1;
+        self.tail
+    }
+
+    /// Reserves the slot after the last element, there must be free space.
+    #[inline]
+    fn push_back_slot(&mut self) -> usize {
+        debug_assert!(self.free() > 0);
+        self.head += 1;
+        self.head - 1
+    }
+
+    /// Releases the slot of the first element, the deque must not be empty.
+    #[inline]
+    fn pop_front_slot(&mut self) -> usize {
+        let slot = if self.tail < self.front_bottom {
+            self.tail += 1;
+            self.tail - 1
+        } else {
+            self.back_bottom += 1;
+            self.back_bottom - 1
+        };
+        self.reset_if_empty();
+        slot
+    }
+
+    /// Releases the slot of the last element, the deque must not be empty.
+    #[inline]
+    fn pop_back_slot(&mut self) -> usize {
+        let slot = if self.head > self.back_bottom {
+            self.head -= 1;
+            self.head
+        } else {
+            self.front_bottom -= 1;
+            self.front_bottom
+        };
+        self.reset_if_empty();
+        slot
+    }
+
+    /// Gives the slots that pops freed at the bottoms back once no element is left.
+    #[inline]
+    fn reset_if_empty(&mut self) {
+        if self.len() == 0 {
+            let cap = self.buf.capacity();
+            (self.back_bottom, self.head, self.tail, self.front_bottom) = (0, 0, cap, cap);
+        }
+    }
+}
+
+/// A rebuild in progress. The elements are split at the position that was the middle when the
+/// rebuild started and are counted outwards from there, so that these offsets do not change
+/// when elements are pushed or popped at the ends.
+struct Rebuild<T> {
+    buf: RawVec<T>,
+    // the elements before the split, offset 0 is the one next to it and ends up in the slot
+    // at the end of the new buffer
+    front: Half,
+    // the elements after the split, offset 0 is the one next to it and ends up in slot 0
+    back: Half,
+}
+
+/// The offsets `[bottom, end)` of the elements on one side of the split. The ones in
+/// `[bottom, copied)` live in the new buffer, the others still in the old one.
+#[derive(Clone, Copy)]
+struct Half {
+    bottom: usize,
+    copied: usize,
+    end: usize,
+}
+
+impl Half {
+    #[inline]
+    fn len(&self) -> usize {
+        self.end - self.bottom
+    }
+
+    #[inline]
+    fn left(&self) -> usize {
+        self.end - self.copied
+    }
+
+    /// Removes the element farthest from the split and returns its offset if it was copied.
+    #[inline]
+    fn pop_outer(&mut self) -> Option<usize> {
+        self.end -= 1;
+        let copied = self.end < self.copied;
+        self.copied = self.copied.min(self.end);
+        copied.then_some(self.end)
+    }
+
+    /// Removes the element next to the split and returns its offset if it was copied.
+    #[inline]
+    fn pop_inner(&mut self) -> Option<usize> {
+        let offset = self.bottom;
+        self.bottom += 1;
+        let copied = offset < self.copied;
+        self.copied = self.copied.max(self.bottom);
+        copied.then_some(offset)
+    }
+}
+
+impl<T> Rebuild<T> {
+    fn new(len: usize) -> Self {
+        let cap = len.checked_mul(3).and_then(|cap| cap.checked_add(4 * STEP));
+        let cap = cap.expect("capacity overflow");
+        let split = len / 2;
+        Self {
+            buf: RawVec::with_capacity(cap),
+            front: Half { bottom: 0, copied: 0, end: split },
+            back: Half { bottom: 0, copied: 0, end: len - split },
+        }
+    }
+
+    #[inline]
+    fn ptr(&self, slot: usize) -> *mut T {
+        // SAFETY: slots are always inside the buffer
+        unsafe { self.buf.ptr().add(slot) }
+    }
+
+    #[inline]
+    fn front_slot(&self, offset: usize) -> usize {
+        self.buf.capacity() - 1 - offset
+    }
+
+    /// Returns the slot in the new buffer of the element at `index` if it was copied already.
+    #[inline]
+    fn slot(&self, index: usize) -> Option<usize> {
+        if index < self.front.len() {
+            let offset = self.front.end - 1 - index;
+            (offset < self.front.copied).then(|| self.front_slot(offset))
+        } else {
+            let offset = self.back.bottom + index - self.front.len();
+            (offset < self.back.copied).then_some(offset)
+        }
+    }
+
+    #[inline]
+    fn pop_front(&mut self) -> Option<usize> {
+        if self.front.len() > 0 {
+            self.front.pop_outer().map(|offset| self.front_slot(offset))
+        } else {
+            self.back.pop_inner()
+        }
+    }
+
+    #[inline]
+    fn pop_back(&mut self) -> Option<usize> {
+        if self.back.len() > 0 {
+            self.back.pop_outer()
+        } else {
+            self.front.pop_inner().map(|offset| self.front_slot(offset))
+        }
+    }
+
+    /// Copies one element out of `old`, from the side with more elements left. Returns `false`
+    /// if all elements are copied.
+    fn copy_one(&mut self, old: &Stacks<T>) -> bool {
+        let (index, slot) = if self.front.left() == 0 && self.back.left() == 0 {
+            return false;
+        } else if self.front.left() >= self.back.left() {
+            let offset = self.front.copied;
+            self.front.copied += 1;
+            (self.front.end - 1 - offset, self.front_slot(offset))
+        } else {
+            let offset = self.back.copied;
+            self.back.copied += 1;
+            (self.front.len() + offset - self.back.bottom, offset)
+        };
+        // SAFETY: the element was not copied yet, so it is still owned by the old buffer. Its
+        // slot there is given up without dropping it.
+        unsafe { ptr::copy_nonoverlapping(old.ptr(old.slot(index)), self.ptr(slot), 1) };
+        true
+    }
+
+    fn is_done(&self) -> bool {
+        self.front.left() == 0 && self.back.left() == 0
+    }
+
+    /// Turns the new buffer into the stacks, all elements must have been copied.
+    fn finish(self) -> Stacks<T> {
+        let cap = self.buf.capacity();
+        debug_assert!(self.front.end + self.back.end <= cap);
+        Stacks {
+            buf: self.buf,
+            back_bottom: self.back.bottom,
+            head: self.back.end,
+            tail: cap - self.front.end,
+            front_bottom: cap - self.front.bottom,
+        }
+    }
+}
+
+impl<T> RtAltDeque<T> {
+    /// Creates an empty deque without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::RtAltDeque;
+    /// let deque: RtAltDeque<i32> = RtAltDeque::new();
+    /// assert!(deque.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self { stacks: Stacks::with_capacity(0), rebuild: None }
+    }
+
+    /// Creates an empty deque that can take `capacity` pushes before the first rebuild starts.
+    ///
+    /// As rebuilds start while there is still free space, the buffer is about half as large
+    /// again as `capacity`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::RtAltDeque;
+    /// let deque: RtAltDeque<i32> = RtAltDeque::with_capacity(10);
+    /// assert!(deque.capacity() >= 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        let cap = if capacity == 0 {
+            0
+        } else {
+            (capacity / 2).checked_add(capacity + STEP).expect("capacity overflow")
+        };
+        Self { stacks: Stacks::with_capacity(cap), rebuild: None }
+    }
+
+    /// Returns the size of the buffer that holds the elements, or of the new buffer while a
+    /// rebuild runs.
+    ///
+    /// This is more than the deque can hold without allocating, because rebuilds start while
+    /// there is still free space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::RtAltDeque;
+    /// let deque: RtAltDeque<i32> = RtAltDeque::with_capacity(10);
+    /// assert!(deque.capacity() >= 10);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        match &self.rebuild {
+            Some(rebuild) => rebuild.buf.capacity(),
+            None => self.stacks.buf.capacity(),
+        }
+    }
+
+    /// Returns the number of elements in the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::RtAltDeque;
+    /// let mut deque = RtAltDeque::new();
+    /// deque.push_back(1);
+    /// assert_eq!(deque.len(), 1);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.stacks.len()
+    }
+
+    /// Returns `true` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::RtAltDeque;
+    /// let mut deque = RtAltDeque::new();
+    /// assert!(deque.is_empty());
+    /// deque.push_back(1);
+    /// assert!(!deque.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Provides a reference to the element at the given index.
+    ///
+    /// Element at index 0 is the front of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::RtAltDeque;
+    /// let deque: RtAltDeque<_> = [1, 2, 3].into_iter().collect();
+    /// assert_eq!(deque.get(1), Some(&2));
+    /// assert_eq!(deque.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        // SAFETY: the index is in bounds
+        (index < self.len()).then(|| unsafe { &*self.ptr_at(index) })
+    }
+
+    /// Provides a mutable reference to the element at the given index.
+    ///
+    /// Element at index 0 is the front of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::RtAltDeque;
+    /// let mut deque: RtAltDeque<_> = [1, 2, 3].into_iter().collect();
+    /// if let Some(el) = deque.get_mut(1) {
+    ///     *el = 7;
+    /// }
+    /// assert_eq!(deque, [1, 7, 3].into_iter().collect());
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        // SAFETY: the index is in bounds
+        (index < self.len()).then(|| unsafe { &mut *self.ptr_at(index) })
+    }
+
+    /// Provides a reference to the front element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::RtAltDeque;
+    /// let mut deque = RtAltDeque::new();
+    /// assert_eq!(deque.front(), None);
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// assert_eq!(deque.front(), Some(&1));
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Provides a reference to the back element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::RtAltDeque;
+    /// let mut deque = RtAltDeque::new();
+    /// assert_eq!(deque.back(), None);
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len().wrapping_sub(1))
+    }
+
+    /// Removes the first element and returns it, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::RtAltDeque;
+    /// let mut deque = RtAltDeque::new();
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// assert_eq!(deque.pop_front(), Some(1));
+    /// assert_eq!(deque.pop_front(), Some(2));
+    /// assert_eq!(deque.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.step();
+        let slot = self.stacks.pop_front_slot();
+        let new_slot = self.rebuild.as_mut().and_then(Rebuild::pop_front);
+        let ptr = match (&self.rebuild, new_slot) {
+            (Some(rebuild), Some(new_slot)) => rebuild.ptr(new_slot),
+            _ => self.stacks.ptr(slot),
+        };
+        // SAFETY: the element was removed from the deque, so it is read exactly once
+        Some(unsafe { ptr::read(ptr) })
+    }
+
+    /// Removes the last element and returns it, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::RtAltDeque;
+    /// let mut deque = RtAltDeque::new();
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// assert_eq!(deque.pop_back(), Some(2));
+    /// assert_eq!(deque.pop_back(), Some(1));
+    /// assert_eq!(deque.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.step();
+        let slot = self.stacks.pop_back_slot();
+        let new_slot = self.rebuild.as_mut().and_then(Rebuild::pop_back);
+        let ptr = match (&self.rebuild, new_slot) {
+            (Some(rebuild), Some(new_slot)) => rebuild.ptr(new_slot),
+            _ => self.stacks.ptr(slot),
+        };
+        // SAFETY: the element was removed from the deque, so it is read exactly once
+        Some(unsafe { ptr::read(ptr) })
+    }
+
+    /// Prepends an element to the deque.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::RtAltDeque;
+    /// let mut deque = RtAltDeque::new();
+    /// deque.push_front(1);
+    /// deque.push_front(2);
+    /// assert_eq!(deque.front(), Some(&2));
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        self.prepare_push();
+        let slot = self.stacks.push_front_slot();
+        if let Some(rebuild) = &mut self.rebuild {
+            rebuild.front.end += 1;
+        }
+        // SAFETY: the slot was free
+        unsafe { ptr::write(self.stacks.ptr(slot), value) };
+    }
+
+    /// Appends an element to the back of the deque.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::RtAltDeque;
+    /// let mut deque = RtAltDeque::new();
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        self.prepare_push();
+        let slot = self.stacks.push_back_slot();
+        if let Some(rebuild) = &mut self.rebuild {
+            rebuild.back.end += 1;
+        }
+        // SAFETY: the slot was free
+        unsafe { ptr::write(self.stacks.ptr(slot), value) };
+    }
+
+    /// Clears the deque, removing all elements.
+    ///
+    /// This takes *O(n)* time to drop the elements, and finishes a running rebuild on the way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::RtAltDeque;
+    /// let mut deque = RtAltDeque::new();
+    /// deque.push_back(1);
+    /// deque.clear();
+    /// assert!(deque.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        while self.pop_back().is_some() {}
+    }
+
+    /// Returns a front-to-back iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::RtAltDeque;
+    /// let mut deque = RtAltDeque::new();
+    /// deque.push_back(2);
+    /// deque.push_front(1);
+    /// assert_eq!(deque.iter().collect::<Vec<_>>(), [&1, &2]);
+    /// ```
+    pub fn iter(&self) -> RtIter<'_, T> {
+        RtIter { deque: self, start: 0, end: self.len() }
+    }
+
+    /// Returns a front-to-back iterator that returns mutable references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::RtAltDeque;
+    /// let mut deque = RtAltDeque::new();
+    /// deque.push_back(2);
+    /// deque.push_front(1);
+    /// deque.iter_mut().for_each(|el| *el *= 10);
+    /// assert_eq!(deque.iter().collect::<Vec<_>>(), [&10, &20]);
+    /// ```
+    pub fn iter_mut(&mut self) -> RtIterMut<'_, T> {
+        RtIterMut { end: self.len(), deque: self, start: 0, marker: PhantomData }
+    }
+
+    /// Returns a pointer to the element at `index`, which must be in bounds.
+    #[inline]
+    fn ptr_at(&self, index: usize) -> *mut T {
+        if let Some(rebuild) = &self.rebuild {
+            if let Some(slot) = rebuild.slot(index) {
+                return rebuild.ptr(slot);
+            }
+        }
+        self.stacks.ptr(self.stacks.slot(index))
+    }
+
+    /// Makes sure that there is a free slot between the stacks, starting a rebuild if the free
+    /// space runs low.
+    fn prepare_push(&mut self) {
+        if self.rebuild.is_none() && self.stacks.free() < self.len() / 2 + STEP {
+            self.rebuild = Some(Rebuild::new(self.len()));
+        }
+        self.step();
+        // A rebuild copies STEP elements per operation while at most one is pushed, so it is
+        // done long before the free space is used up. This only guards the write that follows.
+        while self.stacks.free() == 0 {
+            if self.rebuild.is_none() {
+                self.rebuild = Some(Rebuild::new(self.len()));
+            }
+            self.step();
+        }
+    }
+
+    /// Moves a running rebuild forward by up to `STEP` elements and replaces the old buffer
+    /// once all elements are copied.
+    fn step(&mut self) {
+        let Some(rebuild) = &mut self.rebuild else { return };
+        for _ in 0..STEP {
+            if !rebuild.copy_one(&self.stacks) {
+                break;
+            }
+        }
+        if rebuild.is_done() {
+            let rebuild = self.rebuild.take().unwrap();
+            // the old buffer is freed without dropping anything, all elements were copied
+            self.stacks = rebuild.finish();
+        }
+    }
+}
+
+impl<T> Drop for RtAltDeque<T> {
+    fn drop(&mut self) {
+        self.clear();
+        // RawVec handles deallocation
+    }
+}
+
+impl<T> Default for RtAltDeque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for RtAltDeque<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for RtAltDeque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for RtAltDeque<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for RtAltDeque<T> {}
+
+impl<T> Extend<T> for RtAltDeque<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push_back(value));
+    }
+}
+
+impl<T> FromIterator<T> for RtAltDeque<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut deque = Self::with_capacity(iter.size_hint().0);
+        deque.extend(iter);
+        deque
+    }
+}
+
+impl<T> Index<usize> for RtAltDeque<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).unwrap_or_else(|| index_out_of_bounds(self.len(), index))
+    }
+}
+
+impl<T> IndexMut<usize> for RtAltDeque<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len();
+        self.get_mut(index).unwrap_or_else(|| index_out_of_bounds(len, index))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RtAltDeque<T> {
+    type Item = &'a T;
+    type IntoIter = RtIter<'a, T>;
+
+    fn into_iter(self) -> RtIter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut RtAltDeque<T> {
+    type Item = &'a mut T;
+    type IntoIter = RtIterMut<'a, T>;
+
+    fn into_iter(self) -> RtIterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+/// An iterator over the elements of a [`RtAltDeque`].
+///
+/// This `struct` is created by [`RtAltDeque::iter`].
+pub struct RtIter<'a, T> {
+    deque: &'a RtAltDeque<T>,
+    start: usize,
+    end: usize,
+}
+
+impl<T> Clone for RtIter<'_, T> {
+    fn clone(&self) -> Self {
+        Self { deque: self.deque, start: self.start, end: self.end }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for RtIter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, T> Iterator for RtIter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        if self.start == self.end {
+            return None;
+        }
+        self.start += 1;
+        // SAFETY: the index is in bounds
+        Some(unsafe { &*self.deque.ptr_at(self.start - 1) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for RtIter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        // SAFETY: the index is in bounds
+        Some(unsafe { &*self.deque.ptr_at(self.end) })
+    }
+}
+
+impl<T> ExactSizeIterator for RtIter<'_, T> {}
+
+impl<T> FusedIterator for RtIter<'_, T> {}
+
+/// A mutable iterator over the elements of a [`RtAltDeque`].
+///
+/// This `struct` is created by [`RtAltDeque::iter_mut`].
+pub struct RtIterMut<'a, T> {
+    // the elements live in buffers outside of the deque, so handing out mutable references to
+    // them does not conflict with reading the indices through this shared reference
+    deque: &'a RtAltDeque<T>,
+    start: usize,
+    end: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for RtIterMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let remaining = RtIter { deque: self.deque, start: self.start, end: self.end };
+        f.debug_list().entries(remaining).finish()
+    }
+}
+
+impl<'a, T> Iterator for RtIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.start == self.end {
+            return None;
+        }
+        self.start += 1;
+        // SAFETY: the index is in bounds and every index is handed out only once
+        Some(unsafe { &mut *self.deque.ptr_at(self.start - 1) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for RtIterMut<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        // SAFETY: the index is in bounds and every index is handed out only once
+        Some(unsafe { &mut *self.deque.ptr_at(self.end) })
+    }
+}
+
+impl<T> ExactSizeIterator for RtIterMut<'_, T> {}
+
+impl<T> FusedIterator for RtIterMut<'_, T> {}
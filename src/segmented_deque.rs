@@ -0,0 +1,524 @@
+use core::hash::{Hash, Hasher};
+use core::ops::{Index, IndexMut, Range};
+
+use std::fmt;
+use std::iter::FusedIterator;
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+
+use crate::{index_out_of_bounds, AltDeque, Deque, ExtendFront};
+
+type Block<T> = Box<[MaybeUninit<T>]>;
+
+/// A deque that stores its elements in a chain of fixed-size blocks.
+///
+/// An [`AltDeque`] keeps all elements in one buffer, so growing it copies every element into a
+/// buffer of twice the size, and a pop that finds its stack empty moves half of the elements.
+/// For queues of many gigabytes both are a problem: the old and the new buffer exist at the
+/// same time during the copy, and a single pop can stall for a long time. `SegmentedAltDeque`
+/// allocates blocks of [`BLOCK_LEN`] elements instead, an element never moves once it is
+/// pushed. Every push and pop takes *O(1)* time apart from allocating or freeing a block.
+///
+/// The blocks are kept in an [`AltDeque`] of pointers, which is the only thing that is ever
+/// copied, and which allows indexing in *O(1)* time. The block that became empty last is kept
+/// as a spare, so that pushing and popping around a block boundary does not allocate every
+/// time.
+///
+/// [`BLOCK_LEN`]: SegmentedAltDeque::BLOCK_LEN
+///
+/// # Examples
+///
+/// ```
+/// # use altdeque::SegmentedAltDeque;
+/// let mut deque: SegmentedAltDeque<u64> = (0..10_000).collect();
+/// deque.push_front(42);
+/// assert_eq!(deque.len(), 10_001);
+/// assert_eq!(deque[5000], 4999);
+/// assert_eq!(deque.pop_front(), Some(42));
+/// assert_eq!(deque.pop_back(), Some(9999));
+/// ```
+pub struct SegmentedAltDeque<T> {
+    // all blocks are completely filled except the first one, whose elements start at `head`,
+    // and the last one, an empty deque has no blocks and `head == 0`
+    blocks: AltDeque<Block<T>>,
+    head: usize,
+    len: usize,
+    spare: Option<Block<T>>,
+}
+
+impl<T> SegmentedAltDeque<T> {
+    /// The number of elements in one block. Blocks are about 4 KiB, but hold at least 16
+    /// elements.
+    pub const BLOCK_LEN: usize = {
+        let size = mem::size_of::<T>();
+        if size == 0 || size > 256 {
+            16
+        } else {
+            4096 / size
+        }
+    };
+
+    /// Creates an empty deque. Does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SegmentedAltDeque;
+    /// let deque: SegmentedAltDeque<i32> = SegmentedAltDeque::new();
+    /// assert!(deque.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self { blocks: AltDeque::new(), head: 0, len: 0, spare: None }
+    }
+
+    /// Returns the number of elements in the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SegmentedAltDeque;
+    /// let deque: SegmentedAltDeque<_> = [1, 2].into_iter().collect();
+    /// assert_eq!(deque.len(), 2);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SegmentedAltDeque;
+    /// let mut deque = SegmentedAltDeque::new();
+    /// assert!(deque.is_empty());
+    /// deque.push_back(1);
+    /// assert!(!deque.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of allocated blocks, including the spare one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SegmentedAltDeque;
+    /// let block_len = SegmentedAltDeque::<u8>::BLOCK_LEN;
+    /// let mut deque: SegmentedAltDeque<u8> = (0..block_len).map(|i| i as u8).collect();
+    /// assert_eq!(deque.block_count(), 1);
+    /// deque.push_back(0);
+    /// assert_eq!(deque.block_count(), 2);
+    /// ```
+    pub fn block_count(&self) -> usize {
+        self.blocks.len() + usize::from(self.spare.is_some())
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SegmentedAltDeque;
+    /// let deque: SegmentedAltDeque<_> = [1, 2, 3].into_iter().collect();
+    /// assert_eq!(deque.get(1), Some(&2));
+    /// assert_eq!(deque.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        let (block, offset) = self.locate(index);
+        // SAFETY: the elements at logical indices below len are initialized
+        Some(unsafe { self.blocks[block][offset].assume_init_ref() })
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SegmentedAltDeque;
+    /// let mut deque: SegmentedAltDeque<_> = [1, 2, 3].into_iter().collect();
+    /// *deque.get_mut(1).unwrap() = 7;
+    /// assert_eq!(deque[1], 7);
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        let (block, offset) = self.locate(index);
+        // SAFETY: the elements at logical indices below len are initialized
+        Some(unsafe { self.blocks[block][offset].assume_init_mut() })
+    }
+
+    /// Returns a reference to the front element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SegmentedAltDeque;
+    /// let deque: SegmentedAltDeque<_> = [1, 2].into_iter().collect();
+    /// assert_eq!(deque.front(), Some(&1));
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the back element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SegmentedAltDeque;
+    /// let deque: SegmentedAltDeque<_> = [1, 2].into_iter().collect();
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.wrapping_sub(1))
+    }
+
+    /// Prepends an element to the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SegmentedAltDeque;
+    /// let mut deque = SegmentedAltDeque::new();
+    /// deque.push_front(1);
+    /// deque.push_front(2);
+    /// assert_eq!(deque.front(), Some(&2));
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        if self.head == 0 {
+            let block = self.new_block();
+            self.blocks.push_front(block);
+            self.head = Self::BLOCK_LEN;
+        }
+        self.head -= 1;
+        self.blocks[0][self.head].write(value);
+        self.len += 1;
+    }
+
+    /// Appends an element to the back of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SegmentedAltDeque;
+    /// let mut deque = SegmentedAltDeque::new();
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        if self.head + self.len == self.blocks.len() * Self::BLOCK_LEN {
+            let block = self.new_block();
+            self.blocks.push_back(block);
+        }
+        let (block, offset) = self.locate(self.len);
+        self.blocks[block][offset].write(value);
+        self.len += 1;
+    }
+
+    /// Removes the first element and returns it, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SegmentedAltDeque;
+    /// let mut deque: SegmentedAltDeque<_> = [1, 2].into_iter().collect();
+    /// assert_eq!(deque.pop_front(), Some(1));
+    /// assert_eq!(deque.pop_front(), Some(2));
+    /// assert_eq!(deque.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        // SAFETY: the front element is initialized and no longer counted afterwards
+        let value = unsafe { self.blocks[0][self.head].assume_init_read() };
+        self.head += 1;
+        self.len -= 1;
+        if self.head == Self::BLOCK_LEN || self.len == 0 {
+            self.spare = self.blocks.pop_front();
+            self.head = 0;
+        }
+        Some(value)
+    }
+
+    /// Removes the last element and returns it, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SegmentedAltDeque;
+    /// let mut deque: SegmentedAltDeque<_> = [1, 2].into_iter().collect();
+    /// assert_eq!(deque.pop_back(), Some(2));
+    /// assert_eq!(deque.pop_back(), Some(1));
+    /// assert_eq!(deque.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let (block, offset) = self.locate(self.len);
+        // SAFETY: the back element is initialized and no longer counted
+        let value = unsafe { self.blocks[block][offset].assume_init_read() };
+        if self.len == 0 {
+            self.spare = self.blocks.pop_back();
+            self.head = 0;
+        } else if offset == 0 {
+            self.spare = self.blocks.pop_back();
+        }
+        Some(value)
+    }
+
+    /// Removes all elements. Keeps one block as the spare one and frees the others.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SegmentedAltDeque;
+    /// let mut deque: SegmentedAltDeque<u32> = (0..10_000).collect();
+    /// deque.clear();
+    /// assert!(deque.is_empty());
+    /// assert_eq!(deque.block_count(), 1);
+    /// ```
+    pub fn clear(&mut self) {
+        self.drop_elements();
+        if let Some(block) = self.blocks.pop_front() {
+            self.spare = Some(block);
+        }
+        self.blocks.clear();
+    }
+
+    /// Returns a front-to-back iterator over the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SegmentedAltDeque;
+    /// let deque: SegmentedAltDeque<_> = [1, 2, 3].into_iter().collect();
+    /// assert!(deque.iter().eq(&[1, 2, 3]));
+    /// ```
+    pub fn iter(&self) -> SegmentedIter<'_, T> {
+        SegmentedIter { deque: self, range: 0..self.len }
+    }
+
+    /// Returns the block and the offset in it of the element at logical `index`.
+    #[inline]
+    fn locate(&self, index: usize) -> (usize, usize) {
+        let index = self.head + index;
+        (index / Self::BLOCK_LEN, index % Self::BLOCK_LEN)
+    }
+
+    fn new_block(&mut self) -> Block<T> {
+        self.spare.take().unwrap_or_else(|| Box::new_uninit_slice(Self::BLOCK_LEN))
+    }
+
+    /// Drops all elements and sets the length to zero, the blocks stay allocated. If a
+    /// destructor panics, the remaining elements are leaked.
+    fn drop_elements(&mut self) {
+        let mut start = mem::take(&mut self.head);
+        let mut remaining = mem::take(&mut self.len);
+        for block in self.blocks.iter_mut() {
+            let count = remaining.min(Self::BLOCK_LEN - start);
+            let elements = &mut block[start..start + count] as *mut [MaybeUninit<T>] as *mut [T];
+            // SAFETY: the elements are initialized and the length is already zero
+            unsafe { ptr::drop_in_place(elements) };
+            remaining -= count;
+            start = 0;
+        }
+    }
+}
+
+impl<T: Clone> Clone for SegmentedAltDeque<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SegmentedAltDeque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Default for SegmentedAltDeque<T> {
+    /// Creates an empty deque.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deque<T> for SegmentedAltDeque<T> {
+    type Iter<'a>
+        = SegmentedIter<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        SegmentedAltDeque::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        SegmentedAltDeque::get(self, index)
+    }
+
+    fn push_front(&mut self, value: T) {
+        SegmentedAltDeque::push_front(self, value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        SegmentedAltDeque::push_back(self, value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        SegmentedAltDeque::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        SegmentedAltDeque::pop_back(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        SegmentedAltDeque::iter(self)
+    }
+}
+
+impl<T> Drop for SegmentedAltDeque<T> {
+    fn drop(&mut self) {
+        self.drop_elements();
+    }
+}
+
+impl<T> Extend<T> for SegmentedAltDeque<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|elem| self.push_back(elem));
+    }
+}
+
+impl<T> ExtendFront<T> for SegmentedAltDeque<T> {
+    fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|elem| self.push_front(elem));
+    }
+}
+
+impl<T> FromIterator<T> for SegmentedAltDeque<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut deque = Self::new();
+        deque.extend(iter);
+        deque
+    }
+}
+
+impl<T: Hash> Hash for SegmentedAltDeque<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        self.iter().for_each(|elem| elem.hash(state));
+    }
+}
+
+impl<T> Index<usize> for SegmentedAltDeque<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).unwrap_or_else(|| index_out_of_bounds(self.len, index))
+    }
+}
+
+impl<T> IndexMut<usize> for SegmentedAltDeque<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+        self.get_mut(index).unwrap_or_else(|| index_out_of_bounds(len, index))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SegmentedAltDeque<T> {
+    type Item = &'a T;
+    type IntoIter = SegmentedIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: PartialEq> PartialEq for SegmentedAltDeque<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for SegmentedAltDeque<T> {}
+
+impl<T: PartialEq<U>, U> PartialEq<[U]> for SegmentedAltDeque<T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.len == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for SegmentedAltDeque<T> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+/// A front-to-back iterator over the elements of a [`SegmentedAltDeque`].
+///
+/// This struct is created by the [`iter`] method on [`SegmentedAltDeque`].
+///
+/// [`iter`]: SegmentedAltDeque::iter
+pub struct SegmentedIter<'a, T> {
+    deque: &'a SegmentedAltDeque<T>,
+    range: Range<usize>,
+}
+
+impl<T> Clone for SegmentedIter<'_, T> {
+    fn clone(&self) -> Self {
+        Self { deque: self.deque, range: self.range.clone() }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SegmentedIter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, T> Iterator for SegmentedIter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().and_then(|index| self.deque.get(index))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.range.nth(n).and_then(|index| self.deque.get(index))
+    }
+}
+
+impl<T> DoubleEndedIterator for SegmentedIter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().and_then(|index| self.deque.get(index))
+    }
+}
+
+impl<T> ExactSizeIterator for SegmentedIter<'_, T> {}
+
+impl<T> FusedIterator for SegmentedIter<'_, T> {}
@@ -0,0 +1,128 @@
+use std::iter::FusedIterator;
+#[cfg(feature = "nightly")]
+use std::iter::TrustedLen;
+use std::mem;
+
+/// An iterator over the non-empty contiguous slices of an `AltDeque`, in order.
+///
+/// These are the two slices returned by [`as_slices`] without the empty ones, so there are at
+/// most two of them.
+///
+/// This struct is created by the [`segments`] method on [`AltDeque`].
+///
+/// [`AltDeque`]: crate::AltDeque
+/// [`as_slices`]: crate::AltDeque::as_slices
+/// [`segments`]: crate::AltDeque::segments
+#[derive(Clone, Debug)]
+pub struct Segments<'a, T> {
+    // if front is empty, back is empty too
+    front: &'a [T],
+    back: &'a [T],
+}
+
+impl<'a, T> Segments<'a, T> {
+    pub(crate) fn new(front: &'a [T], back: &'a [T]) -> Self {
+        if front.is_empty() {
+            Self { front: back, back: &[] }
+        } else {
+            Self { front, back }
+        }
+    }
+}
+
+impl<'a, T> Iterator for Segments<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front.is_empty() {
+            return None;
+        }
+        let front = mem::replace(&mut self.front, mem::take(&mut self.back));
+        Some(front)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::from(!self.front.is_empty()) + usize::from(!self.back.is_empty());
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Segments<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if !self.back.is_empty() {
+            Some(mem::take(&mut self.back))
+        } else if !self.front.is_empty() {
+            Some(mem::take(&mut self.front))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Segments<'_, T> {}
+
+impl<T> FusedIterator for Segments<'_, T> {}
+
+// SAFETY: size_hint returns the exact number of remaining items
+#[cfg(feature = "nightly")]
+unsafe impl<T> TrustedLen for Segments<'_, T> {}
+
+/// An iterator over the non-empty contiguous mutable slices of an `AltDeque`, in order.
+///
+/// This struct is created by the [`segments_mut`] method on [`AltDeque`].
+///
+/// [`AltDeque`]: crate::AltDeque
+/// [`segments_mut`]: crate::AltDeque::segments_mut
+#[derive(Debug)]
+pub struct SegmentsMut<'a, T> {
+    // if front is empty, back is empty too
+    front: &'a mut [T],
+    back: &'a mut [T],
+}
+
+impl<'a, T> SegmentsMut<'a, T> {
+    pub(crate) fn new(front: &'a mut [T], back: &'a mut [T]) -> Self {
+        if front.is_empty() {
+            Self { front: back, back: &mut [] }
+        } else {
+            Self { front, back }
+        }
+    }
+}
+
+impl<'a, T> Iterator for SegmentsMut<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front.is_empty() {
+            return None;
+        }
+        let front = mem::replace(&mut self.front, mem::take(&mut self.back));
+        Some(front)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::from(!self.front.is_empty()) + usize::from(!self.back.is_empty());
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for SegmentsMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if !self.back.is_empty() {
+            Some(mem::take(&mut self.back))
+        } else if !self.front.is_empty() {
+            Some(mem::take(&mut self.front))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for SegmentsMut<'_, T> {}
+
+impl<T> FusedIterator for SegmentsMut<'_, T> {}
+
+// SAFETY: size_hint returns the exact number of remaining items
+#[cfg(feature = "nightly")]
+unsafe impl<T> TrustedLen for SegmentsMut<'_, T> {}
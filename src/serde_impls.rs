@@ -0,0 +1,97 @@
+//! `Serialize` and `Deserialize` for `AltDeque`, as a sequence like `Vec` and `VecDeque`.
+//!
+//! The length that a deserializer announces comes from the input, so it is only trusted up to
+//! [`MAX_PREALLOC_BYTES`] when allocating the deque up front. Longer sequences grow the deque
+//! as usual while their elements arrive.
+
+use core::fmt;
+use core::marker::PhantomData;
+use std::cmp;
+use std::mem;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+use crate::AltDeque;
+
+/// The most memory that is allocated before the elements have actually been read, the same
+/// limit that serde uses for `Vec`.
+const MAX_PREALLOC_BYTES: usize = 1024 * 1024;
+
+impl<T: Serialize> Serialize for AltDeque<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for element in self {
+            seq.serialize_element(element)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for AltDeque<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(DequeVisitor { max_len: usize::MAX, marker: PhantomData })
+    }
+}
+
+impl<T> AltDeque<T> {
+    /// Deserializes a deque like its `Deserialize` impl does, but fails with an
+    /// [`invalid_length`] error as soon as the input holds more than `max_len` elements.
+    ///
+    /// Use this for untrusted input, which could otherwise make the deque grow as large as the
+    /// input allows. At most `max_len` elements, and never more than 1 MiB, are allocated
+    /// before the elements have been read, no matter which length the input announces.
+    ///
+    /// [`invalid_length`]: serde::de::Error::invalid_length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::AltDeque;
+    /// use serde::de::value::{Error, SeqDeserializer};
+    ///
+    /// let input = SeqDeserializer::<_, Error>::new(vec![1, 2, 3].into_iter());
+    /// let deque = AltDeque::<i32>::deserialize_bounded(input, 3).unwrap();
+    /// assert_eq!(deque, [1, 2, 3]);
+    ///
+    /// let input = SeqDeserializer::<_, Error>::new(vec![1, 2, 3].into_iter());
+    /// assert!(AltDeque::<i32>::deserialize_bounded(input, 2).is_err());
+    /// ```
+    pub fn deserialize_bounded<'de, D>(deserializer: D, max_len: usize) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        deserializer.deserialize_seq(DequeVisitor { max_len, marker: PhantomData })
+    }
+}
+
+struct DequeVisitor<T> {
+    max_len: usize,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for DequeVisitor<T> {
+    type Value = AltDeque<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.max_len == usize::MAX {
+            f.write_str("a sequence")
+        } else {
+            write!(f, "a sequence of at most {} elements", self.max_len)
+        }
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let hint = seq.size_hint().unwrap_or(0);
+        let max_prealloc = MAX_PREALLOC_BYTES / cmp::max(mem::size_of::<T>(), 1);
+        let mut deque = AltDeque::with_capacity(cmp::min(hint, cmp::min(max_prealloc, self.max_len)));
+        while let Some(element) = seq.next_element()? {
+            if deque.len() == self.max_len {
+                return Err(de::Error::invalid_length(self.max_len.saturating_add(1), &self));
+            }
+            deque.push_back(element);
+        }
+        Ok(deque)
+    }
+}
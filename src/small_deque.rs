@@ -0,0 +1,686 @@
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::{Index, IndexMut};
+
+use std::fmt;
+use std::mem;
+
+use crate::{index_out_of_bounds, AltDeque, ArrayAltDeque, Deque, ExtendFront, Iter, IterMut};
+
+/// A deque that stores up to `N` elements inline and moves them to the heap once it grows
+/// beyond that.
+///
+/// While the deque is inline it is backed by an [`ArrayAltDeque`] and does not allocate.
+/// When a push would overflow the inline buffer, all elements are moved into an [`AltDeque`]
+/// and the deque stays on the heap from then on, unless [`shrink_to_fit`] moves it back.
+///
+/// [`shrink_to_fit`]: SmallAltDeque::shrink_to_fit
+///
+/// # Examples
+///
+/// ```
+/// # use altdeque::SmallAltDeque;
+/// let mut deque: SmallAltDeque<i32, 2> = SmallAltDeque::new();
+/// deque.push_back(1);
+/// deque.push_front(0);
+/// assert!(!deque.spilled());
+/// deque.push_back(2);
+/// assert!(deque.spilled());
+/// assert_eq!(deque, [0, 1, 2]);
+/// ```
+pub struct SmallAltDeque<T, const N: usize> {
+    repr: Repr<T, N>,
+}
+
+enum Repr<T, const N: usize> {
+    Inline(ArrayAltDeque<T, N>),
+    Heap(AltDeque<T>),
+}
+
+impl<T, const N: usize> SmallAltDeque<T, N> {
+    /// Creates an empty deque that stores its elements inline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let deque: SmallAltDeque<i32, 8> = SmallAltDeque::new();
+    /// assert!(deque.is_empty());
+    /// assert!(!deque.spilled());
+    /// ```
+    pub const fn new() -> Self {
+        Self { repr: Repr::Inline(ArrayAltDeque::new()) }
+    }
+
+    /// Creates an empty deque with space for at least `capacity` elements.
+    /// Allocates only if `capacity` is greater than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let deque: SmallAltDeque<i32, 8> = SmallAltDeque::with_capacity(4);
+    /// assert!(!deque.spilled());
+    /// let deque: SmallAltDeque<i32, 8> = SmallAltDeque::with_capacity(10);
+    /// assert!(deque.spilled());
+    /// assert!(deque.capacity() >= 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        if capacity <= N {
+            Self::new()
+        } else {
+            Self { repr: Repr::Heap(AltDeque::with_capacity(capacity)) }
+        }
+    }
+
+    /// Returns `true` if the elements have been moved to the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let mut deque: SmallAltDeque<i32, 1> = SmallAltDeque::new();
+    /// deque.push_back(1);
+    /// assert!(!deque.spilled());
+    /// deque.push_back(2);
+    /// assert!(deque.spilled());
+    /// ```
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        matches!(self.repr, Repr::Heap(_))
+    }
+
+    /// Returns the number of elements the deque can hold without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let deque: SmallAltDeque<i32, 8> = SmallAltDeque::new();
+    /// assert_eq!(deque.capacity(), 8);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        match &self.repr {
+            Repr::Inline(deque) => deque.capacity(),
+            Repr::Heap(deque) => deque.capacity(),
+        }
+    }
+
+    /// Returns the number of elements in the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let mut deque: SmallAltDeque<i32, 8> = SmallAltDeque::new();
+    /// deque.push_back(1);
+    /// assert_eq!(deque.len(), 1);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        match &self.repr {
+            Repr::Inline(deque) => deque.len(),
+            Repr::Heap(deque) => deque.len(),
+        }
+    }
+
+    /// Returns `true` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let mut deque: SmallAltDeque<i32, 8> = SmallAltDeque::new();
+    /// assert!(deque.is_empty());
+    /// deque.push_back(1);
+    /// assert!(!deque.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a pair of slices which contain, in order, the contents of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let mut deque: SmallAltDeque<i32, 8> = SmallAltDeque::new();
+    /// deque.push_back(2);
+    /// deque.push_front(1);
+    /// assert_eq!(deque.as_slices(), (&[1][..], &[2][..]));
+    /// ```
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        match &self.repr {
+            Repr::Inline(deque) => deque.as_slices(),
+            Repr::Heap(deque) => deque.as_slices(),
+        }
+    }
+
+    /// Returns a pair of mutable slices which contain, in order, the contents of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let mut deque: SmallAltDeque<i32, 8> = SmallAltDeque::new();
+    /// deque.push_back(2);
+    /// deque.push_front(1);
+    /// deque.as_mut_slices().0[0] = 0;
+    /// assert_eq!(deque, [0, 2]);
+    /// ```
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        match &mut self.repr {
+            Repr::Inline(deque) => deque.as_mut_slices(),
+            Repr::Heap(deque) => deque.as_mut_slices(),
+        }
+    }
+
+    /// Provides a reference to the element at the given index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let deque: SmallAltDeque<_, 8> = [1, 2, 3].into_iter().collect();
+    /// assert_eq!(deque.get(1), Some(&2));
+    /// assert_eq!(deque.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match &self.repr {
+            Repr::Inline(deque) => deque.get(index),
+            Repr::Heap(deque) => deque.get(index),
+        }
+    }
+
+    /// Provides a mutable reference to the element at the given index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let mut deque: SmallAltDeque<_, 8> = [1, 2, 3].into_iter().collect();
+    /// *deque.get_mut(1).unwrap() = 4;
+    /// assert_eq!(deque, [1, 4, 3]);
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match &mut self.repr {
+            Repr::Inline(deque) => deque.get_mut(index),
+            Repr::Heap(deque) => deque.get_mut(index),
+        }
+    }
+
+    /// Provides a reference to the front element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let deque: SmallAltDeque<_, 8> = [1, 2].into_iter().collect();
+    /// assert_eq!(deque.front(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Provides a mutable reference to the front element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let mut deque: SmallAltDeque<_, 8> = [1, 2].into_iter().collect();
+    /// *deque.front_mut().unwrap() = 0;
+    /// assert_eq!(deque, [0, 2]);
+    /// ```
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Provides a reference to the back element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let deque: SmallAltDeque<_, 8> = [1, 2].into_iter().collect();
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len().wrapping_sub(1))
+    }
+
+    /// Provides a mutable reference to the back element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let mut deque: SmallAltDeque<_, 8> = [1, 2].into_iter().collect();
+    /// *deque.back_mut().unwrap() = 3;
+    /// assert_eq!(deque, [1, 3]);
+    /// ```
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len().wrapping_sub(1))
+    }
+
+    /// Removes the first element and returns it, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let mut deque: SmallAltDeque<_, 8> = [1, 2].into_iter().collect();
+    /// assert_eq!(deque.pop_front(), Some(1));
+    /// assert_eq!(deque.pop_front(), Some(2));
+    /// assert_eq!(deque.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        match &mut self.repr {
+            Repr::Inline(deque) => deque.pop_front(),
+            Repr::Heap(deque) => deque.pop_front(),
+        }
+    }
+
+    /// Removes the last element and returns it, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let mut deque: SmallAltDeque<_, 8> = [1, 2].into_iter().collect();
+    /// assert_eq!(deque.pop_back(), Some(2));
+    /// assert_eq!(deque.pop_back(), Some(1));
+    /// assert_eq!(deque.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        match &mut self.repr {
+            Repr::Inline(deque) => deque.pop_back(),
+            Repr::Heap(deque) => deque.pop_back(),
+        }
+    }
+
+    /// Prepends an element to the deque, moving the deque to the heap if the inline buffer
+    /// is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let mut deque: SmallAltDeque<_, 1> = SmallAltDeque::new();
+    /// deque.push_front(2);
+    /// deque.push_front(1);
+    /// assert_eq!(deque, [1, 2]);
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        match &mut self.repr {
+            Repr::Inline(deque) => {
+                if let Err(value) = deque.push_front(value) {
+                    self.spill().push_front(value);
+                }
+            }
+            Repr::Heap(deque) => deque.push_front(value),
+        }
+    }
+
+    /// Appends an element to the back of the deque, moving the deque to the heap if the
+    /// inline buffer is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let mut deque: SmallAltDeque<_, 1> = SmallAltDeque::new();
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// assert_eq!(deque, [1, 2]);
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        match &mut self.repr {
+            Repr::Inline(deque) => {
+                if let Err(value) = deque.push_back(value) {
+                    self.spill().push_back(value);
+                }
+            }
+            Repr::Heap(deque) => deque.push_back(value),
+        }
+    }
+
+    /// Shortens the deque, keeping the first `len` elements and dropping the rest.
+    /// If `len` is greater than the deque's current length, this has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let mut deque: SmallAltDeque<_, 8> = [1, 2, 3].into_iter().collect();
+    /// deque.truncate(1);
+    /// assert_eq!(deque, [1]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        match &mut self.repr {
+            Repr::Inline(deque) => deque.truncate(len),
+            Repr::Heap(deque) => deque.truncate(len),
+        }
+    }
+
+    /// Removes all elements from the deque. A spilled deque keeps its heap allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let mut deque: SmallAltDeque<_, 8> = [1, 2, 3].into_iter().collect();
+    /// deque.clear();
+    /// assert!(deque.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Moves the elements back into the inline buffer if they fit, freeing the heap
+    /// allocation. Otherwise shrinks the heap buffer as much as possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let mut deque: SmallAltDeque<_, 2> = [1, 2, 3].into_iter().collect();
+    /// assert!(deque.spilled());
+    /// deque.pop_back();
+    /// deque.shrink_to_fit();
+    /// assert!(!deque.spilled());
+    /// assert_eq!(deque, [1, 2]);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        if let Repr::Heap(deque) = &mut self.repr {
+            if deque.len() <= N {
+                let mut inline = ArrayAltDeque::new();
+                while let Some(value) = deque.pop_front() {
+                    // cannot fail as we checked the length
+                    let _ = inline.push_back(value);
+                }
+                self.repr = Repr::Inline(inline);
+            } else {
+                deque.shrink_to_fit();
+            }
+        }
+    }
+
+    /// Reverses the order of the elements in the deque, in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let mut deque: SmallAltDeque<_, 8> = [1, 2, 3].into_iter().collect();
+    /// deque.reverse();
+    /// assert_eq!(deque, [3, 2, 1]);
+    /// ```
+    pub fn reverse(&mut self) {
+        match &mut self.repr {
+            Repr::Inline(deque) => deque.reverse(),
+            Repr::Heap(deque) => deque.reverse(),
+        }
+    }
+
+    /// Returns a front-to-back iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let deque: SmallAltDeque<_, 8> = [1, 2].into_iter().collect();
+    /// assert_eq!(deque.iter().collect::<Vec<_>>(), vec![&1, &2]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        match &self.repr {
+            Repr::Inline(deque) => deque.iter(),
+            Repr::Heap(deque) => deque.iter(),
+        }
+    }
+
+    /// Returns a front-to-back iterator that returns mutable references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::SmallAltDeque;
+    /// let mut deque: SmallAltDeque<_, 8> = [1, 2].into_iter().collect();
+    /// deque.iter_mut().for_each(|x| *x *= 2);
+    /// assert_eq!(deque, [2, 4]);
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        match &mut self.repr {
+            Repr::Inline(deque) => deque.iter_mut(),
+            Repr::Heap(deque) => deque.iter_mut(),
+        }
+    }
+
+    /// Moves all elements from the inline buffer to a new heap allocation with twice the
+    /// inline capacity and returns a reference to it.
+    #[cold]
+    fn spill(&mut self) -> &mut AltDeque<T> {
+        if let Repr::Inline(inline) = &mut self.repr {
+            let mut heap = AltDeque::with_capacity(N.saturating_mul(2));
+            while let Some(value) = inline.pop_front() {
+                heap.push_back(value);
+            }
+            self.repr = Repr::Heap(heap);
+        }
+        match &mut self.repr {
+            Repr::Heap(deque) => deque,
+            Repr::Inline(_) => unreachable!(),
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for SmallAltDeque<T, N> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for SmallAltDeque<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T, const N: usize> Default for SmallAltDeque<T, N> {
+    /// Creates an empty deque.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Deque<T> for SmallAltDeque<T, N> {
+    type Iter<'a>
+        = Iter<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        SmallAltDeque::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        SmallAltDeque::get(self, index)
+    }
+
+    fn push_front(&mut self, value: T) {
+        SmallAltDeque::push_front(self, value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        SmallAltDeque::push_back(self, value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        SmallAltDeque::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        SmallAltDeque::pop_back(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        SmallAltDeque::iter(self)
+    }
+}
+
+impl<T, const N: usize> Extend<T> for SmallAltDeque<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        if self.len().saturating_add(lower) > N {
+            self.spill().reserve(lower);
+        }
+        iter.for_each(|el| self.push_back(el));
+    }
+}
+
+impl<'a, T: 'a + Copy, const N: usize> Extend<&'a T> for SmallAltDeque<T, N> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T, const N: usize> ExtendFront<T> for SmallAltDeque<T, N> {
+    fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        if self.len().saturating_add(lower) > N {
+            self.spill().reserve(lower);
+        }
+        iter.for_each(|el| self.push_front(el));
+    }
+}
+
+impl<'a, T: 'a + Copy, const N: usize> ExtendFront<&'a T> for SmallAltDeque<T, N> {
+    fn extend_front<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend_front(iter.into_iter().copied());
+    }
+}
+
+impl<T, const N: usize> From<AltDeque<T>> for SmallAltDeque<T, N> {
+    /// Turns an [`AltDeque`] into a spilled `SmallAltDeque` without moving the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{AltDeque, SmallAltDeque};
+    /// let deque: SmallAltDeque<_, 8> = AltDeque::from([1, 2]).into();
+    /// assert!(deque.spilled());
+    /// assert_eq!(deque, [1, 2]);
+    /// ```
+    fn from(deque: AltDeque<T>) -> Self {
+        Self { repr: Repr::Heap(deque) }
+    }
+}
+
+impl<T, const N: usize> From<SmallAltDeque<T, N>> for AltDeque<T> {
+    /// Turns a `SmallAltDeque` into an [`AltDeque`]. This allocates only if the deque has
+    /// not spilled yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::{AltDeque, SmallAltDeque};
+    /// let deque: SmallAltDeque<_, 8> = [1, 2].into_iter().collect();
+    /// assert_eq!(AltDeque::from(deque), [1, 2]);
+    /// ```
+    fn from(mut deque: SmallAltDeque<T, N>) -> Self {
+        mem::take(deque.spill())
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for SmallAltDeque<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut deque = Self::new();
+        deque.extend(iter);
+        deque
+    }
+}
+
+impl<T: Hash, const N: usize> Hash for SmallAltDeque<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        self.iter().for_each(|elem| elem.hash(state));
+    }
+}
+
+impl<T, const N: usize> Index<usize> for SmallAltDeque<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).unwrap_or_else(|| index_out_of_bounds(self.len(), index))
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for SmallAltDeque<T, N> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len();
+        self.get_mut(index).unwrap_or_else(|| index_out_of_bounds(len, index))
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a SmallAltDeque<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut SmallAltDeque<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for SmallAltDeque<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for SmallAltDeque<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for SmallAltDeque<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for SmallAltDeque<T, N> {}
+
+__impl_slice_eq! { [const N: usize] SmallAltDeque<T, N>, Vec<U>, }
+__impl_slice_eq! { [const N: usize] SmallAltDeque<T, N>, &[U], }
+__impl_slice_eq! { [const N: usize] SmallAltDeque<T, N>, &mut [U], }
+__impl_slice_eq! { [const N: usize, const M: usize] SmallAltDeque<T, N>, [U; M], }
+__impl_slice_eq! { [const N: usize, const M: usize] SmallAltDeque<T, N>, &[U; M], }
+__impl_slice_eq! { [const N: usize, const M: usize] SmallAltDeque<T, N>, &mut [U; M], }
+__impl_slice_ord! { [const N: usize] SmallAltDeque<T, N>, Vec<U>, }
+__impl_slice_ord! { [const N: usize] SmallAltDeque<T, N>, &[U], }
+__impl_slice_ord! { [const N: usize, const M: usize] SmallAltDeque<T, N>, [U; M], }
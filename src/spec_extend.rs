@@ -0,0 +1,58 @@
+//! Specialized `Extend` implementations, only compiled with the `nightly` feature.
+
+use std::iter::TrustedLen;
+use std::ptr;
+use std::slice;
+
+use crate::{AltDeque, IntoIter};
+
+/// Extends a deque with the fastest strategy for the iterator type `I`.
+pub(crate) trait SpecExtend<T, I> {
+    fn spec_extend(&mut self, iter: I);
+}
+
+impl<T, I: Iterator<Item = T>> SpecExtend<T, I> for AltDeque<T> {
+    default fn spec_extend(&mut self, iter: I) {
+        self.extend_desugared(iter);
+    }
+}
+
+impl<T, I: TrustedLen<Item = T>> SpecExtend<T, I> for AltDeque<T> {
+    default fn spec_extend(&mut self, iter: I) {
+        let additional = match iter.size_hint() {
+            (_, Some(upper)) => upper,
+            // a trusted length without an upper bound means more than usize::MAX elements
+            (_, None) => panic!("capacity overflow"),
+        };
+        self.reserve(additional);
+        let ptr = self.buf.ptr();
+        let head = &mut self.head;
+        iter.for_each(|element| {
+            // SAFETY: TrustedLen guarantees that there are exactly `additional` elements, for
+            // which we reserved space, so head < tail
+            unsafe { ptr::write(ptr.add(*head), element) };
+            *head += 1;
+        });
+        record!(self, peak_len);
+        check_invariants!(self);
+    }
+}
+
+impl<T> SpecExtend<T, IntoIter<T>> for AltDeque<T> {
+    fn spec_extend(&mut self, mut iter: IntoIter<T>) {
+        // the elements that are left are a deque of their own, whose stacks are moved in bulk
+        self.append(iter.as_deque_mut());
+    }
+}
+
+impl<'a, T: 'a + Copy, I: Iterator<Item = &'a T>> SpecExtend<&'a T, I> for AltDeque<T> {
+    default fn spec_extend(&mut self, iter: I) {
+        self.spec_extend(iter.copied());
+    }
+}
+
+impl<'a, T: 'a + Copy> SpecExtend<&'a T, slice::Iter<'a, T>> for AltDeque<T> {
+    fn spec_extend(&mut self, iter: slice::Iter<'a, T>) {
+        self.extend_from_slice_copy(iter.as_slice());
+    }
+}
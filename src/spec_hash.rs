@@ -0,0 +1,53 @@
+//! Specialized `Hash` implementations, only compiled with the `nightly` feature.
+
+use std::hash::{Hash, Hasher};
+use std::slice;
+
+use crate::{AltDeque, Chunks};
+
+/// The number of bytes that are hashed with one call to `Hasher::write`.
+const CHUNK_LEN: usize = 256;
+
+/// Hashes a deque with the fastest strategy for its element type.
+pub(crate) trait SpecHash {
+    fn spec_hash<H: Hasher>(&self, state: &mut H);
+}
+
+impl<T: Hash> SpecHash for AltDeque<T> {
+    default fn spec_hash<H: Hasher>(&self, state: &mut H) {
+        self.hash_elements(state);
+    }
+}
+
+impl SpecHash for AltDeque<u8> {
+    fn spec_hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        hash_bytes(self.as_slices(), state);
+    }
+}
+
+impl SpecHash for AltDeque<i8> {
+    fn spec_hash<H: Hasher>(&self, state: &mut H) {
+        // SAFETY: i8 and u8 have the same size and alignment and every bit pattern is valid
+        let cast = |s: &[i8]| unsafe { slice::from_raw_parts(s.as_ptr() as *const u8, s.len()) };
+        let (front, back) = self.as_slices();
+        state.write_usize(self.len());
+        hash_bytes((cast(front), cast(back)), state);
+    }
+}
+
+/// Writes the bytes in chunks of `CHUNK_LEN`. A `Hasher` only guarantees the same result for the
+/// same sequence of calls, so the chunks must not depend on where the deque is split into its
+/// two slices. The one chunk that spans both slices is copied into a buffer.
+fn hash_bytes<H: Hasher>((front, back): (&[u8], &[u8]), state: &mut H) {
+    let mut buf = [0; CHUNK_LEN];
+    for (a, b) in Chunks::new(front, back, CHUNK_LEN) {
+        if b.is_empty() {
+            state.write(a);
+        } else {
+            buf[..a.len()].copy_from_slice(a);
+            buf[a.len()..a.len() + b.len()].copy_from_slice(b);
+            state.write(&buf[..a.len() + b.len()]);
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use std::fmt;
+use std::iter::FusedIterator;
+
+use crate::chunks::take_front;
+
+/// An iterator over the parts of an `AltDeque` that are separated by elements matching a
+/// predicate.
+///
+/// Every part is returned as a pair of slices, like [`as_slices`] returns the whole deque. Only
+/// a part that spans the boundary between the two internal stacks uses the second slice.
+///
+/// This struct is created by the [`split`] method on [`AltDeque`].
+///
+/// [`AltDeque`]: crate::AltDeque
+/// [`as_slices`]: crate::AltDeque::as_slices
+/// [`split`]: crate::AltDeque::split
+pub struct Split<'a, T, P> {
+    front: &'a [T],
+    back: &'a [T],
+    pred: P,
+    finished: bool,
+}
+
+impl<'a, T, P: FnMut(&T) -> bool> Split<'a, T, P> {
+    pub(crate) fn new(front: &'a [T], back: &'a [T], pred: P) -> Self {
+        Self { front, back, pred, finished: false }
+    }
+}
+
+impl<'a, T, P: FnMut(&T) -> bool> Iterator for Split<'a, T, P> {
+    type Item = (&'a [T], &'a [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match position(self.front, self.back, &mut self.pred) {
+            Some(idx) => {
+                let part = take_front(&mut self.front, &mut self.back, idx);
+                // skip the separator
+                take_front(&mut self.front, &mut self.back, 1);
+                Some(part)
+            }
+            None => {
+                self.finished = true;
+                let len = self.front.len() + self.back.len();
+                Some(take_front(&mut self.front, &mut self.back, len))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(self.front.len() + self.back.len() + 1))
+        }
+    }
+}
+
+impl<T, P: FnMut(&T) -> bool> FusedIterator for Split<'_, T, P> {}
+
+impl<T: fmt::Debug, P> fmt::Debug for Split<'_, T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Split")
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+/// An iterator over the parts of an `AltDeque` that end with elements matching a predicate.
+///
+/// Unlike [`Split`], the matching elements are kept at the end of their parts, and there is no
+/// empty part after a match at the back of the deque.
+///
+/// This struct is created by the [`split_inclusive`] method on [`AltDeque`].
+///
+/// [`AltDeque`]: crate::AltDeque
+/// [`split_inclusive`]: crate::AltDeque::split_inclusive
+pub struct SplitInclusive<'a, T, P> {
+    front: &'a [T],
+    back: &'a [T],
+    pred: P,
+}
+
+impl<'a, T, P: FnMut(&T) -> bool> SplitInclusive<'a, T, P> {
+    pub(crate) fn new(front: &'a [T], back: &'a [T], pred: P) -> Self {
+        Self { front, back, pred }
+    }
+}
+
+impl<'a, T, P: FnMut(&T) -> bool> Iterator for SplitInclusive<'a, T, P> {
+    type Item = (&'a [T], &'a [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.front.len() + self.back.len();
+        if len == 0 {
+            return None;
+        }
+        let end = match position(self.front, self.back, &mut self.pred) {
+            Some(idx) => idx + 1,
+            None => len,
+        };
+        Some(take_front(&mut self.front, &mut self.back, end))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (usize::from(len != 0), Some(len))
+    }
+}
+
+impl<T, P: FnMut(&T) -> bool> FusedIterator for SplitInclusive<'_, T, P> {}
+
+impl<T: fmt::Debug, P> fmt::Debug for SplitInclusive<'_, T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitInclusive")
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .finish()
+    }
+}
+
+/// Returns the index of the first element of the two slices that matches `pred`.
+fn position<T>(front: &[T], back: &[T], pred: &mut impl FnMut(&T) -> bool) -> Option<usize> {
+    match front.iter().position(&mut *pred) {
+        Some(idx) => Some(idx),
+        None => back.iter().position(pred).map(|idx| front.len() + idx),
+    }
+}
@@ -0,0 +1,371 @@
+//! A bounded, lock-free single-producer/single-consumer queue.
+//!
+//! The queue follows the same idea as [`AltDeque`]: the [`Producer`] owns the back stack and
+//! is the only one writing to it, the [`Consumer`] owns the front stack and is the only one
+//! reading from it. Both stacks live in one shared ring buffer, and the border between them
+//! is a pair of atomic positions. When the consumer's stack runs dry it takes over everything
+//! the producer has published so far with a single atomic load, and the producer learns about
+//! freed slots the same way once its stack reaches the capacity. In between neither side
+//! touches the other's atomic, so a push or pop is usually a plain write plus one release
+//! store.
+//!
+//! [`AltDeque`]: crate::AltDeque
+//!
+//! # Examples
+//!
+//! ```
+//! use altdeque::spsc;
+//! use std::thread;
+//!
+//! let (mut producer, mut consumer) = spsc::channel(4);
+//! let handle = thread::spawn(move || {
+//!     for i in 0..100 {
+//!         let mut value = i;
+//!         while let Err(v) = producer.push(value) {
+//!             value = v;
+//!             thread::yield_now();
+//!         }
+//!     }
+//! });
+//! let mut received = Vec::new();
+//! while received.len() < 100 {
+//!     match consumer.pop() {
+//!         Some(value) => received.push(value),
+//!         None => thread::yield_now(),
+//!     }
+//! }
+//! handle.join().unwrap();
+//! assert_eq!(received, (0..100).collect::<Vec<_>>());
+//! ```
+
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::ops::Deref;
+
+use crate::loom::{Arc, AtomicUsize, Ordering, UnsafeCell};
+
+/// Creates a new queue that can hold up to `capacity` elements and returns its two ends.
+///
+/// # Panics
+///
+/// Panics if `capacity` is greater than `isize::MAX`.
+///
+/// # Examples
+///
+/// ```
+/// # use altdeque::spsc;
+/// let (mut producer, mut consumer) = spsc::channel(2);
+/// assert_eq!(producer.push(1), Ok(()));
+/// assert_eq!(producer.push(2), Ok(()));
+/// assert_eq!(producer.push(3), Err(3));
+/// assert_eq!(consumer.pop(), Some(1));
+/// assert_eq!(producer.push(3), Ok(()));
+/// ```
+pub fn channel<T>(capacity: usize) -> (Producer<T>, Consumer<T>) {
+    assert!(capacity <= isize::MAX as usize, "capacity overflow");
+    let buf = (0..capacity).map(|_| UnsafeCell::new(MaybeUninit::uninit())).collect();
+    let shared = Arc::new(Shared {
+        buf,
+        head: CachePadded(AtomicUsize::new(0)),
+        tail: CachePadded(AtomicUsize::new(0)),
+    });
+    let producer = Producer { shared: shared.clone(), head: 0, tail: 0 };
+    let consumer = Consumer { shared, head: 0, tail: 0 };
+    (producer, consumer)
+}
+
+/// The sending end of a queue created by [`channel`].
+pub struct Producer<T> {
+    shared: Arc<Shared<T>>,
+    // next position to write, the published value of shared.head
+    head: usize,
+    // last seen value of shared.tail
+    tail: usize,
+}
+
+/// The receiving end of a queue created by [`channel`].
+pub struct Consumer<T> {
+    shared: Arc<Shared<T>>,
+    // last seen value of shared.head, the end of the consumer's stack
+    head: usize,
+    // next position to read, the published value of shared.tail
+    tail: usize,
+}
+
+// Positions run from 0 to 2 * capacity so that a full and an empty queue can be told apart.
+struct Shared<T> {
+    buf: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    head: CachePadded<AtomicUsize>,
+    tail: CachePadded<AtomicUsize>,
+}
+
+// SAFETY: every slot is accessed by only one side at a time, the atomics order the handoffs.
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+#[repr(align(64))]
+struct CachePadded<T>(T);
+
+impl<T> Deref for CachePadded<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> Shared<T> {
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    #[inline]
+    fn distance(&self, tail: usize, head: usize) -> usize {
+        if head >= tail {
+            head - tail
+        } else {
+            head + 2 * self.capacity() - tail
+        }
+    }
+
+    #[inline]
+    fn next(&self, pos: usize) -> usize {
+        if pos + 1 == 2 * self.capacity() {
+            0
+        } else {
+            pos + 1
+        }
+    }
+
+    #[inline]
+    fn with_slot<R>(&self, pos: usize, f: impl FnOnce(*mut MaybeUninit<T>) -> R) -> R {
+        let idx = if pos >= self.capacity() { pos - self.capacity() } else { pos };
+        self.buf[idx].with_mut(f)
+    }
+}
+
+impl<T> Drop for Shared<T> {
+    fn drop(&mut self) {
+        // both ends are gone, so these are the final values
+        let head = self.head.load(Ordering::Relaxed);
+        let mut pos = self.tail.load(Ordering::Relaxed);
+        while pos != head {
+            // SAFETY: slots between tail and head are initialized and no one else can
+            // access them anymore.
+            self.with_slot(pos, |slot| unsafe { (*slot).assume_init_drop() });
+            pos = self.next(pos);
+        }
+    }
+}
+
+impl<T> Producer<T> {
+    /// Appends an element to the back of the queue.
+    /// Returns the element as an error if the queue is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::spsc;
+    /// let (mut producer, _consumer) = spsc::channel(1);
+    /// assert_eq!(producer.push(1), Ok(()));
+    /// assert_eq!(producer.push(2), Err(2));
+    /// ```
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        let shared = &*self.shared;
+        if shared.distance(self.tail, self.head) == shared.capacity() {
+            // our stack fills the whole buffer, look for slots the consumer has freed
+            self.tail = shared.tail.load(Ordering::Acquire);
+            if shared.distance(self.tail, self.head) == shared.capacity() {
+                return Err(value);
+            }
+        }
+        // SAFETY: the slot is outside of the consumer's stack, so only we can access it.
+        shared.with_slot(self.head, |slot| unsafe { (*slot).write(value) });
+        self.head = shared.next(self.head);
+        shared.head.store(self.head, Ordering::Release);
+        Ok(())
+    }
+
+    /// Returns the number of elements the queue can hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::spsc;
+    /// let (producer, _consumer) = spsc::channel::<i32>(8);
+    /// assert_eq!(producer.capacity(), 8);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.shared.capacity()
+    }
+
+    /// Returns the number of elements in the queue.
+    /// The value may already be outdated when it is returned, as the consumer can pop
+    /// concurrently, but it is never less than the actual length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::spsc;
+    /// let (mut producer, _consumer) = spsc::channel(8);
+    /// producer.push(1).unwrap();
+    /// assert_eq!(producer.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        let tail = self.shared.tail.load(Ordering::Acquire);
+        self.shared.distance(tail, self.head)
+    }
+
+    /// Returns `true` if the queue is empty.
+    /// The value may already be outdated when it is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::spsc;
+    /// let (mut producer, _consumer) = spsc::channel(8);
+    /// assert!(producer.is_empty());
+    /// producer.push(1).unwrap();
+    /// assert!(!producer.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the queue is full.
+    /// The value may already be outdated when it is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::spsc;
+    /// let (mut producer, _consumer) = spsc::channel(1);
+    /// assert!(!producer.is_full());
+    /// producer.push(1).unwrap();
+    /// assert!(producer.is_full());
+    /// ```
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+}
+
+impl<T> Consumer<T> {
+    /// Removes the first element and returns it, or `None` if the queue is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::spsc;
+    /// let (mut producer, mut consumer) = spsc::channel(2);
+    /// producer.push(1).unwrap();
+    /// assert_eq!(consumer.pop(), Some(1));
+    /// assert_eq!(consumer.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        let shared = &*self.shared;
+        if self.tail == self.head {
+            // our stack ran dry, take over everything the producer has published
+            self.head = shared.head.load(Ordering::Acquire);
+            if self.tail == self.head {
+                return None;
+            }
+        }
+        // SAFETY: the slot is in our stack, it is initialized and the producer won't touch it
+        // until we publish the new tail.
+        let value = shared.with_slot(self.tail, |slot| unsafe { (*slot).assume_init_read() });
+        self.tail = shared.next(self.tail);
+        shared.tail.store(self.tail, Ordering::Release);
+        Some(value)
+    }
+
+    /// Calls `f` with a reference to the first element and returns its result, or returns
+    /// `None` if the queue is empty.
+    ///
+    /// The reference is only lent to the closure, so that the loom model checks track the whole
+    /// access to the slot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::spsc;
+    /// let (mut producer, mut consumer) = spsc::channel(2);
+    /// assert_eq!(consumer.peek_with(|&x| x), None);
+    /// producer.push(1).unwrap();
+    /// assert_eq!(consumer.peek_with(|&x| x), Some(1));
+    /// ```
+    pub fn peek_with<R>(&mut self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        let shared = &*self.shared;
+        if self.tail == self.head {
+            self.head = shared.head.load(Ordering::Acquire);
+            if self.tail == self.head {
+                return None;
+            }
+        }
+        // SAFETY: the slot is in our stack and initialized, and the producer won't touch it
+        // until we publish a new tail, which we can't do while f runs.
+        Some(shared.with_slot(self.tail, |slot| f(unsafe { (*slot).assume_init_ref() })))
+    }
+
+    /// Returns the number of elements the queue can hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::spsc;
+    /// let (_producer, consumer) = spsc::channel::<i32>(8);
+    /// assert_eq!(consumer.capacity(), 8);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.shared.capacity()
+    }
+
+    /// Returns the number of elements in the queue.
+    /// The value may already be outdated when it is returned, as the producer can push
+    /// concurrently, but it is never more than the actual length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::spsc;
+    /// let (mut producer, consumer) = spsc::channel(8);
+    /// producer.push(1).unwrap();
+    /// assert_eq!(consumer.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        let head = self.shared.head.load(Ordering::Acquire);
+        self.shared.distance(self.tail, head)
+    }
+
+    /// Returns `true` if the queue is empty.
+    /// The value may already be outdated when it is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::spsc;
+    /// let (mut producer, consumer) = spsc::channel(8);
+    /// assert!(consumer.is_empty());
+    /// producer.push(1).unwrap();
+    /// assert!(!consumer.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> fmt::Debug for Producer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Producer").field("capacity", &self.capacity()).finish_non_exhaustive()
+    }
+}
+
+impl<T> fmt::Debug for Consumer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Consumer").field("capacity", &self.capacity()).finish_non_exhaustive()
+    }
+}
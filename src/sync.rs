@@ -0,0 +1,259 @@
+//! A bounded, blocking multi-producer/multi-consumer queue.
+//!
+//! [`SyncAltQueue`] is a [`BoundedAltDeque`] behind a [`Mutex`], with one [`Condvar`] for
+//! waiting producers and one for waiting consumers. It has the memory behavior of the deques
+//! in this crate, the buffer grows lazily up to the capacity of the queue, and needs no
+//! dependencies besides the standard library. Share it between threads with an [`Arc`].
+//!
+//! [`BoundedAltDeque`]: crate::BoundedAltDeque
+//! [`Arc`]: std::sync::Arc
+//!
+//! # Examples
+//!
+//! ```
+//! use altdeque::sync::SyncAltQueue;
+//! use std::sync::Arc;
+//! use std::thread;
+//!
+//! let queue = Arc::new(SyncAltQueue::new(4));
+//! let producer = {
+//!     let queue = Arc::clone(&queue);
+//!     thread::spawn(move || {
+//!         for i in 0..100 {
+//!             queue.push(i);
+//!         }
+//!     })
+//! };
+//! let received: Vec<_> = (0..100).map(|_| queue.pop()).collect();
+//! producer.join().unwrap();
+//! assert_eq!(received, (0..100).collect::<Vec<_>>());
+//! ```
+
+use std::fmt;
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use crate::BoundedAltDeque;
+
+/// A bounded FIFO queue whose [`push`] blocks while the queue is full and whose [`pop`] blocks
+/// while it is empty.
+///
+/// See the [module documentation](self) for more.
+///
+/// [`push`]: SyncAltQueue::push
+/// [`pop`]: SyncAltQueue::pop
+pub struct SyncAltQueue<T> {
+    deque: Mutex<BoundedAltDeque<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+}
+
+impl<T> SyncAltQueue<T> {
+    /// Creates an empty queue that holds at most `capacity` elements. Does not allocate.
+    ///
+    /// With a capacity of zero every push blocks forever or fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::sync::SyncAltQueue;
+    /// let queue: SyncAltQueue<i32> = SyncAltQueue::new(10);
+    /// assert_eq!(queue.capacity(), 10);
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            deque: Mutex::new(BoundedAltDeque::new(capacity)),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+        }
+    }
+
+    /// Returns the maximum number of elements the queue can hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::sync::SyncAltQueue;
+    /// let queue: SyncAltQueue<i32> = SyncAltQueue::new(10);
+    /// assert_eq!(queue.capacity(), 10);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.lock().limit()
+    }
+
+    /// Returns the number of elements in the queue. Other threads may change it at any time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::sync::SyncAltQueue;
+    /// let queue = SyncAltQueue::new(10);
+    /// queue.push(1);
+    /// assert_eq!(queue.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if the queue is empty. Other threads may change it at any time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::sync::SyncAltQueue;
+    /// let queue = SyncAltQueue::new(10);
+    /// assert!(queue.is_empty());
+    /// queue.push(1);
+    /// assert!(!queue.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Appends an element to the back of the queue, blocking while the queue is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::sync::SyncAltQueue;
+    /// let queue = SyncAltQueue::new(10);
+    /// queue.push(1);
+    /// assert_eq!(queue.pop(), 1);
+    /// ```
+    pub fn push(&self, value: T) {
+        let deque = self.lock();
+        let mut deque = self
+            .not_full
+            .wait_while(deque, |deque| deque.is_full())
+            .unwrap_or_else(PoisonError::into_inner);
+        self.push_locked(&mut deque, value);
+    }
+
+    /// Appends an element to the back of the queue, or returns it in an `Err` if the queue is
+    /// full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::sync::SyncAltQueue;
+    /// let queue = SyncAltQueue::new(1);
+    /// assert_eq!(queue.try_push(1), Ok(()));
+    /// assert_eq!(queue.try_push(2), Err(2));
+    /// ```
+    pub fn try_push(&self, value: T) -> Result<(), T> {
+        let mut deque = self.lock();
+        if deque.is_full() {
+            return Err(value);
+        }
+        self.push_locked(&mut deque, value);
+        Ok(())
+    }
+
+    /// Appends an element to the back of the queue, blocking for at most `timeout` while the
+    /// queue is full. Returns the element in an `Err` if the queue is still full afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::sync::SyncAltQueue;
+    /// # use std::time::Duration;
+    /// let queue = SyncAltQueue::new(1);
+    /// assert_eq!(queue.push_timeout(1, Duration::from_millis(10)), Ok(()));
+    /// assert_eq!(queue.push_timeout(2, Duration::from_millis(10)), Err(2));
+    /// ```
+    pub fn push_timeout(&self, value: T, timeout: Duration) -> Result<(), T> {
+        let deque = self.lock();
+        let (mut deque, _) = self
+            .not_full
+            .wait_timeout_while(deque, timeout, |deque| deque.is_full())
+            .unwrap_or_else(PoisonError::into_inner);
+        if deque.is_full() {
+            return Err(value);
+        }
+        self.push_locked(&mut deque, value);
+        Ok(())
+    }
+
+    /// Removes the first element and returns it, blocking while the queue is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::sync::SyncAltQueue;
+    /// let queue = SyncAltQueue::new(10);
+    /// queue.push(1);
+    /// queue.push(2);
+    /// assert_eq!(queue.pop(), 1);
+    /// assert_eq!(queue.pop(), 2);
+    /// ```
+    pub fn pop(&self) -> T {
+        let deque = self.lock();
+        let mut deque = self
+            .not_empty
+            .wait_while(deque, |deque| deque.is_empty())
+            .unwrap_or_else(PoisonError::into_inner);
+        self.pop_locked(&mut deque).unwrap()
+    }
+
+    /// Removes the first element and returns it, or `None` if the queue is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::sync::SyncAltQueue;
+    /// let queue = SyncAltQueue::new(10);
+    /// assert_eq!(queue.try_pop(), None);
+    /// queue.push(1);
+    /// assert_eq!(queue.try_pop(), Some(1));
+    /// ```
+    pub fn try_pop(&self) -> Option<T> {
+        let mut deque = self.lock();
+        self.pop_locked(&mut deque)
+    }
+
+    /// Removes the first element and returns it, blocking for at most `timeout` while the
+    /// queue is empty. Returns `None` if the queue is still empty afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::sync::SyncAltQueue;
+    /// # use std::time::Duration;
+    /// let queue = SyncAltQueue::new(10);
+    /// assert_eq!(queue.pop_timeout(Duration::from_millis(10)), None);
+    /// queue.push(1);
+    /// assert_eq!(queue.pop_timeout(Duration::from_millis(10)), Some(1));
+    /// ```
+    pub fn pop_timeout(&self, timeout: Duration) -> Option<T> {
+        let deque = self.lock();
+        let (mut deque, _) = self
+            .not_empty
+            .wait_timeout_while(deque, timeout, |deque| deque.is_empty())
+            .unwrap_or_else(PoisonError::into_inner);
+        self.pop_locked(&mut deque)
+    }
+
+    /// Locks the deque. None of the operations can leave the deque in an inconsistent state,
+    /// so a poisoned lock is taken over as is.
+    fn lock(&self) -> MutexGuard<'_, BoundedAltDeque<T>> {
+        self.deque.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn push_locked(&self, deque: &mut BoundedAltDeque<T>, value: T) {
+        let pushed = deque.push_back(value);
+        debug_assert!(pushed.is_ok());
+        self.not_empty.notify_one();
+    }
+
+    fn pop_locked(&self, deque: &mut BoundedAltDeque<T>) -> Option<T> {
+        let value = deque.pop_front()?;
+        self.not_full.notify_one();
+        Some(value)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SyncAltQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyncAltQueue").field("deque", &*self.lock()).finish()
+    }
+}
@@ -1,5 +1,63 @@
 use core::cmp::Ordering;
-use crate::AltDeque;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::mem::MaybeUninit;
+#[cfg(feature = "bumpalo")]
+use crate::BumpAltDeque;
+use crate::{concurrent, spsc, sync, AggregateDeque, AltDeque, Arena, ArenaAltDeque, ArrayAltDeque, CowAltDeque, Deque, BoundedAltDeque, BufferDeque, DualStackDeque, ExactAltDeque, GapBuffer, MinMaxQueue, RtAltDeque, SegmentedAltDeque, SmallAltDeque, StackLayout, TryReserveError, UndoRedo};
+
+/// Builds a deque of `items` whose first `front_len` elements are on the front stack and the
+/// rest on the back stack, with a buffer that is exactly full.
+fn split_deque<T>(items: impl IntoIterator<Item = T>, front_len: usize) -> AltDeque<T> {
+    split_deque_with_free(items, front_len, 0)
+}
+
+/// Like [`split_deque`], but leaves `free` slots between the stacks.
+fn split_deque_with_free<T>(items: impl IntoIterator<Item = T>, front_len: usize, free: usize) -> AltDeque<T> {
+    let mut front: Vec<_> = items.into_iter().collect();
+    let back = front.split_off(front_len);
+    let mut deque = AltDeque::with_capacity(front.len() + back.len() + free);
+    front.into_iter().rev().for_each(|x| deque.push_front(x));
+    deque.extend(back);
+    assert_eq!(deque.front_stack_len(), front_len);
+    deque
+}
+
+/// Forwards to the system allocator and counts the allocations and reallocations of each thread,
+/// so tests can check how often a deque reallocates. Tests run on their own threads.
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+// SAFETY: all calls are forwarded to the system allocator
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Returns how many allocations and reallocations `f` made on this thread.
+fn allocations_in(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
 
 #[test]
 fn test_new() {
@@ -41,6 +99,34 @@ fn test_as_slices() {
     assert_eq!(deque.as_slices(), (&[4, 3][..], &[0, 1, 2][..]));
 }
 
+#[test]
+fn test_layout() {
+    let mut deque = AltDeque::with_capacity(6);
+    let cap = deque.capacity();
+    assert_eq!(deque.debug_layout(), StackLayout { capacity: cap, head: 0, tail: cap, front_len: 0, back_len: 0, gap: cap });
+    deque.extend([1, 2, 3, 4]);
+    deque.push_front(0);
+    assert_eq!(deque.front_stack_len(), 1);
+    assert_eq!(deque.back_stack_len(), 4);
+    assert_eq!(deque.debug_layout(), StackLayout { capacity: cap, head: 4, tail: cap - 1, front_len: 1, back_len: 4, gap: cap - 5 });
+    deque.pop_front();
+    deque.pop_front();
+    assert_eq!((deque.front_stack_len(), deque.back_stack_len()), (2, 1));
+}
+
+#[test]
+#[cfg(feature = "debug-invariants")]
+#[should_panic = "invariant violated: head 2 > tail 1"]
+fn test_check_invariants() {
+    use std::mem::ManuallyDrop;
+
+    let mut deque = ManuallyDrop::new(AltDeque::from([1, 2]));
+    deque.push_back(3);
+    deque.head = 2;
+    deque.tail = 1;
+    deque.check_invariants();
+}
+
 #[test]
 fn test_get() {
     let deque = AltDeque::from(([1, 2], [3, 4]));
@@ -51,6 +137,16 @@ fn test_get() {
     assert_eq!(deque.get(5), None);
 }
 
+#[test]
+fn test_get_unchecked() {
+    let mut deque = AltDeque::from(([1, 2], [3, 4]));
+    for i in 0..4 {
+        assert_eq!(unsafe { deque.get_unchecked(i) }, &deque[i]);
+        unsafe { *deque.get_unchecked_mut(i) *= 10 };
+    }
+    assert_eq!(deque, [10, 20, 30, 40]);
+}
+
 #[test]
 fn test_reserve_and_exact() {
     let mut deque = AltDeque::from([1, 2, 3, 4]);
@@ -69,6 +165,34 @@ fn test_resize() {
     assert_eq!(deque, [1, 2, 5, 5, 5]);
 }
 
+#[test]
+#[cfg(feature = "metrics")]
+fn test_stats() {
+    let mut deque = AltDeque::new();
+    (0..5).for_each(|i| deque.push_back(i));
+    let stats = deque.stats();
+    // capacity 4 and then 8
+    assert_eq!(stats.reallocations, 2);
+    assert_eq!(stats.rebalances, 0);
+    assert_eq!(stats.peak_len, 5);
+
+    // the first pop moves the other four elements
+    deque.pop_front();
+    deque.pop_front();
+    assert_eq!(deque.stats().rebalances, 1);
+    assert_eq!(deque.stats().elements_moved, 4);
+
+    deque.rebalance(0);
+    deque.shrink_to_fit();
+    let stats = deque.stats();
+    assert_eq!(stats.rebalances, 2);
+    assert_eq!(stats.reallocations, 3);
+    assert_eq!(stats.peak_len, 5);
+
+    deque.reset_stats();
+    assert_eq!(deque.stats(), crate::Stats { peak_len: 3, ..Default::default() });
+}
+
 #[test]
 fn test_resize_with() {
     let mut deque = AltDeque::from([1, 2, 3]);
@@ -88,6 +212,24 @@ fn test_shrink() {
     deque.shrink_to(0);
     assert_eq!(deque.as_slices(), (&[-1][..], &[1][..]));
     assert_eq!(deque.capacity(), 2);
+
+    // shrinking an empty deque to zero releases the allocation
+    let mut deque: AltDeque<_> = (0..100).collect();
+    deque.clear();
+    deque.shrink_to(0);
+    assert_eq!(deque.capacity(), 0);
+    assert!(deque.is_empty());
+    deque.push_back(1);
+    deque.push_front(0);
+    assert_eq!(deque, [0, 1]);
+
+    // zero-sized types always have the maximum capacity
+    let mut deque = AltDeque::new();
+    deque.push_front(());
+    deque.push_back(());
+    deque.shrink_to(0);
+    assert_eq!(deque.capacity(), usize::MAX);
+    assert_eq!(deque.as_slices(), (&[()][..], &[()][..]));
 }
 
 #[test]
@@ -123,6 +265,45 @@ fn test_contains() {
     assert!(!deque.contains(&5));
 }
 
+#[test]
+fn test_position() {
+    let deque = AltDeque::from(([1, 2], [3, 1]));
+    for x in 0..5 {
+        assert_eq!(deque.position(&x), deque.index_of(&x));
+    }
+    assert_eq!(deque.position(&1), Some(0));
+    assert_eq!(deque.position(&3), Some(2));
+    assert_eq!(deque.position(&5), None);
+}
+
+#[test]
+fn test_index_of() {
+    let mut deque = AltDeque::from(([1, 2, 3], [1, 2]));
+    for _ in 0..5 {
+        let vec: Vec<_> = deque.iter().copied().collect();
+        for x in 0..5 {
+            assert_eq!(deque.index_of(&x), vec.iter().position(|&y| y == x));
+            assert_eq!(deque.rindex_of(&x), vec.iter().rposition(|&y| y == x));
+            assert_eq!(deque.index_of_by(|&y| y > x), vec.iter().position(|&y| y > x));
+            assert_eq!(deque.rindex_of_by(|&y| y > x), vec.iter().rposition(|&y| y > x));
+        }
+        // move the seam between the stacks
+        deque.rotate_left(1);
+    }
+}
+
+#[cfg(feature = "memchr")]
+#[test]
+fn test_find_byte() {
+    let mut deque: AltDeque<u8> = (0..100).collect();
+    (100..=255).rev().for_each(|b| deque.push_front(b));
+    deque.push_front(7);
+    for byte in 0..=255 {
+        assert_eq!(deque.find_byte(byte), deque.index_of(&byte));
+        assert_eq!(deque.rfind_byte(byte), deque.rindex_of(&byte));
+    }
+}
+
 #[test]
 fn test_front() {
     let mut deque = AltDeque::new();
@@ -169,15 +350,55 @@ fn test_back() {
     assert_eq!(deque.back_mut(), None);
 }
 
+#[test]
+fn test_chunks() {
+    let mut deque = AltDeque::from(([1, 2, 3], [4, 5]));
+    assert_eq!(deque.front_chunk::<0>(), Some(&[]));
+    assert_eq!(deque.front_chunk::<3>(), Some(&[1, 2, 3]));
+    assert_eq!(deque.front_chunk::<4>(), None);
+    assert_eq!(deque.back_chunk::<2>(), Some(&[4, 5]));
+    assert_eq!(deque.back_chunk::<3>(), None);
+    assert_eq!(deque.front_chunk_copied::<4>(), Some([1, 2, 3, 4]));
+    assert_eq!(deque.back_chunk_copied::<5>(), Some([1, 2, 3, 4, 5]));
+    assert_eq!(deque.back_chunk_copied::<6>(), None);
+
+    deque.front_chunk_mut::<2>().unwrap()[0] = 0;
+    deque.back_chunk_mut::<2>().unwrap()[1] = 0;
+    assert_eq!(deque, [0, 2, 3, 4, 0]);
+
+    // with one stack empty, the chunks are taken from the other one
+    let deque = AltDeque::from(([], [1, 2, 3]));
+    assert_eq!(deque.front_chunk::<3>(), Some(&[1, 2, 3]));
+    assert_eq!(deque.back_chunk::<2>(), Some(&[2, 3]));
+    let deque = AltDeque::from(([1, 2, 3], []));
+    assert_eq!(deque.front_chunk::<2>(), Some(&[1, 2]));
+    assert_eq!(deque.back_chunk::<3>(), Some(&[1, 2, 3]));
+}
+
 #[test]
 fn test_pop_front() {
     let mut deque = AltDeque::new();
     deque.push_back(1);
     deque.push_back(2);
     deque.push_back(3);
+    deque.push_back(4);
+    deque.push_back(5);
+    // half of the back stack is moved to the front
     assert_eq!(deque.pop_front(), Some(1));
+    assert_eq!(deque.as_slices(), (&[2, 3][..], &[4, 5][..]));
     assert_eq!(deque.pop_front(), Some(2));
-    assert_eq!(deque.as_slices(), (&[3][..], &[][..]));
+    assert_eq!(deque.pop_front(), Some(3));
+    assert_eq!(deque.pop_front(), Some(4));
+    assert_eq!(deque.as_slices(), (&[5][..], &[][..]));
+
+    // full buffer, the halves have to be rotated
+    let mut deque = AltDeque::with_capacity(6);
+    deque.extend(0..deque.capacity());
+    let cap = deque.capacity();
+    assert_eq!(deque.pop_front(), Some(0));
+    assert_eq!(deque.len(), cap - 1);
+    assert!(deque.iter().copied().eq(1..cap));
+    assert_eq!(deque.as_slices().0.len(), cap / 2);
 }
 
 #[test]
@@ -186,9 +407,75 @@ fn test_pop_back() {
     deque.push_front(1);
     deque.push_front(2);
     deque.push_front(3);
+    deque.push_front(4);
+    deque.push_front(5);
+    // half of the front stack is moved to the back
     assert_eq!(deque.pop_back(), Some(1));
+    assert_eq!(deque.as_slices(), (&[5, 4][..], &[3, 2][..]));
     assert_eq!(deque.pop_back(), Some(2));
-    assert_eq!(deque.as_slices(), (&[][..], &[3][..]));
+    assert_eq!(deque.pop_back(), Some(3));
+    assert_eq!(deque.pop_back(), Some(4));
+    assert_eq!(deque.as_slices(), (&[][..], &[5][..]));
+
+    // full buffer, the halves have to be rotated
+    let mut deque = AltDeque::with_capacity(6);
+    let cap = deque.capacity();
+    (0..cap).rev().for_each(|i| deque.push_front(i));
+    assert_eq!(deque.pop_back(), Some(cap - 1));
+    assert_eq!(deque.len(), cap - 1);
+    assert!(deque.iter().copied().eq(0..cap - 1));
+    assert_eq!(deque.as_slices().1.len(), cap / 2);
+}
+
+#[test]
+fn test_pop_alternating() {
+    let mut deque: AltDeque<_> = (0..1000).collect();
+    let mut front = 0;
+    let mut back = 1000;
+    while !deque.is_empty() {
+        assert_eq!(deque.pop_front(), Some(front));
+        front += 1;
+        if let Some(value) = deque.pop_back() {
+            back -= 1;
+            assert_eq!(value, back);
+        }
+        // after the first split both stacks stay non-empty most of the time
+        let (f, b) = deque.as_slices();
+        assert!(f.len() + 1 >= b.len() / 2 || b.len() <= 1);
+    }
+    assert_eq!(front, back);
+}
+
+#[test]
+fn test_pop_into() {
+    for front_len in 0..5 {
+        for back_len in 0..5 {
+            for count in 0..10 {
+                let front: Vec<_> = (0..front_len).map(|i| i.to_string()).collect();
+                let back: Vec<_> = (front_len..front_len + back_len).map(|i| i.to_string()).collect();
+                let mut model: VecDeque<_> = front.iter().chain(&back).cloned().collect();
+                let mut deque = split_deque(front.into_iter().chain(back), front_len);
+
+                let mut buf: Vec<_> = (0..count).map(|_| MaybeUninit::uninit()).collect();
+                let popped = deque.pop_front_into(&mut buf);
+                assert_eq!(popped, count.min(model.len()));
+                for slot in &mut buf[..popped] {
+                    // SAFETY: the first popped slots are initialized
+                    assert_eq!(unsafe { slot.assume_init_read() }, model.pop_front().unwrap());
+                }
+                assert_eq!(deque, model);
+
+                let popped = deque.pop_back_into(&mut buf);
+                assert_eq!(popped, count.min(model.len()));
+                let expected = model.split_off(model.len() - popped);
+                for (slot, x) in buf[..popped].iter_mut().zip(expected) {
+                    // SAFETY: the first popped slots are initialized
+                    assert_eq!(unsafe { slot.assume_init_read() }, x);
+                }
+                assert_eq!(deque, model);
+            }
+        }
+    }
 }
 
 #[test]
@@ -209,6 +496,44 @@ fn test_push_back() {
     assert_eq!(deque.as_slices(), (&[][..], &[1, 2, 3][..]));
 }
 
+#[test]
+fn test_push_chunk() {
+    let mut deque = AltDeque::new();
+    deque.push_back_chunk([3, 4]);
+    deque.push_front_chunk([1, 2]);
+    deque.push_back_chunk([]);
+    assert_eq!(deque.as_slices(), (&[1, 2][..], &[3, 4][..]));
+    for i in 0..10 {
+        deque.push_back_chunk([i; 16]);
+        deque.push_front_chunk([i; 16]);
+    }
+    assert_eq!(deque.len(), 324);
+
+    let mut deque = AltDeque::new();
+    deque.push_back_chunk([String::from("b"), String::from("c")]);
+    deque.push_front_chunk([String::from("a")]);
+    assert_eq!(deque, ["a", "b", "c"]);
+
+    let mut deque = AltDeque::new();
+    deque.push_front_chunk([(); 3]);
+    deque.push_back_chunk([(); 2]);
+    assert_eq!(deque.len(), 5);
+}
+
+#[test]
+fn test_pop_chunk() {
+    let mut deque: AltDeque<_> = (0..10).map(|i| i.to_string()).collect();
+    deque.push_front(String::from("-1"));
+    assert_eq!(deque.pop_front_chunk(), Some([String::from("-1"), String::from("0")]));
+    assert_eq!(deque.pop_back_chunk(), Some([String::from("8"), String::from("9")]));
+    assert_eq!(deque.pop_front_chunk::<8>(), None);
+    assert_eq!(deque.pop_front_chunk::<0>(), Some([]));
+    assert_eq!(deque.pop_back_chunk::<6>().unwrap().concat(), "234567");
+    assert_eq!(deque.pop_back_chunk::<1>().unwrap().concat(), "1");
+    assert!(deque.is_empty());
+    assert_eq!(deque.pop_back_chunk::<1>(), None);
+}
+
 #[test]
 fn test_swap() {
     let mut deque = AltDeque::from(([-3, -2, -1], [1, 2, 3]));
@@ -336,11 +661,66 @@ fn test_append_overflow() {
     deque.append(&mut deque.clone());
 }
 
+#[test]
+fn test_merge() {
+    let mut deque = AltDeque::from(([(1, 'a'), (3, 'a')], [(3, 'b'), (5, 'a')]));
+    let mut other = AltDeque::from(([(0, 'c'), (3, 'c')], [(6, 'c')]));
+    deque.merge_by(&mut other, |a, b| a.0.cmp(&b.0));
+    assert_eq!(deque, [(0, 'c'), (1, 'a'), (3, 'a'), (3, 'b'), (3, 'c'), (5, 'a'), (6, 'c')]);
+    assert!(other.is_empty());
+    other.push_back((7, 'd'));
+    assert_eq!(other, [(7, 'd')]);
+
+    for len in 0..6 {
+        for other_len in 0..6 {
+            let mut deque: AltDeque<_> = (0..len).map(|i| i * 2).collect();
+            let mut other: AltDeque<_> = (0..other_len).map(|i| i * 3).collect();
+            let mut expected: Vec<_> = deque.iter().chain(other.iter()).copied().collect();
+            expected.sort();
+            deque.merge(&mut other);
+            assert_eq!(deque, expected);
+            assert!(other.is_empty());
+        }
+    }
+}
+
+#[test]
+fn test_merge_panic() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    let el = Rc::new(0);
+    for panic_at in 0..5 {
+        let mut deque = AltDeque::from(([0, 2].map(|x| (x, el.clone())), [4, 6].map(|x| (x, el.clone()))));
+        let mut other = AltDeque::from([1, 3, 5].map(|x| (x, el.clone())));
+        let mut calls = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            deque.merge_by(&mut other, |a, b| {
+                assert_ne!(calls, panic_at);
+                calls += 1;
+                a.0.cmp(&b.0)
+            });
+        }));
+        assert!(result.is_err());
+        assert!(other.is_empty());
+        let mut values: Vec<_> = deque.iter().map(|x| x.0).collect();
+        values.sort();
+        assert_eq!(values, [0, 1, 2, 3, 4, 5, 6]);
+        drop(deque);
+        assert_eq!(Rc::strong_count(&el), 1);
+    }
+}
+
 #[test]
 fn test_retain() {
     let mut deque = AltDeque::from(([-3, -2, -1], [1, 2, 3]));
     deque.retain(|el| el % 2 == 0);
     assert_eq!(deque, [-2, 2]);
+    // the elements stay in their stacks
+    assert_eq!(deque.as_slices(), (&[-2][..], &[2][..]));
+    deque.push_front(-4);
+    deque.push_back(4);
+    assert_eq!(deque, [-4, -2, 2, 4]);
 }
 
 #[test]
@@ -348,6 +728,107 @@ fn test_retain_mut() {
     let mut deque = AltDeque::from(([-3, -2, -1], [1, 2, 3]));
     deque.retain_mut(|el| { *el += 1; *el % 2 == 0 });
     assert_eq!(deque, [-2, 0, 2, 4]);
+    assert_eq!(deque.as_slices(), (&[-2, 0][..], &[2, 4][..]));
+}
+
+#[test]
+fn test_retain_panic() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    let el = Rc::new(0);
+    for panic_at in 0..6 {
+        let mut deque = AltDeque::from(([0, 1, 2].map(|_| el.clone()), [3, 4, 5].map(|_| el.clone())));
+        let mut cur = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            deque.retain(|_| {
+                assert_ne!(cur, panic_at);
+                cur += 1;
+                cur % 2 == 0
+            });
+        }));
+        assert!(result.is_err());
+        // every element before the panic has been visited, the rest is untouched
+        assert_eq!(deque.len(), 6 - panic_at + panic_at / 2);
+        assert_eq!(Rc::strong_count(&el), 1 + deque.len());
+        drop(deque);
+        assert_eq!(Rc::strong_count(&el), 1);
+    }
+}
+
+#[test]
+fn test_sort() {
+    // front stack shorter, longer and equal to the back stack
+    for (front, back) in [(vec![9, 3, 7], vec![8, 1, 6, 2, 5]), (vec![9, 3, 7, 2, 5], vec![8, 1]), (vec![4, 2], vec![3, 1])] {
+        let build = || {
+            let mut deque = AltDeque::from(front.clone());
+            deque.make_contiguous();
+            deque.extend(back.iter().copied());
+            deque
+        };
+        let mut expected = [front.clone(), back.clone()].concat();
+        expected.sort();
+        let mut deque = build();
+        let mut unstable = build();
+        deque.sort();
+        assert_eq!(deque, expected);
+        assert_eq!(deque.as_slices().0.len(), front.len());
+        unstable.sort_unstable();
+        assert_eq!(unstable, expected);
+        assert_eq!(unstable.as_slices().0.len(), front.len());
+    }
+
+    // already sorted
+    let mut deque = AltDeque::from(([1, 2], [3, 4]));
+    deque.sort();
+    assert_eq!(deque.as_slices(), (&[1, 2][..], &[3, 4][..]));
+    let mut deque = AltDeque::from(([], [2, 1]));
+    deque.sort();
+    assert_eq!(deque.as_slices(), (&[][..], &[1, 2][..]));
+}
+
+#[test]
+fn test_sort_stable() {
+    for split in 0..=8 {
+        let values = [(3, 0), (1, 1), (3, 2), (2, 3), (1, 4), (2, 5), (3, 6), (1, 7)];
+        let mut deque = AltDeque::from(values[..split].to_vec());
+        deque.make_contiguous();
+        deque.extend(values[split..].iter().copied());
+        deque.sort_by_key(|&(k, _)| k);
+        let mut expected = values.to_vec();
+        expected.sort_by_key(|&(k, _)| k);
+        assert_eq!(deque, expected);
+        deque.sort_by(|a, b| b.cmp(a));
+        expected.sort_by(|a, b| b.cmp(a));
+        assert_eq!(deque, expected);
+    }
+}
+
+#[test]
+fn test_sort_panic() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    for panic_at in 0..20 {
+        let els: Vec<_> = [5, 1, 4, 2, 8, 3, 7, 6].into_iter().map(Rc::new).collect();
+        let mut deque = AltDeque::from(els[..3].to_vec());
+        deque.make_contiguous();
+        deque.extend(els[3..].iter().cloned());
+        let mut count = 0;
+        let _ = catch_unwind(AssertUnwindSafe(|| {
+            deque.sort_by(|a, b| {
+                count += 1;
+                assert_ne!(count, panic_at);
+                a.cmp(b)
+            });
+        }));
+        // no element got lost or duplicated
+        assert_eq!(deque.len(), 8);
+        assert!(els.iter().all(|el| Rc::strong_count(el) == 2));
+        let mut values: Vec<_> = deque.iter().map(|el| **el).collect();
+        values.sort();
+        assert_eq!(values, [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
 }
 
 #[test]
@@ -396,6 +877,151 @@ fn test_make_contiguous() {
     let mut deque = AltDeque::from(([1, 2], [3, 4, 5, 6, 7, 8, 9, 10]));
     deque.pop_back();
     assert_eq!(deque.make_contiguous(), &[1, 2, 3, 4, 5, 6, 7, 8, 9][..]);
+
+    // every combination of front len, back len and free space in 0..10
+    for front_len in 0..10 {
+        for back_len in 0..10 {
+            for free in 0..10 {
+                let mut deque = split_deque_with_free(0..front_len + back_len, front_len, free);
+                assert!(deque.make_contiguous().iter().copied().eq(0..front_len + back_len));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_make_contiguous_back() {
+    let mut deque = AltDeque::from(([1, 2, 3], [4, 5]));
+    assert_eq!(deque.make_contiguous_back(), &[1, 2, 3, 4, 5][..]);
+    assert_eq!(deque.back_stack_len(), 5);
+    // everything is in the back stack already
+    assert_eq!(deque.make_contiguous_back(), &[1, 2, 3, 4, 5][..]);
+    assert_eq!(deque.pop_back(), Some(5));
+    assert_eq!(deque.front_stack_len(), 0);
+
+    // every combination of front len, back len and free space in 0..10
+    for front_len in 0..10 {
+        for back_len in 0..10 {
+            for free in 0..10 {
+                let mut deque = split_deque_with_free(0..front_len + back_len, front_len, free);
+                assert!(deque.make_contiguous_back().iter().copied().eq(0..front_len + back_len));
+                assert_eq!(deque.front_stack_len(), 0);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_exact_growth() {
+    let mut deque = ExactAltDeque::new();
+    assert_eq!(deque.capacity(), 0);
+    for i in 1..=10 {
+        deque.push_back(i);
+        assert_eq!(deque.capacity(), i);
+    }
+    deque.reserve(5);
+    assert_eq!(deque.capacity(), 15);
+    deque.extend(0..10);
+    assert_eq!(deque.capacity(), 20);
+    deque.insert(3, 0);
+    assert_eq!(deque.capacity(), 21);
+    deque.append(&mut AltDeque::from([1, 2]));
+    assert_eq!(deque.capacity(), 23);
+    // clones and split off parts grow exactly as well
+    let mut clone = deque.clone();
+    assert_eq!(clone.capacity(), 23);
+    clone.push_front(0);
+    assert_eq!(clone.capacity(), 24);
+    let mut other = deque.split_off(11);
+    other.push_back(0);
+    assert_eq!(other.capacity(), 13);
+    // an AltDeque with slack keeps it until it has to grow
+    let mut deque = ExactAltDeque::from(AltDeque::from_iter(0..3));
+    deque.shrink_to_fit();
+    deque.push_front(-1);
+    assert_eq!(deque.capacity(), 4);
+    assert_eq!(deque, [-1, 0, 1, 2]);
+    let mut deque = AltDeque::from(deque);
+    deque.push_back(3);
+    assert!(deque.capacity() > 5);
+}
+
+#[test]
+fn test_exact_extend_filtered() {
+    // the lower bound of the size hint is 0, growing by one per element would reallocate for
+    // each of them
+    let mut deque = ExactAltDeque::from_iter([0; 3]);
+    let allocations = allocations_in(|| deque.extend((0..20_000).filter(|x| x % 2 == 0)));
+    assert!(allocations < 32, "{allocations} allocations");
+    assert_eq!(deque.len(), 10_003);
+    assert_eq!(deque.capacity(), 10_003);
+    assert_eq!(deque[3], 0);
+    assert_eq!(deque.back(), Some(&19_998));
+    let deque: ExactAltDeque<_> = (0..1000).filter(|x| x % 3 == 0).collect();
+    assert_eq!(deque.capacity(), 334);
+    // the size hint is already enough
+    let mut deque = ExactAltDeque::with_capacity(10);
+    assert_eq!(allocations_in(|| deque.extend(0..10)), 0);
+    assert_eq!(deque.capacity(), 10);
+}
+
+#[test]
+fn test_reserve_front_back() {
+    // the back stack fills most of the buffer, the free space is still usable from the front
+    let mut deque: AltDeque<_> = AltDeque::new();
+    (0..100).for_each(|i| deque.push_back(i));
+    deque.reserve_front(50);
+    let cap = deque.capacity();
+    (0..50).for_each(|i| deque.push_front(i));
+    assert_eq!(deque.capacity(), cap);
+
+    let mut deque: AltDeque<_> = (0..100).collect();
+    deque.reserve_back(50);
+    let cap = deque.capacity();
+    (0..50).for_each(|i| deque.push_back(i));
+    assert_eq!(deque.capacity(), cap);
+}
+
+#[test]
+fn test_rebalance() {
+    // every combination of front len, back len, free space and new front len in 0..8
+    for front_len in 0..8 {
+        for back_len in 0..8 {
+            for free in 0..8 {
+                for new_front_len in 0..=front_len + back_len {
+                    let mut deque = split_deque_with_free(0..front_len + back_len, front_len, free);
+                    deque.rebalance(new_front_len);
+                    assert_eq!(deque.front_stack_len(), new_front_len);
+                    assert!(deque.iter().copied().eq(0..front_len + back_len));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic="index out of bounds: the len is 3 but the index is 4"]
+fn test_rebalance_out_of_bounds() {
+    let mut deque = AltDeque::from([1, 2, 3]);
+    deque.rebalance(4);
+}
+
+#[test]
+fn test_reverse() {
+    // every combination of front len, back len and free space in 0..8
+    for front_len in 0..8 {
+        for back_len in 0..8 {
+            for free in 0..8 {
+                let len = front_len + back_len;
+                let mut deque = split_deque_with_free(0..len, front_len, free);
+                deque.reverse();
+                assert!(deque.iter().copied().eq((0..len).rev()));
+                assert_eq!(deque.front_stack_len(), back_len);
+                deque.reverse();
+                assert!(deque.iter().copied().eq(0..len));
+            }
+        }
+    }
 }
 
 #[test]
@@ -425,6 +1051,33 @@ fn test_rotate() {
     }
 }
 
+#[test]
+fn test_rotate_without_spare_capacity() {
+    // every combination of front len, back len, free space in 0..3 and mid
+    for front_len in 0..8 {
+        for back_len in 0..8 {
+            let len = front_len + back_len;
+            for free in 0..3 {
+                for mid in 0..=len {
+                    let make = || {
+                        let deque = split_deque_with_free(0..len, front_len, free);
+                        assert_eq!(deque.capacity(), len + free);
+                        deque
+                    };
+                    let mut deque_l = make();
+                    let mut deque_r = make();
+                    deque_l.rotate_left(mid);
+                    deque_r.rotate_right(mid);
+                    assert!(deque_l.iter().copied().eq((mid..len).chain(0..mid)));
+                    assert!(deque_r.iter().copied().eq((len - mid..len).chain(0..len - mid)));
+                    assert_eq!(deque_l.capacity(), len + free);
+                    assert_eq!(deque_r.capacity(), len + free);
+                }
+            }
+        }
+    }
+}
+
 #[test]
 fn test_binary_search() {
     let deque = AltDeque::from(([-3, -2, -1], [1, 2, 3]));
@@ -465,6 +1118,22 @@ fn test_partition_point() {
 }
 
 #[test]
+fn test_bounds() {
+    // equal elements on both sides of the seam
+    let deque = AltDeque::from(([1, 3, 3], [3, 5, 5]));
+    let vec: Vec<_> = deque.iter().copied().collect();
+    for x in 0..7 {
+        assert_eq!(deque.lower_bound(&x), vec.partition_point(|&y| y < x));
+        assert_eq!(deque.upper_bound(&x), vec.partition_point(|&y| y <= x));
+        assert_eq!(deque.lower_bound_by(|y| y.cmp(&x)), deque.lower_bound(&x));
+        assert_eq!(deque.upper_bound_by(|y| y.cmp(&x)), deque.upper_bound(&x));
+    }
+    assert_eq!(deque.lower_bound(&3), 1);
+    assert_eq!(deque.upper_bound(&3), 4);
+}
+
+#[test]
+#[allow(clippy::iter_nth_zero)]
 fn test_iter() {
     let deque = AltDeque::<i32>::from(([-3, -2, -1], [1, 2, 3]));
 
@@ -485,6 +1154,7 @@ fn test_iter() {
 }
 
 #[test]
+#[allow(clippy::iter_nth_zero)]
 fn test_iter_mut() {
     let mut deque = AltDeque::<i32>::from(([-3, -2, -1], [1, 2, 3]));
     deque.iter_mut().for_each(|el| *el *= 2);
@@ -559,6 +1229,39 @@ fn test_drain() {
     assert_eq!(drain.next_back(), Some(1));
     assert_eq!(drain.next_back(), Some(-1));
     assert_eq!(drain.next_back(), None);
+    drop(drain);
+    assert_eq!(deque.as_slices(), (&[-3, -2][..], &[2, 3][..]));
+}
+
+#[test]
+fn test_drain_within_one_stack() {
+    // dropping a drain of a range in one stack must keep the other stack as it was
+    let mut deque = AltDeque::from(([-3, -2, -1], [1, 2, 3]));
+    assert_eq!(deque.drain(1..2).collect::<Vec<_>>(), vec![-2]);
+    assert_eq!(deque.as_slices(), (&[-3, -1][..], &[1, 2, 3][..]));
+    assert_eq!(deque.drain(3..4).collect::<Vec<_>>(), vec![2]);
+    assert_eq!(deque.as_slices(), (&[-3, -1][..], &[1, 3][..]));
+
+    for front_len in 0..=4 {
+        for back_len in 0..=4 {
+            let len = front_len + back_len;
+            let ranges = (0..=front_len)
+                .flat_map(|start| (start..=front_len).map(move |end| start..end))
+                .chain((front_len..=len).flat_map(|start| (start..=len).map(move |end| start..end)));
+            for range in ranges {
+                for taken in 0..=range.len() {
+                    let mut deque = split_deque_with_free(0..len, front_len, 2);
+                    let mut model: VecDeque<_> = (0..len).collect();
+                    let expected: Vec<_> = model.drain(range.clone()).take(taken).collect();
+                    let drained: Vec<_> = deque.drain(range.clone()).take(taken).collect();
+                    assert_eq!(drained, expected);
+                    assert_eq!(deque, model, "range {:?} in {} + {}", range, front_len, back_len);
+                    let front_drained = if range.end <= front_len { range.len() } else { 0 };
+                    assert_eq!(deque.front_stack_len(), front_len - front_drained);
+                }
+            }
+        }
+    }
 }
 #[test]
 #[should_panic]
@@ -574,11 +1277,131 @@ fn test_drain_out_of_bounds_end() {
 }
 #[test]
 #[should_panic]
+#[allow(clippy::reversed_empty_ranges)]
 fn test_drain_invalid_bounds() {
     let deque = AltDeque::from([1, 2, 3]);
     let _range = deque.range(2..1);
 }
 
+#[test]
+fn test_drain_drop_panic() {
+    use std::cell::Cell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    struct Bomb<'a>(i32, &'a Cell<usize>);
+
+    impl Drop for Bomb<'_> {
+        fn drop(&mut self) {
+            self.1.set(self.1.get() + 1);
+            assert_ne!(self.0, 0, "boom");
+        }
+    }
+
+    for (start, end) in [(0, 6), (1, 3), (2, 5), (3, 6)] {
+        for bomb in start..end {
+            let dropped = Cell::new(0);
+            let front: [_; 3] = core::array::from_fn(|i| Bomb(i as i32 - bomb as i32, &dropped));
+            let back: [_; 3] = core::array::from_fn(|i| Bomb(i as i32 + 3 - bomb as i32, &dropped));
+            let mut deque = AltDeque::from((front, back));
+            let result = catch_unwind(AssertUnwindSafe(|| drop(deque.drain(start..end))));
+            assert!(result.is_err());
+            // every drained element got dropped and the rest is still in the deque
+            assert_eq!(dropped.get(), end - start);
+            let remaining: Vec<_> = deque.iter().map(|el| el.0 + bomb as i32).collect();
+            let expected: Vec<_> = (0..start as i32).chain(end as i32..6).collect();
+            assert_eq!(remaining, expected);
+        }
+    }
+}
+
+#[test]
+fn test_drain_leak() {
+    use std::mem;
+    use std::rc::Rc;
+
+    let make = || {
+        let rc = Rc::new(());
+        let front: [_; 3] = core::array::from_fn(|_| rc.clone());
+        let back: [_; 3] = core::array::from_fn(|_| rc.clone());
+        (rc, AltDeque::from((front, back)))
+    };
+    for (start, end) in [(0, 6), (1, 2), (0, 3), (2, 5), (3, 6), (4, 5), (3, 3)] {
+        let (rc, mut deque) = make();
+        let mut drain = deque.drain(start..end);
+        drain.next();
+        mem::forget(drain);
+        assert_eq!(deque.len(), 6 - (end - start));
+        drop(deque);
+        assert_eq!(Rc::strong_count(&rc), 1 + (end - start).saturating_sub(1));
+    }
+
+    let mut deque = AltDeque::from(([1, 2, 3], [4, 5, 6]));
+    mem::forget(deque.drain(2..4));
+    assert_eq!(deque.as_slices(), (&[1, 2][..], &[5, 6][..]));
+    deque.push_front(0);
+    deque.push_back(7);
+    assert_eq!(deque, [0, 1, 2, 5, 6, 7]);
+}
+
+#[test]
+fn test_clone_copy() {
+    let deque = AltDeque::from(([1, 2, 3], [4, 5]));
+    let copy = deque.clone_copy();
+    assert_eq!(copy.capacity(), 5);
+    assert_eq!(copy.as_slices(), (&[1, 2, 3][..], &[4, 5][..]));
+
+    let mut deque = AltDeque::new();
+    deque.push_front(());
+    deque.push_front(());
+    deque.push_back(());
+    assert_eq!(deque.clone_copy().as_slices(), (&[(), ()][..], &[()][..]));
+    assert!(AltDeque::<i32>::new().clone_copy().is_empty());
+}
+
+#[test]
+fn test_copy_to_slice() {
+    let deque = AltDeque::from(([1, 2, 3], [4, 5]));
+    let vec: Vec<_> = deque.iter().copied().collect();
+    for start in 0..=5 {
+        for len in 0..=5 - start {
+            let mut buf = vec![0; len];
+            deque.copy_to_slice(start, &mut buf);
+            assert_eq!(buf, vec[start..start + len]);
+        }
+    }
+}
+
+#[test]
+fn test_copy_within() {
+    for front_len in 0..=6 {
+        for start in 0..=6 {
+            for end in start..=6 {
+                for dest in 0..=6 - (end - start) {
+                    let mut deque = split_deque(0..6, front_len);
+                    let mut vec: Vec<_> = (0..6).collect();
+                    deque.copy_within(start..end, dest);
+                    vec.copy_within(start..end, dest);
+                    assert_eq!(deque, vec);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic="dest is out of bounds"]
+fn test_copy_within_out_of_bounds() {
+    let mut deque = AltDeque::from(([1, 2, 3], [4, 5]));
+    deque.copy_within(1..3, 4);
+}
+
+#[test]
+#[should_panic="range end Excluded(6) should be <= length 5"]
+fn test_copy_to_slice_out_of_bounds() {
+    let deque = AltDeque::from(([1, 2, 3], [4, 5]));
+    deque.copy_to_slice(3, &mut [0; 3]);
+}
+
 #[test]
 fn test_trait_clone() {
     let deque = AltDeque::from([1, 2, 3]);
@@ -619,6 +1442,48 @@ fn test_trait_extend() {
     deque.push_back(2);
     deque.extend([3, 4, 5, 6, 7, 8, 9]);
     assert_eq!(deque.as_slices(), (&[1][..], &[2, 3, 4, 5, 6, 7, 8, 9][..]));
+
+    // sources with an exact size hint
+    let mut deque = AltDeque::from(([1], []));
+    deque.extend(vec![2, 3, 4]);
+    deque.extend(AltDeque::from(([5, 6], [7])));
+    assert!(deque.capacity() >= 7);
+    assert_eq!(deque, [1, 2, 3, 4, 5, 6, 7]);
+
+    // an iterator over a deque that was partially consumed from both ends
+    let mut iter = AltDeque::from(([0, 1, 2], [3, 4])).into_iter();
+    iter.next();
+    iter.next_back();
+    let mut other = AltDeque::from([-1]);
+    other.extend(iter);
+    assert_eq!(other, [-1, 1, 2, 3]);
+
+    // size hints that are too small
+    deque.extend((8..100).filter(|x| x % 2 == 0));
+    deque.extend(&[100, 101]);
+    assert!(deque.iter().copied().eq((1..8).chain((8..100).step_by(2)).chain([100, 101])));
+}
+
+#[test]
+fn test_extend_from_slice() {
+    let mut deque = AltDeque::from(([1], [2]));
+    deque.extend_from_slice(&[3, 4, 5]);
+    assert_eq!(deque.capacity(), 5);
+    assert_eq!(deque.as_slices(), (&[1][..], &[2, 3, 4, 5][..]));
+
+    let mut deque = AltDeque::new();
+    deque.extend_from_slice(&[String::from("a"), String::from("b")]);
+    assert_eq!(deque, ["a", "b"]);
+
+    let mut deque = AltDeque::from(([1], [2]));
+    deque.extend_from_slice_copy(&[3, 4, 5]);
+    deque.extend_from_slice_copy(&[]);
+    assert_eq!(deque.capacity(), 5);
+    assert_eq!(deque.as_slices(), (&[1][..], &[2, 3, 4, 5][..]));
+
+    let mut deque = AltDeque::new();
+    deque.extend_from_slice_copy(&[(); 3]);
+    assert_eq!(deque.len(), 3);
 }
 
 #[test]
@@ -643,6 +1508,32 @@ fn test_trait_from() {
     let deque = AltDeque::from(vec);
     assert!(deque.capacity() >= 6);
     assert_eq!(deque, [1, 2, 3, 4, 5, 6, 7]);
+
+    // vec_deque from deque
+    let cap = deque.capacity();
+    let mut vec_deque = VecDeque::from(deque);
+    assert_eq!(vec_deque.capacity(), cap);
+    assert_eq!(vec_deque, [1, 2, 3, 4, 5, 6, 7]);
+
+    // from wrapped vec_deque
+    vec_deque.pop_back();
+    vec_deque.push_front(0);
+    let deque = AltDeque::from(vec_deque);
+    assert_eq!(deque.capacity(), cap);
+    assert_eq!(deque, [0, 1, 2, 3, 4, 5, 6]);
+
+    // from boxed slice
+    let deque = AltDeque::from(vec![1, 2, 3].into_boxed_slice());
+    assert_eq!(deque.capacity(), 3);
+    assert_eq!(deque, [1, 2, 3]);
+
+    // from slices
+    let mut strings = [String::from("a"), String::from("b")];
+    let deque = AltDeque::from(&strings[..]);
+    assert_eq!(deque.capacity(), 2);
+    assert_eq!(deque, ["a", "b"]);
+    let deque = AltDeque::from(&mut strings[1..]);
+    assert_eq!(deque, ["b"]);
 }
 
 #[test]
@@ -698,6 +1589,22 @@ fn test_trait_partial_ord() {
     assert_eq!(AltDeque::from([2]).partial_cmp(&AltDeque::from([1])), Some(Ordering::Greater));
 }
 
+#[test]
+fn test_trait_partial_ord_slice() {
+    let deque = AltDeque::from(([1, 2], [3]));
+    assert!(deque < [1, 2, 4]);
+    assert!(deque > [1, 2]);
+    assert!(deque <= vec![1, 2, 3]);
+    assert!(deque >= &[0, 5][..]);
+    assert_eq!(deque.partial_cmp(&[1, 2, 3, 0]), Some(Ordering::Less));
+    assert_eq!(AltDeque::from([f64::NAN]).partial_cmp(&[1.0]), None);
+    let mut array = ArrayAltDeque::<i32, 4>::new();
+    array.push_front(1).unwrap();
+    array.push_back(2).unwrap();
+    assert!(array < [1, 3]);
+    assert!([1, 2, 3].into_iter().collect::<SmallAltDeque<_, 2>>() > vec![1, 2]);
+}
+
 #[test]
 fn test_trait_ord() {
     assert_eq!(AltDeque::from([1]).cmp(&AltDeque::from([1])), Ordering::Equal);
@@ -714,3 +1621,2020 @@ fn test_trait_partial_eq() {
     assert_eq!(AltDeque::from(([1, 2], [3])), AltDeque::from([1, 2, 3]));
     assert_ne!(AltDeque::from(([1, 2], [3])), AltDeque::from([1, 2, 4]));
 }
+
+#[test]
+fn test_trait_partial_eq_vec_deque() {
+    let mut vec_deque = VecDeque::from([2, 3]);
+    vec_deque.push_front(1);
+    assert_eq!(AltDeque::from(([1], [2, 3])), vec_deque);
+    assert_eq!(AltDeque::from(([1, 2, 3], [])), vec_deque);
+    assert_eq!(vec_deque, AltDeque::from(([], [1, 2, 3])));
+    assert_ne!(AltDeque::from(([1, 2], [4])), vec_deque);
+    assert_ne!(vec_deque, AltDeque::from(([1, 2], [3, 4])));
+    assert_eq!(AltDeque::from(([1.0], [2.0])), VecDeque::from([1.0, 2.0]));
+}
+
+#[test]
+fn test_array_push_pop() {
+    let mut deque = ArrayAltDeque::<i32, 4>::new();
+    assert_eq!(deque.capacity(), 4);
+    assert_eq!(deque.pop_front(), None);
+    assert_eq!(deque.pop_back(), None);
+    assert_eq!(deque.push_back(1), Ok(()));
+    assert_eq!(deque.push_back(2), Ok(()));
+    assert_eq!(deque.push_front(0), Ok(()));
+    assert_eq!(deque.push_front(-1), Ok(()));
+    assert!(deque.is_full());
+    assert_eq!(deque.push_back(3), Err(3));
+    assert_eq!(deque.push_front(-2), Err(-2));
+    assert_eq!(deque, [-1, 0, 1, 2]);
+    assert_eq!(deque.as_slices(), (&[-1, 0][..], &[1, 2][..]));
+
+    // popping from an empty stack moves half of the other one over
+    assert_eq!(deque.pop_front(), Some(-1));
+    assert_eq!(deque.pop_front(), Some(0));
+    assert_eq!(deque.pop_front(), Some(1));
+    assert_eq!(deque.as_slices(), (&[2][..], &[][..]));
+    assert_eq!(deque.push_back(3), Ok(()));
+    assert_eq!(deque.pop_back(), Some(3));
+    assert_eq!(deque.pop_back(), Some(2));
+    assert_eq!(deque.pop_back(), None);
+    assert!(deque.is_empty());
+}
+
+#[test]
+fn test_array_get() {
+    let mut deque = ArrayAltDeque::<i32, 8>::new();
+    for i in 0..3 {
+        deque.push_back(i).unwrap();
+        deque.push_front(-i - 1).unwrap();
+    }
+    assert_eq!(deque.len(), 6);
+    assert_eq!(deque.get(0), Some(&-3));
+    assert_eq!(deque.get(5), Some(&2));
+    assert_eq!(deque.get(6), None);
+    assert_eq!(deque.front(), Some(&-3));
+    assert_eq!(deque.back(), Some(&2));
+    *deque.back_mut().unwrap() = 5;
+    deque[0] = -5;
+    assert_eq!(deque, [-5, -2, -1, 0, 1, 5]);
+    assert_eq!(deque.iter().rev().copied().collect::<Vec<_>>(), vec![5, 1, 0, -1, -2, -5]);
+}
+
+#[test]
+#[should_panic="index out of bounds: the len is 1 but the index is 1"]
+fn test_array_index_out_of_bounds() {
+    let mut deque = ArrayAltDeque::<i32, 2>::new();
+    deque.push_back(1).unwrap();
+    let _ = deque[1];
+}
+
+#[test]
+fn test_array_truncate_and_clear() {
+    use std::rc::Rc;
+
+    let el_2 = Rc::new(2);
+    let el_4 = Rc::new(4);
+    let weak_2 = Rc::downgrade(&el_2);
+    let weak_4 = Rc::downgrade(&el_4);
+    let mut deque = ArrayAltDeque::<Rc<i32>, 6>::new();
+    deque.push_front(el_2).unwrap();
+    deque.push_front(Rc::new(1)).unwrap();
+    deque.push_back(Rc::new(3)).unwrap();
+    deque.push_back(el_4).unwrap();
+    deque.truncate(1);
+    assert_eq!(deque.as_slices(), (&[Rc::new(1)][..], &[][..]));
+    assert!(weak_2.upgrade().is_none());
+    assert!(weak_4.upgrade().is_none());
+
+    deque.clear();
+    assert!(deque.is_empty());
+    assert_eq!(deque.push_back(Rc::new(5)), Ok(()));
+    assert_eq!(deque, [Rc::new(5)]);
+}
+
+#[test]
+fn test_array_reverse() {
+    for front_len in 0..=5 {
+        for back_len in 0..=5 - front_len {
+            let mut deque = ArrayAltDeque::<usize, 5>::new();
+            (0..front_len).rev().for_each(|i| deque.push_front(i).unwrap());
+            (front_len..front_len + back_len).for_each(|i| deque.push_back(i).unwrap());
+            deque.reverse();
+            assert!(deque.iter().copied().eq((0..front_len + back_len).rev()));
+            assert_eq!(deque.as_slices().0.len(), back_len);
+        }
+    }
+}
+
+#[test]
+fn test_array_trait_drop() {
+    use std::rc::Rc;
+
+    let el = Rc::new(1);
+    let weak = Rc::downgrade(&el);
+    {
+        let mut deque = ArrayAltDeque::<Rc<i32>, 3>::new();
+        deque.push_front(el).unwrap();
+        deque.push_back(Rc::new(2)).unwrap();
+    }
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn test_array_trait_clone_and_eq() {
+    let mut deque = ArrayAltDeque::<i32, 4>::new();
+    deque.push_front(1).unwrap();
+    deque.push_back(2).unwrap();
+    let clone = deque.clone();
+    assert_eq!(clone, deque);
+    assert_eq!(clone, vec![1, 2]);
+    assert_eq!(clone.cmp(&deque), Ordering::Equal);
+    deque.pop_back();
+    assert_ne!(clone, deque);
+    assert_eq!(deque.partial_cmp(&clone), Some(Ordering::Less));
+}
+
+#[test]
+fn test_small_spill() {
+    let mut deque = SmallAltDeque::<i32, 2>::new();
+    deque.push_back(1);
+    deque.push_front(0);
+    assert!(!deque.spilled());
+    assert_eq!(deque.capacity(), 2);
+    deque.push_front(-1);
+    assert!(deque.spilled());
+    assert!(deque.capacity() >= 3);
+    deque.push_back(2);
+    assert_eq!(deque, [-1, 0, 1, 2]);
+    assert_eq!(deque.pop_back(), Some(2));
+    assert_eq!(deque.pop_front(), Some(-1));
+    deque.shrink_to_fit();
+    assert!(!deque.spilled());
+    assert_eq!(deque, [0, 1]);
+    assert_eq!(deque.front(), Some(&0));
+    assert_eq!(deque.back(), Some(&1));
+}
+
+#[test]
+fn test_small_from_iter_and_drop() {
+    use std::rc::Rc;
+
+    let el = Rc::new(0);
+    let weak = Rc::downgrade(&el);
+    let mut deque: SmallAltDeque<_, 4> = [el, Rc::new(1)].into_iter().collect();
+    assert!(!deque.spilled());
+    deque.extend([Rc::new(2), Rc::new(3), Rc::new(4)]);
+    assert!(deque.spilled());
+    assert_eq!(deque.len(), 5);
+    assert_eq!(*deque[4], 4);
+    assert_eq!(deque.clone(), deque);
+    drop(deque);
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn test_rt_push_pop() {
+    let mut deque = RtAltDeque::new();
+    let mut model = VecDeque::new();
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    // phases that push more than they pop run through rebuilds, the others pop across the
+    // bottoms of the stacks, both while a rebuild runs and while none does
+    for i in 0..20_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let push_bias = if (i / 1000) % 3 == 2 { 3 } else { 6 };
+        match state % 10 {
+            op if op < push_bias && op % 2 == 0 => {
+                deque.push_front(i);
+                model.push_front(i);
+            }
+            op if op < push_bias => {
+                deque.push_back(i);
+                model.push_back(i);
+            }
+            op if op % 2 == 0 => assert_eq!(deque.pop_front(), model.pop_front()),
+            _ => assert_eq!(deque.pop_back(), model.pop_back()),
+        }
+        assert_eq!(deque.len(), model.len());
+        assert_eq!(deque.front(), model.front());
+        assert_eq!(deque.back(), model.back());
+        let index = (state >> 32) as usize % (model.len() + 1);
+        assert_eq!(deque.get(index), model.get(index));
+        if i % 97 == 0 {
+            assert!(deque.iter().eq(model.iter()));
+            assert!(deque.iter().rev().eq(model.iter().rev()));
+        }
+    }
+    while let Some(value) = model.pop_front() {
+        assert_eq!(deque.pop_front(), Some(value));
+    }
+    assert_eq!(deque.pop_front(), None);
+    assert_eq!(deque.pop_back(), None);
+}
+
+#[test]
+fn test_rt_pop_across_bottom() {
+    // everything goes onto the back stack and is popped from the front
+    let mut deque = RtAltDeque::new();
+    for round in 0..10 {
+        deque.extend(0..100);
+        for i in 0..100 {
+            assert_eq!(deque.pop_front(), Some(i), "round {}", round);
+        }
+        assert!(deque.is_empty());
+    }
+    // the freed slots are reused, so the buffer stays small
+    assert!(deque.capacity() < 1000);
+
+    let mut deque = RtAltDeque::with_capacity(8);
+    for i in 0..8 {
+        deque.push_front(i);
+    }
+    let cap = deque.capacity();
+    assert_eq!(deque.pop_back(), Some(0));
+    assert_eq!(deque.pop_back(), Some(1));
+    deque.push_back(10);
+    assert!(deque.iter().copied().eq([7, 6, 5, 4, 3, 2, 10]));
+    assert_eq!(deque.capacity(), cap);
+
+    let mut deque = RtAltDeque::new();
+    deque.extend(0..1000);
+    for i in 0..1000 {
+        assert_eq!(deque[0], i);
+        deque[0] += 1;
+        assert_eq!(deque.pop_front(), Some(i + 1));
+    }
+}
+
+#[test]
+fn test_rt_traits() {
+    let mut deque: RtAltDeque<_> = (0..100).collect();
+    deque.extend(100..200);
+    for value in deque.iter_mut().rev().take(100) {
+        *value *= 2;
+    }
+    for value in &mut deque {
+        *value += 1;
+    }
+    let expected: Vec<_> = (0..100).chain((100..200).map(|x| x * 2)).map(|x| x + 1).collect();
+    assert!(deque.iter().copied().eq(expected.iter().copied()));
+    assert_eq!(deque.iter().len(), 200);
+    assert_eq!((&deque).into_iter().nth(150), Some(&expected[150]));
+    let clone = deque.clone();
+    assert_eq!(clone, deque);
+    deque[3] = 0;
+    assert_ne!(clone, deque);
+    assert_eq!(deque, {
+        let mut other = clone.clone();
+        other[3] = 0;
+        other
+    });
+    assert_eq!(format!("{:?}", (1..4).collect::<RtAltDeque<_>>()), "[1, 2, 3]");
+    assert_eq!(RtAltDeque::<i32>::default(), RtAltDeque::new());
+
+    let mut deque = RtAltDeque::new();
+    for _ in 0..10_000 {
+        deque.push_front(());
+    }
+    assert_eq!(deque.len(), 10_000);
+    assert_eq!(deque.pop_back(), Some(()));
+    assert_eq!(deque.iter().count(), 9_999);
+}
+
+#[test]
+#[should_panic = "index out of bounds: the len is 3 but the index is 3"]
+fn test_rt_index_out_of_bounds() {
+    let deque: RtAltDeque<_> = (0..3).collect();
+    let _ = deque[3];
+}
+
+#[test]
+fn test_rt_drop() {
+    use std::rc::Rc;
+
+    let el = Rc::new(0);
+    let mut deque = RtAltDeque::new();
+    // the drops happen in the middle of rebuilds, with the elements spread over both buffers
+    for len in [3, 50, 51, 200] {
+        for i in 0..len {
+            if i % 2 == 0 {
+                deque.push_back(el.clone());
+            } else {
+                deque.push_front(el.clone());
+            }
+        }
+        deque.pop_back();
+        deque.pop_front();
+        assert_eq!(Rc::strong_count(&el), len - 1);
+        deque.clear();
+        assert_eq!(Rc::strong_count(&el), 1);
+        deque.extend((0..len).map(|_| el.clone()));
+        assert_eq!(Rc::strong_count(&el), len + 1);
+        drop(deque);
+        assert_eq!(Rc::strong_count(&el), 1);
+        deque = RtAltDeque::new();
+    }
+}
+
+#[test]
+fn test_spsc_push_pop() {
+    let (mut producer, mut consumer) = spsc::channel(3);
+    assert_eq!(consumer.pop(), None);
+    for round in 0..5 {
+        assert_eq!(producer.push(round), Ok(()));
+        assert_eq!(producer.push(round + 1), Ok(()));
+        assert_eq!(producer.push(round + 2), Ok(()));
+        assert_eq!(producer.push(round + 3), Err(round + 3));
+        assert!(producer.is_full());
+        assert_eq!(consumer.len(), 3);
+        assert_eq!(consumer.pop(), Some(round));
+        assert_eq!(producer.push(round + 3), Ok(()));
+        assert_eq!(consumer.peek_with(|&x| x), Some(round + 1));
+        assert_eq!(consumer.pop(), Some(round + 1));
+        assert_eq!(consumer.pop(), Some(round + 2));
+        assert_eq!(consumer.pop(), Some(round + 3));
+        assert_eq!(consumer.pop(), None);
+        assert!(producer.is_empty());
+    }
+
+    let (mut producer, mut consumer) = spsc::channel(0);
+    assert_eq!(producer.push(1), Err(1));
+    assert_eq!(consumer.pop(), None);
+}
+
+#[test]
+fn test_spsc_drop() {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let el = Arc::new(1);
+    let (mut producer, mut consumer) = spsc::channel(4);
+    producer.push(el.clone()).unwrap();
+    producer.push(el.clone()).unwrap();
+    producer.push(el.clone()).unwrap();
+    drop(consumer.pop());
+    drop(producer);
+    assert_eq!(Arc::strong_count(&el), 3);
+    drop(consumer);
+    assert_eq!(Arc::strong_count(&el), 1);
+
+    // the queue does not need Send for single threaded use
+    let (mut producer, consumer) = spsc::channel(1);
+    producer.push(Rc::new(1)).unwrap();
+    drop((producer, consumer));
+}
+
+#[test]
+fn test_spsc_threads() {
+    use std::thread;
+
+    const COUNT: usize = 100_000;
+    let (mut producer, mut consumer) = spsc::channel(7);
+    let handle = thread::spawn(move || {
+        for i in 0..COUNT {
+            let mut value = Box::new(i);
+            while let Err(v) = producer.push(value) {
+                value = v;
+                thread::yield_now();
+            }
+        }
+    });
+    let mut expected = 0;
+    while expected < COUNT {
+        match consumer.pop() {
+            Some(value) => {
+                assert_eq!(*value, expected);
+                expected += 1;
+            }
+            None => thread::yield_now(),
+        }
+    }
+    handle.join().unwrap();
+    assert_eq!(consumer.pop(), None);
+}
+
+#[test]
+fn test_min_max_queue() {
+    let mut queue = MinMaxQueue::new();
+    let mut model = VecDeque::new();
+    let mut x = 12345u32;
+    for step in 0..2000 {
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        let value = x % 100;
+        match x % 7 {
+            0 | 1 => {
+                queue.push_back(value);
+                model.push_back(value);
+            }
+            2 => {
+                queue.push_front(value);
+                model.push_front(value);
+            }
+            3 | 4 => assert_eq!(queue.pop_front(), model.pop_front()),
+            5 => assert_eq!(queue.pop_back(), model.pop_back()),
+            _ if step % 100 == 0 => {
+                queue.clear();
+                model.clear();
+            }
+            _ => {}
+        }
+        assert!(queue.iter().eq(&model));
+        assert_eq!(queue.min(), model.iter().min());
+        assert_eq!(queue.max(), model.iter().max());
+        let clone = queue.clone();
+        assert_eq!(clone.min(), queue.min());
+        assert_eq!(clone.max(), queue.max());
+    }
+}
+
+#[test]
+fn test_aggregate_deque() {
+    // string concatenation is associative but not commutative
+    let mut deque = AggregateDeque::new(|a: &String, b: &String| a.clone() + b);
+    let mut model = VecDeque::new();
+    let mut x = 54321u32;
+    for _ in 0..2000 {
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        let value = char::from(b'a' + (x % 26) as u8).to_string();
+        match x % 6 {
+            0 | 1 => {
+                deque.push_back(value.clone());
+                model.push_back(value);
+            }
+            2 => {
+                deque.push_front(value.clone());
+                model.push_front(value);
+            }
+            3 | 4 => assert_eq!(deque.pop_front(), model.pop_front()),
+            _ => assert_eq!(deque.pop_back(), model.pop_back()),
+        }
+        assert!(deque.iter().eq(&model));
+        let expected = if model.is_empty() { None } else { Some(model.iter().cloned().collect::<String>()) };
+        assert_eq!(deque.fold_all(), expected);
+        assert_eq!(deque.clone().fold_all(), expected);
+    }
+}
+
+#[test]
+fn test_bounded_deque() {
+    let mut deque = BoundedAltDeque::new(5);
+    for i in 0..5 {
+        assert_eq!(deque.push_back(i), Ok(()));
+        assert!(deque.capacity() <= 5);
+    }
+    assert!(deque.is_full());
+    assert_eq!(deque.push_back(5), Err(5));
+    assert_eq!(deque.push_front(-1), Err(-1));
+    assert_eq!(deque.capacity(), 5);
+    assert_eq!(deque, [0, 1, 2, 3, 4]);
+
+    assert_eq!(deque.pop_back(), Some(4));
+    assert_eq!(deque.push_front(-1), Ok(()));
+    assert_eq!(deque, [-1, 0, 1, 2, 3]);
+    assert_eq!(deque.capacity(), 5);
+
+    let mut deque = BoundedAltDeque::new(0);
+    assert_eq!(deque.push_back(String::from("a")), Err(String::from("a")));
+    assert_eq!(deque.capacity(), 0);
+}
+
+#[test]
+fn test_bounded_deque_overwrite() {
+    let mut deque = BoundedAltDeque::new(3);
+    let mut model = VecDeque::new();
+    for i in 0..100 {
+        let evicted = if i % 3 == 0 {
+            model.push_front(i);
+            (model.len() > 3).then(|| model.pop_back().unwrap())
+        } else {
+            model.push_back(i);
+            (model.len() > 3).then(|| model.pop_front().unwrap())
+        };
+        if i % 3 == 0 {
+            assert_eq!(deque.push_front_overwrite(i), evicted);
+        } else {
+            assert_eq!(deque.push_back_overwrite(i), evicted);
+        }
+        assert!(deque.iter().eq(&model));
+        assert!(deque.capacity() <= 3);
+    }
+
+    let mut deque = BoundedAltDeque::new(0);
+    assert_eq!(deque.push_back_overwrite(1), Some(1));
+    assert_eq!(deque.push_front_overwrite(2), Some(2));
+    assert!(deque.is_empty());
+}
+
+#[test]
+fn test_sync_queue() {
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    let queue = Arc::new(sync::SyncAltQueue::new(3));
+    let producers: Vec<_> = (0..4)
+        .map(|p| {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || (0..250).for_each(|i| queue.push(p * 1000 + i)))
+        })
+        .collect();
+    let mut received: Vec<_> = (0..1000).map(|_| queue.pop()).collect();
+    producers.into_iter().for_each(|p| p.join().unwrap());
+    // every producer's elements arrive in order
+    for p in 0..4 {
+        let own: Vec<_> = received.iter().filter(|&&x| x / 1000 == p).copied().collect();
+        assert_eq!(own, (p * 1000..p * 1000 + 250).collect::<Vec<_>>());
+    }
+    received.sort();
+    received.dedup();
+    assert_eq!(received.len(), 1000);
+
+    assert!(queue.is_empty());
+    assert_eq!(queue.pop_timeout(Duration::from_millis(1)), None);
+    (0..3).for_each(|i| queue.push(i));
+    assert_eq!(queue.try_push(3), Err(3));
+    assert_eq!(queue.push_timeout(3, Duration::from_millis(1)), Err(3));
+    assert_eq!(queue.try_pop(), Some(0));
+    assert_eq!(queue.push_timeout(3, Duration::from_millis(1)), Ok(()));
+    assert_eq!(queue.len(), 3);
+}
+
+#[test]
+fn test_send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<AltDeque<i32>>();
+    assert_send_sync::<RtAltDeque<i32>>();
+    assert_send_sync::<crate::IntoIter<i32>>();
+    assert_send_sync::<sync::SyncAltQueue<i32>>();
+    assert_send_sync::<concurrent::TwoLockQueue<i32>>();
+}
+
+#[cfg(feature = "futures")]
+#[test]
+fn test_stream() {
+    use futures_core::{FusedStream, Stream};
+    use std::pin::Pin;
+    use std::task::{Context, Poll, Waker};
+
+    fn collect<S: Stream + Unpin>(mut stream: S) -> Vec<S::Item> {
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut items = Vec::new();
+        while let Poll::Ready(Some(item)) = Pin::new(&mut stream).poll_next(&mut cx) {
+            items.push(item);
+        }
+        items
+    }
+
+    let mut deque = AltDeque::from(([1, 2], [3, 4]));
+    let drain = deque.drain(1..3);
+    assert_eq!(Stream::size_hint(&drain), (2, Some(2)));
+    assert_eq!(collect(drain), [2, 3]);
+    assert_eq!(deque, [1, 4]);
+
+    let mut iter = deque.into_iter();
+    assert!(!iter.is_terminated());
+    assert_eq!(collect(&mut iter), [1, 4]);
+    assert!(iter.is_terminated());
+}
+
+#[cfg(feature = "futures-io")]
+#[test]
+fn test_futures_io() {
+    use futures_io::{AsyncRead, AsyncWrite};
+    use std::pin::Pin;
+    use std::task::{Context, Poll, Waker};
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut deque = AltDeque::from((*b"ab", *b"c"));
+    let written = Pin::new(&mut deque).poll_write(&mut cx, b"de");
+    assert!(matches!(written, Poll::Ready(Ok(2))));
+    assert!(Pin::new(&mut deque).poll_flush(&mut cx).is_ready());
+
+    let mut buf = [0; 4];
+    let read = Pin::new(&mut deque).poll_read(&mut cx, &mut buf);
+    assert!(matches!(read, Poll::Ready(Ok(4))));
+    assert_eq!(&buf, b"abcd");
+    let read = Pin::new(&mut deque).poll_read(&mut cx, &mut buf);
+    assert!(matches!(read, Poll::Ready(Ok(1))));
+    assert_eq!(buf[0], b'e');
+    let read = Pin::new(&mut deque).poll_read(&mut cx, &mut buf);
+    assert!(matches!(read, Poll::Ready(Ok(0))));
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_tokio_io() {
+    use std::pin::Pin;
+    use std::task::{Context, Poll, Waker};
+    use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut deque = AltDeque::from((*b"ab", *b"c"));
+    let written = Pin::new(&mut deque).poll_write(&mut cx, b"de");
+    assert!(matches!(written, Poll::Ready(Ok(2))));
+    assert!(Pin::new(&mut deque).poll_shutdown(&mut cx).is_ready());
+
+    let mut buf = [0; 4];
+    let mut read_buf = ReadBuf::new(&mut buf);
+    assert!(Pin::new(&mut deque).poll_read(&mut cx, &mut read_buf).is_ready());
+    assert_eq!(read_buf.filled(), b"abcd");
+    read_buf.clear();
+    assert!(Pin::new(&mut deque).poll_read(&mut cx, &mut read_buf).is_ready());
+    assert_eq!(read_buf.filled(), b"e");
+    assert!(deque.is_empty());
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_bytemuck() {
+    use bytemuck::PodCastError;
+
+    let mut deque = AltDeque::from(([1_u32, 2], [3]));
+    deque.reserve(10);
+    let (front, back) = deque.as_byte_slices();
+    assert_eq!(front, [1_u32.to_ne_bytes(), 2_u32.to_ne_bytes()].concat());
+    assert_eq!(back, 3_u32.to_ne_bytes());
+
+    let capacity = deque.capacity();
+    let mut deque = deque.try_cast_deque::<i32>().unwrap();
+    assert_eq!(deque, [1, 2, 3]);
+    assert_eq!(deque.capacity(), capacity);
+    deque.push_front(0);
+    assert_eq!(deque.pop_back(), Some(3));
+
+    let deque = deque.try_cast_deque::<u16>().unwrap_err();
+    assert_eq!(deque, [0, 1, 2]);
+    assert!(AltDeque::from([0_u8; 4]).try_cast_deque::<[u8; 4]>().is_err());
+
+    let bytes = [0_u32.to_ne_bytes(), 7_u32.to_ne_bytes()].concat();
+    assert_eq!(AltDeque::<u32>::from_bytes(&bytes).unwrap(), [0, 7]);
+    let unaligned = AltDeque::<u32>::from_bytes(&bytes[1..5]).unwrap();
+    assert_eq!(unaligned, [u32::from_ne_bytes([0, 0, 0, bytes[4]])]);
+    assert_eq!(AltDeque::<u32>::from_bytes(&[]).unwrap(), []);
+    let slop = AltDeque::<u32>::from_bytes(&bytes[1..]);
+    assert_eq!(slop, Err(PodCastError::OutputSliceWouldHaveSlop));
+    assert_eq!(AltDeque::<()>::from_bytes(&[]), Err(PodCastError::SizeMismatch));
+}
+
+#[test]
+fn test_buffer_deque() {
+    let mut buf = [const { MaybeUninit::uninit() }; 4];
+    let mut deque = BufferDeque::new(&mut buf);
+    assert_eq!(deque.capacity(), 4);
+    assert_eq!(deque.pop_front(), None);
+    assert_eq!(deque.pop_back(), None);
+
+    for i in 0..4 {
+        assert_eq!(deque.push_back(Box::new(i)), Ok(()));
+    }
+    assert!(deque.is_full());
+    assert_eq!(deque.push_front(Box::new(4)), Err(Box::new(4)));
+    assert_eq!(deque.pop_front(), Some(Box::new(0)));
+    assert_eq!(deque.as_slices(), (&[Box::new(1), Box::new(2)][..], &[Box::new(3)][..]));
+    deque.push_front(Box::new(5)).unwrap();
+    assert_eq!(deque, [Box::new(5), Box::new(1), Box::new(2), Box::new(3)]);
+    assert_eq!(deque.pop_back(), Some(Box::new(3)));
+    deque.reverse();
+    assert_eq!(deque, [Box::new(2), Box::new(1), Box::new(5)]);
+    deque.truncate(2);
+    assert_eq!(deque[1], Box::new(1));
+    // the remaining boxes are freed when the deque is dropped
+    drop(deque);
+
+    // the buffer can be reused
+    let mut deque = BufferDeque::new(&mut buf);
+    deque.push_back(Box::new(6)).unwrap();
+    assert_eq!(deque.back(), Some(&Box::new(6)));
+
+    let mut empty: [MaybeUninit<u8>; 0] = [];
+    let mut deque = BufferDeque::new(&mut empty);
+    assert_eq!(deque.push_back(1), Err(1));
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+fn test_ffi() {
+    use crate::ffi::*;
+    use std::{ptr, slice};
+
+    unsafe {
+        let deque = altdeque_u8_new();
+        let mut byte = 0;
+        assert!(!altdeque_u8_pop_front(deque, &mut byte));
+        assert!(!altdeque_u8_pop_back(deque, &mut byte));
+        for i in 0..4 {
+            altdeque_u8_push_back(deque, i);
+        }
+        altdeque_u8_push_front(deque, 9);
+        assert!(altdeque_u8_pop_back(deque, &mut byte));
+        assert_eq!(byte, 3);
+        assert!(altdeque_u8_pop_front(deque, &mut byte));
+        assert_eq!(byte, 9);
+        assert!(altdeque_u8_pop_front(deque, &mut byte));
+        assert_eq!(byte, 0);
+        assert_eq!(altdeque_u8_len(deque), 2);
+
+        let slices = altdeque_u8_as_slices(deque);
+        let front = slice::from_raw_parts(slices.front, slices.front_len);
+        let back = slice::from_raw_parts(slices.back, slices.back_len);
+        assert_eq!([front, back].concat(), [1, 2]);
+
+        altdeque_u8_free(deque);
+        altdeque_u8_free(ptr::null_mut());
+    }
+}
+
+#[test]
+fn test_dual_stack_deque() {
+    let mut deque = DualStackDeque::from(vec![1, 2, 3, 4, 5]);
+    let mut model: VecDeque<_> = (1..=5).collect();
+    for i in 0..40 {
+        match i % 5 {
+            0 | 3 => assert_eq!(deque.pop_front(), model.pop_front()),
+            1 => assert_eq!(deque.pop_back(), model.pop_back()),
+            2 => {
+                deque.push_front(i);
+                model.push_front(i);
+            }
+            _ => {
+                deque.push_back(i);
+                model.push_back(i);
+            }
+        }
+        assert_eq!(deque.len(), model.len());
+        assert!(deque.iter().eq(&model));
+        assert_eq!(deque.front(), model.front());
+        assert_eq!(deque.back(), model.back());
+    }
+
+    deque.clear();
+    deque.push_front(0);
+    deque.prepend_vec(vec![-2, -1]);
+    deque.append_vec(vec![1, 2]);
+    deque.prepend_vec(vec![-3]);
+    deque.append_vec(vec![3]);
+    assert_eq!(deque, [-3, -2, -1, 0, 1, 2, 3]);
+    deque[3] = 10;
+    assert_eq!(deque.get(3), Some(&10));
+    assert_eq!(deque.get(7), None);
+    assert_eq!(Vec::from(deque.clone()), [-3, -2, -1, 10, 1, 2, 3]);
+
+    // the back stack is handed back without copying
+    let vec = Vec::with_capacity(10);
+    let ptr = vec.as_ptr();
+    let mut deque = DualStackDeque::from(vec);
+    deque.extend([1, 2]);
+    let vec = Vec::from(deque);
+    assert_eq!(vec, [1, 2]);
+    assert_eq!(vec.as_ptr(), ptr);
+}
+
+#[test]
+fn test_arena_deque() {
+    use std::rc::Rc;
+
+    /// Leaks every buffer and counts how many were handed out.
+    struct Leak(Cell<usize>);
+
+    impl Arena for Leak {
+        fn alloc_buffer<T>(&self, capacity: usize) -> &mut [MaybeUninit<T>] {
+            self.0.set(self.0.get() + 1);
+            Box::leak((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+        }
+    }
+
+    let arena = Leak(Cell::new(0));
+    let el = Rc::new(());
+    let mut deque = ArenaAltDeque::new_in(&arena);
+    assert_eq!(arena.0.get(), 0);
+    for i in 0..10 {
+        // grow from both ends so that both stacks are moved to the new buffers
+        if i % 2 == 0 {
+            deque.push_back((i, el.clone()));
+        } else {
+            deque.push_front((i, el.clone()));
+        }
+    }
+    // 4, 8 and 16
+    assert_eq!(arena.0.get(), 3);
+    assert_eq!(deque.capacity(), 16);
+    assert!(deque.iter().map(|x| x.0).eq([9, 7, 5, 3, 1, 0, 2, 4, 6, 8]));
+    assert_eq!(deque.pop_back().unwrap().0, 8);
+    assert_eq!(Rc::strong_count(&el), 10);
+    let clone = deque.clone();
+    assert_eq!(clone.len(), 9);
+    assert_eq!(arena.0.get(), 4);
+    drop(clone);
+    deque.truncate(4);
+    assert_eq!(Rc::strong_count(&el), 5);
+    drop(deque);
+    assert_eq!(Rc::strong_count(&el), 1);
+
+    let mut zsts = ArenaAltDeque::new_in(&arena);
+    zsts.extend([(), (), ()]);
+    assert_eq!(zsts.len(), 3);
+    assert_eq!(arena.0.get(), 4);
+}
+
+#[cfg(feature = "bumpalo")]
+#[test]
+fn test_bump_deque() {
+    use bumpalo::Bump;
+
+    let bump = Bump::new();
+    let mut strings: BumpAltDeque<'_, String> = BumpAltDeque::new_in(&bump);
+    let mut numbers = BumpAltDeque::with_capacity_in(2, &bump);
+    for i in 0..100 {
+        strings.push_back(i.to_string());
+        strings.push_front(i.to_string());
+    }
+    assert_eq!(strings.len(), 200);
+    assert_eq!(strings.pop_front().as_deref(), Some("99"));
+    assert_eq!(strings.pop_back().as_deref(), Some("99"));
+    assert_eq!(strings[0], "98");
+    numbers.extend([1, 2, 3]);
+    assert!(numbers.capacity() >= 3);
+    assert_eq!(numbers, [1, 2, 3]);
+    assert_eq!(BumpAltDeque::<u8>::new_in(&bump).capacity(), 0);
+}
+
+#[test]
+fn test_memory_usage() {
+    let mut deque = AltDeque::with_capacity(8);
+    assert_eq!(deque.allocated_bytes(), 64);
+    assert_eq!(deque.spare_capacity_len(), 8);
+    deque.extend([1_u64, 2, 3]);
+    deque.push_front(0);
+    assert_eq!(deque.spare_capacity_len(), 4);
+    deque.shrink_to_fit();
+    assert_eq!(deque.allocated_bytes(), 32);
+    assert_eq!(deque.spare_capacity_len(), 0);
+
+    let mut zsts = AltDeque::new();
+    zsts.push_back(());
+    assert_eq!(zsts.allocated_bytes(), 0);
+    assert_eq!(zsts.spare_capacity_len(), usize::MAX - 1);
+}
+
+#[test]
+fn test_extend_front() {
+    use crate::ExtendFront;
+
+    let mut deque = AltDeque::from(([1, 2], [3]));
+    deque.extend_front([0, -1]);
+    deque.extend_front((-3..-1).rev());
+    // an iterator without a size hint has to grow while extending
+    deque.extend_front((-9..-3).rev().filter(|x| x % 2 == 0));
+    deque.extend_front(&[-10]);
+    assert_eq!(deque, [-10, -8, -6, -4, -3, -2, -1, 0, 1, 2, 3]);
+
+    let mut small: SmallAltDeque<_, 2> = SmallAltDeque::new();
+    small.extend_front([1]);
+    assert!(!small.spilled());
+    small.extend_front([2, 3]);
+    assert_eq!(small, [3, 2, 1]);
+
+    let mut dual = DualStackDeque::from(vec![2]);
+    dual.extend_front([1, 0]);
+    assert_eq!(dual, [0, 1, 2]);
+    assert_eq!(dual.pop_back(), Some(2));
+    assert_eq!(dual.pop_back(), Some(1));
+}
+
+#[test]
+fn test_chunks_iter() {
+    for front_len in 0..=7 {
+        let deque = split_deque(0..7, front_len);
+        assert_eq!(deque.as_slices().0.len(), front_len);
+        let vec: Vec<_> = (0..7).collect();
+        for size in 1..=8 {
+            let join = |(a, b): (&[usize], &[usize])| {
+                assert!(b.is_empty() || !a.is_empty());
+                [a, b].concat()
+            };
+            let chunks: Vec<_> = deque.chunks(size).map(join).collect();
+            let expected: Vec<_> = vec.chunks(size).map(<[_]>::to_vec).collect();
+            assert_eq!(chunks, expected);
+            let chunks: Vec<_> = deque.chunks(size).rev().map(join).collect();
+            assert_eq!(chunks, expected.into_iter().rev().collect::<Vec<_>>());
+
+            let rchunks: Vec<_> = deque.rchunks(size).map(join).collect();
+            let expected: Vec<_> = vec.rchunks(size).map(<[_]>::to_vec).collect();
+            assert_eq!(rchunks, expected);
+            let rchunks: Vec<_> = deque.rchunks(size).rev().map(join).collect();
+            assert_eq!(rchunks, expected.into_iter().rev().collect::<Vec<_>>());
+
+            assert_eq!(deque.chunks(size).len(), vec.chunks(size).len());
+        }
+    }
+    assert_eq!(AltDeque::<i32>::new().chunks(3).next(), None);
+}
+
+#[test]
+#[should_panic = "chunk size must be non-zero"]
+fn test_chunks_zero() {
+    AltDeque::from([1]).chunks(0);
+}
+
+#[test]
+fn test_windows() {
+    for front_len in 0..=6 {
+        let deque = split_deque(0..6, front_len);
+        let vec: Vec<_> = (0..6).collect();
+        for size in 1..=7 {
+            let join = |(a, b): (&[usize], &[usize])| [a, b].concat();
+            let windows: Vec<_> = deque.windows(size).map(join).collect();
+            let expected: Vec<_> = vec.windows(size).map(<[_]>::to_vec).collect();
+            assert_eq!(windows, expected);
+            let windows: Vec<_> = deque.windows(size).rev().map(join).collect();
+            assert_eq!(windows, expected.into_iter().rev().collect::<Vec<_>>());
+            assert_eq!(deque.windows(size).len(), vec.windows(size).len());
+            assert_eq!(deque.windows(size).nth(2).map(join), vec.windows(size).nth(2).map(<[_]>::to_vec));
+        }
+    }
+}
+
+#[test]
+#[should_panic = "window size must be non-zero"]
+fn test_windows_zero() {
+    AltDeque::from([1]).windows(0);
+}
+
+#[test]
+fn test_array_chunks_windows() {
+    use std::borrow::Cow;
+
+    let deque = AltDeque::from(([0, 1, 2], [3, 4, 5, 6]));
+    let chunks: Vec<_> = deque.array_chunks::<3>().collect();
+    assert_eq!(chunks, [Cow::Borrowed(&[0, 1, 2]), Cow::Borrowed(&[3, 4, 5])]);
+    let chunks: Vec<_> = deque.array_chunks::<2>().rev().collect();
+    assert_eq!(chunks, [Cow::Borrowed(&[4, 5]), Cow::Owned([2, 3]), Cow::Borrowed(&[0, 1])]);
+    assert_eq!(deque.array_chunks::<8>().next(), None);
+    assert_eq!(deque.array_chunks::<4>().len(), 1);
+
+    let windows: Vec<_> = deque.array_windows::<3>().collect();
+    assert_eq!(windows.len(), 5);
+    assert!(matches!(windows[0], Cow::Borrowed(&[0, 1, 2])));
+    assert!(matches!(windows[1], Cow::Owned([1, 2, 3])));
+    assert!(matches!(windows[2], Cow::Owned([2, 3, 4])));
+    assert!(matches!(windows[3], Cow::Borrowed(&[3, 4, 5])));
+    assert_eq!(deque.array_windows::<7>().next().as_deref(), Some(&[0, 1, 2, 3, 4, 5, 6]));
+    assert_eq!(deque.array_windows::<8>().next(), None);
+}
+
+#[test]
+fn test_split() {
+    let vec = [0, 1, 0, 0, 2, 3, 0, 4, 0];
+    for front_len in 0..=vec.len() {
+        let deque = split_deque(vec, front_len);
+        assert_eq!(deque.as_slices().0.len(), front_len);
+        let is_zero = |x: &i32| *x == 0;
+        let parts: Vec<_> = deque.split(is_zero).map(|(a, b)| [a, b].concat()).collect();
+        let expected: Vec<_> = vec.split(is_zero).map(<[_]>::to_vec).collect();
+        assert_eq!(parts, expected);
+        let parts: Vec<_> = deque.split_inclusive(is_zero).map(|(a, b)| [a, b].concat()).collect();
+        let expected: Vec<_> = vec.split_inclusive(is_zero).map(<[_]>::to_vec).collect();
+        assert_eq!(parts, expected);
+    }
+
+    let empty = AltDeque::<i32>::new();
+    assert_eq!(empty.split(|_| true).count(), 1);
+    assert_eq!(empty.split_inclusive(|_| true).count(), 0);
+}
+
+#[test]
+fn test_chunk_by() {
+    let vec = [1, 1, 2, 3, 3, 3, 2, 2, 1];
+    for front_len in 0..=vec.len() {
+        let deque = split_deque(vec, front_len);
+        assert_eq!(deque.as_slices().0.len(), front_len);
+        let join = |(a, b): (&[i32], &[i32])| [a, b].concat();
+        for pred in [|a: &i32, b: &i32| a == b, |a: &i32, b: &i32| a <= b] {
+            let runs: Vec<_> = deque.chunk_by(pred).map(join).collect();
+            let expected: Vec<_> = vec.chunk_by(pred).map(<[_]>::to_vec).collect();
+            assert_eq!(runs, expected);
+            let runs: Vec<_> = deque.chunk_by(pred).rev().map(join).collect();
+            let expected: Vec<_> = vec.chunk_by(pred).rev().map(<[_]>::to_vec).collect();
+            assert_eq!(runs, expected);
+        }
+    }
+    assert_eq!(AltDeque::<i32>::new().chunk_by(|_, _| true).next(), None);
+}
+
+#[test]
+fn test_concat() {
+    let mut deque = AltDeque::from(([1], [2]));
+    let mut split = AltDeque::from(([4, 5], [6]));
+    split.push_front(3);
+    deque.concat_into([AltDeque::new(), split, AltDeque::from([7])]);
+    assert_eq!(deque, [1, 2, 3, 4, 5, 6, 7]);
+
+    let mut deque = AltDeque::new();
+    deque.concat_into((0..4).map(|i| (0..i).collect::<AltDeque<_>>()));
+    assert_eq!(deque, [0, 0, 1, 0, 1, 2]);
+    assert_eq!(deque.capacity(), 6);
+
+    let mut deque = AltDeque::new();
+    deque.concat_into(vec![vec![String::from("a")], vec![], vec![String::from("b")]]);
+    assert_eq!(deque, ["a", "b"]);
+
+    let mut deque = AltDeque::new();
+    deque.concat_into([&[1, 2][..], &[], &[3]]);
+    assert_eq!(deque, [1, 2, 3]);
+    assert!(deque.capacity() >= 3);
+
+    // collecting nested vectors still builds a deque of vectors
+    let deque: AltDeque<_> = vec![vec![1], vec![2, 3]].into_iter().collect();
+    assert_eq!(deque, [vec![1], vec![2, 3]]);
+
+    let mut deque = AltDeque::new();
+    deque.concat_into([AltDeque::from([(); 3]), AltDeque::from([()])]);
+    assert_eq!(deque.len(), 4);
+}
+
+#[test]
+fn test_join() {
+    let mut deque = AltDeque::from([2.5, 3.0]);
+    deque.push_front(1.0);
+    assert_eq!(deque.join("-"), "1-2.5-3");
+    assert_eq!(AltDeque::from(['x']).join(", "), "x");
+    assert_eq!(AltDeque::<char>::new().join(", "), "");
+
+    let mut deque = AltDeque::from([String::from("cd"), String::new()]);
+    deque.push_front(String::from("ab"));
+    deque.push_back(String::from("e"));
+    assert_eq!(deque.concat(), "abcde");
+    assert_eq!(AltDeque::<&str>::new().concat(), "");
+}
+
+#[test]
+fn test_fmt_write() {
+    use std::fmt::Write;
+
+    let mut bytes = AltDeque::from(*b"> ");
+    let (n, c) = (1, 'ä');
+    write!(bytes, "{n}-{c}").unwrap();
+    assert_eq!(bytes, "> 1-ä".as_bytes());
+    assert_eq!(bytes.pop_front(), Some(b'>'));
+    bytes.write_char('!').unwrap();
+    assert_eq!(bytes, " 1-ä!".as_bytes());
+
+    let mut chars = AltDeque::<char>::new();
+    write!(chars, "{c:>3}").unwrap();
+    chars.write_char('b').unwrap();
+    assert_eq!(chars, [' ', ' ', 'ä', 'b']);
+}
+
+#[test]
+fn test_drain_to_vec() {
+    for front_len in 0..=6 {
+        for start in 0..=6 {
+            for end in start..=6 {
+                let mut deque = split_deque((0..6).map(|x| x.to_string()), front_len);
+                let mut expected = deque.clone();
+                let drained = deque.drain_to_vec(start..end);
+                assert_eq!(drained, expected.drain(start..end).collect::<Vec<_>>());
+                assert_eq!(deque, expected);
+            }
+        }
+
+        for at in 0..=6 {
+            let mut deque = split_deque(0..6, front_len);
+            let mut expected = deque.clone();
+            assert_eq!(deque.split_off_to_vec(at), Vec::from(expected.split_off(at)));
+            assert_eq!(deque, expected);
+        }
+    }
+
+    let mut deque = AltDeque::from([(); 5]);
+    assert_eq!(deque.drain_to_vec(1..3).len(), 2);
+    assert_eq!(deque.len(), 3);
+}
+
+#[test]
+#[should_panic = "index out of bounds"]
+fn test_split_off_to_vec_out_of_bounds() {
+    let mut deque = AltDeque::from([1, 2]);
+    let _ = deque.split_off_to_vec(3);
+}
+
+#[test]
+fn test_drain_into() {
+    for front_len in 0..=6 {
+        for start in 0..=6 {
+            for end in start..=6 {
+                let mut deque = split_deque((0..6).map(|x| x.to_string()), front_len);
+                let mut expected = deque.clone();
+                let mut expected_drained = vec![String::from("x")];
+                expected_drained.extend(expected.drain(start..end));
+
+                let mut vec = vec![String::from("x")];
+                deque.clone().drain_into(start..end, &mut vec);
+                assert_eq!(vec, expected_drained);
+
+                let mut other = AltDeque::from([String::from("x")]);
+                deque.transfer(start..end, &mut other);
+                assert_eq!(other, expected_drained);
+                assert_eq!(deque, expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_transfer() {
+    let mut deque = AltDeque::from(([1, 2], [3, 4]));
+    let mut other = AltDeque::with_capacity(8);
+    other.push_front(0);
+    let ptr = other.as_slices().0.as_ptr();
+    deque.transfer(1..3, &mut other);
+    deque.transfer(.., &mut other);
+    assert_eq!(other, [0, 2, 3, 1, 4]);
+    assert!(deque.is_empty());
+    assert_eq!(other.as_slices().0.as_ptr(), ptr);
+
+    let mut empty = AltDeque::new();
+    other.transfer(5..5, &mut empty);
+    assert_eq!(empty.capacity(), 0);
+}
+
+#[test]
+fn test_swap_ranges() {
+    let vec: Vec<_> = (0..8).collect();
+    for front_len in 0..=8 {
+        for count in 0..=4 {
+            for a in 0..=8 - 2 * count {
+                for b in a + count..=8 - count {
+                    let mut deque = split_deque(0..8, front_len);
+                    let mut expected = vec.clone();
+                    let (left, right) = expected.split_at_mut(b);
+                    left[a..a + count].swap_with_slice(&mut right[..count]);
+
+                    deque.swap_ranges(a..a + count, b..b + count);
+                    assert_eq!(deque, expected);
+                    deque.swap_ranges(b..b + count, a..a + count);
+                    assert_eq!(deque, vec);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic = "ranges must not overlap"]
+fn test_swap_ranges_overlap() {
+    AltDeque::from([1, 2, 3, 4]).swap_ranges(0..2, 1..3);
+}
+
+#[test]
+#[should_panic = "ranges must have the same length"]
+fn test_swap_ranges_length() {
+    AltDeque::from([1, 2, 3, 4]).swap_ranges(0..1, 2..4);
+}
+
+#[test]
+fn test_get_from_back() {
+    for front_len in 0..=5 {
+        let mut deque = split_deque(0..5, front_len);
+        for i in 0..5 {
+            assert_eq!(deque.get_from_back(i), Some(&(4 - i)));
+            *deque.get_from_back_mut(i).unwrap() += 10;
+            assert_eq!(deque[4 - i], 14 - i);
+        }
+        assert_eq!(deque.get_from_back(5), None);
+        assert_eq!(deque.get_from_back_mut(usize::MAX), None);
+    }
+    assert_eq!(AltDeque::from([(); 2]).get_from_back(1), Some(&()));
+    assert_eq!(AltDeque::from([(); 2]).get_from_back(2), None);
+}
+
+#[test]
+fn test_get_wrapping() {
+    let mut deque = AltDeque::from(([1, 2], [3]));
+    for i in 0..10 {
+        assert_eq!(deque.get_wrapping(i), Some(&(i % 3 + 1)));
+    }
+    assert_eq!(deque.get_wrapping(usize::MAX), Some(&(usize::MAX % 3 + 1)));
+    *deque.get_wrapping_mut(4).unwrap() = 0;
+    assert_eq!(deque, [1, 0, 3]);
+    assert_eq!(AltDeque::<usize>::new().get_wrapping(0), None);
+    assert_eq!(AltDeque::<usize>::new().get_wrapping_mut(0), None);
+}
+
+#[test]
+fn test_rotate_moves_fewer_elements() {
+    // every combination of front len, back len, free space in 0..6 and mid, with elements that
+    // need to be dropped
+    for front_len in 0..8 {
+        for back_len in 0..8 {
+            let len = front_len + back_len;
+            for free in 0..6 {
+                for mid in 0..=len {
+                    let mut deque = split_deque_with_free((0..len).map(|i| i.to_string()), front_len, free);
+                    let mut expected: VecDeque<_> = deque.iter().cloned().collect();
+                    deque.rotate_left(mid);
+                    expected.rotate_left(mid);
+                    assert_eq!(deque, expected);
+                    assert_eq!(deque.capacity(), len + free);
+                }
+            }
+        }
+    }
+
+    // a full deque is rotated by relabelling the stacks
+    let mut deque = AltDeque::with_capacity(6);
+    deque.extend(0..6);
+    let ptr = deque.as_slices().1.as_ptr();
+    deque.rotate_left(4);
+    deque.rotate_right(1);
+    assert_eq!(deque, [3, 4, 5, 0, 1, 2]);
+    assert_eq!(deque.as_slices(), (&[3, 4, 5][..], &[0, 1, 2][..]));
+    assert_eq!(deque.as_slices().1.as_ptr(), ptr);
+
+    // only the last element is moved instead of all others
+    let mut deque = AltDeque::with_capacity(7);
+    (0..6).rev().for_each(|i| deque.push_front(i));
+    let ptr = deque.as_slices().0.as_ptr();
+    deque.rotate_left(5);
+    assert_eq!(deque, [5, 0, 1, 2, 3, 4]);
+    assert_eq!(deque.as_slices().1[1..].as_ptr(), ptr);
+}
+
+#[test]
+fn test_segments() {
+    for (front_len, back_len) in [(0, 0), (2, 0), (0, 2), (2, 3)] {
+        let mut deque = split_deque(0..front_len + back_len, front_len);
+        let count = usize::from(front_len > 0) + usize::from(back_len > 0);
+        assert_eq!(deque.segments().len(), count);
+        assert!(deque.segments().all(|segment| !segment.is_empty()));
+        let segments: Vec<_> = deque.segments().collect();
+        assert_eq!(segments.concat(), (0..front_len + back_len).collect::<Vec<_>>());
+        let mut rev: Vec<_> = deque.segments().rev().collect();
+        rev.reverse();
+        assert_eq!(rev, segments);
+
+        let mut segments = deque.segments_mut();
+        assert_eq!(segments.len(), count);
+        if let Some(last) = segments.next_back() {
+            last.fill(9);
+        }
+        assert_eq!(segments.len(), count.saturating_sub(1));
+        assert_eq!(deque.back(), if count > 0 { Some(&9) } else { None });
+    }
+}
+
+#[test]
+fn test_contiguous() {
+    let mut deque = AltDeque::new();
+    assert_eq!(deque.contiguous(), Some(&[][..]));
+    deque.push_front(1);
+    deque.push_front(0);
+    assert_eq!(deque.contiguous(), Some(&[0, 1][..]));
+    deque.push_back(2);
+    assert_eq!(deque.contiguous(), None);
+    assert_eq!(deque.contiguous_mut(), None);
+    deque.pop_front();
+    deque.pop_front();
+    assert_eq!(deque.contiguous(), Some(&[2][..]));
+    deque.contiguous_mut().unwrap()[0] = 5;
+    assert_eq!(deque, [5]);
+
+    deque.clear();
+    deque.extend([1, 2]);
+    deque.push_front(0);
+    assert!(deque.contiguous().is_none());
+    deque.make_contiguous();
+    assert_eq!(deque.contiguous(), Some(&[0, 1, 2][..]));
+}
+
+#[test]
+fn test_to_vec() {
+    let mut deque = AltDeque::from([String::from("b")]);
+    deque.push_front(String::from("a"));
+    let vec = deque.to_vec();
+    assert_eq!(vec, ["a", "b"]);
+    assert_eq!(vec.capacity(), 2);
+    assert_eq!(deque, ["a", "b"]);
+    assert!(AltDeque::<i32>::new().to_vec().is_empty());
+}
+
+#[test]
+fn test_range_slices() {
+    let vec: Vec<_> = (0..6).collect();
+    for front_len in 0..=6 {
+        let mut deque = split_deque(0..6, front_len);
+        for start in 0..=6 {
+            for end in start..=6 {
+                let (a, b) = deque.range_slices(start..end);
+                assert!(b.is_empty() || (a.len() == front_len - start && !a.is_empty()));
+                assert_eq!([a, b].concat(), &vec[start..end]);
+                let (a, b) = deque.range_slices_mut(start..end);
+                a.iter_mut().chain(b).for_each(|x| *x += 10);
+                assert!(deque.range(start..end).copied().eq(start + 10..end + 10));
+                deque.range_mut(start..end).for_each(|x| *x -= 10);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic = "should be <= length"]
+fn test_range_slices_out_of_bounds() {
+    let _ = AltDeque::from([1, 2, 3]).range_slices(1..4);
+}
+
+#[test]
+fn test_eq_cross_type() {
+    let mut strings = AltDeque::from([String::from("b")]);
+    strings.push_front(String::from("a"));
+    let strs = AltDeque::from(["a", "b"]);
+    assert_eq!(strings, strs);
+    assert_ne!(strings, AltDeque::from(["a"]));
+    assert_ne!(strings, AltDeque::from(["a", "c"]));
+    assert_eq!(AltDeque::<String>::new(), AltDeque::<&str>::new());
+}
+
+#[test]
+fn test_ord() {
+    let make = |front: &[i32], back: &[i32]| split_deque(front.iter().chain(back).copied(), front.len());
+    let values: [&[i32]; 6] = [&[], &[1], &[1, 2], &[1, 2, 3], &[1, 3], &[2]];
+    for a in values {
+        for b in values {
+            let expected = a.cmp(b);
+            for i in 0..=a.len() {
+                for j in 0..=b.len() {
+                    let da = make(&a[..i], &a[i..]);
+                    let db = make(&b[..j], &b[j..]);
+                    assert_eq!(da.cmp(&db), expected, "{:?} {:?}", da.as_slices(), db.as_slices());
+                    assert_eq!(da.partial_cmp(&db), Some(expected));
+                }
+            }
+        }
+    }
+
+    let nan = AltDeque::from([1.0, f64::NAN]);
+    assert_eq!(nan.partial_cmp(&AltDeque::from([1.0, 2.0])), None);
+    assert_eq!(nan.partial_cmp(&AltDeque::from([2.0])), Some(Ordering::Less));
+}
+
+#[test]
+fn test_drain_nth_fold() {
+    for front_len in 0..=8 {
+        for n in 0..=9 {
+            let make = || split_deque((0..8).map(|x| x.to_string()), front_len);
+            let mut deque = make();
+            let mut expected: Vec<_> = make().into_iter().collect();
+            let mut drain = deque.drain(..);
+            assert_eq!(drain.nth(n), expected.get(n).cloned());
+            assert_eq!(drain.len(), 8usize.saturating_sub(n + 1));
+            // the remaining elements are n + 1..8
+            assert_eq!(drain.nth_back(1), (n < 6).then(|| 6.to_string()));
+            drop(drain);
+            assert!(deque.is_empty());
+
+            let mut deque = make();
+            let joined = deque.drain(1..).fold(String::new(), |acc, x| acc + &x);
+            assert_eq!(joined, expected[1..].concat());
+            let mut deque = make();
+            let joined = deque.drain(..7).rfold(String::new(), |acc, x| acc + &x);
+            expected.truncate(7);
+            expected.reverse();
+            assert_eq!(joined, expected.concat());
+        }
+    }
+}
+
+#[test]
+fn test_try_reserve() {
+    let mut deque = AltDeque::try_with_capacity(4).unwrap();
+    assert!(deque.capacity() >= 4);
+    deque.extend(2..4);
+    deque.push_front(1);
+    deque.push_front(0);
+    deque.try_reserve(10).unwrap();
+    assert!(deque.capacity() >= 14);
+    assert_eq!(deque, [0, 1, 2, 3]);
+    deque.try_reserve_exact(20).unwrap();
+    assert!(deque.capacity() >= 24);
+    assert_eq!(deque, [0, 1, 2, 3]);
+
+    let cap = deque.capacity();
+    assert_eq!(deque.try_reserve(usize::MAX), Err(TryReserveError::CapacityOverflow));
+    assert_eq!(deque.try_reserve_exact(usize::MAX - 2), Err(TryReserveError::CapacityOverflow));
+    assert_eq!(deque.capacity(), cap);
+    assert_eq!(deque, [0, 1, 2, 3]);
+    assert!(AltDeque::<u32>::try_with_capacity(usize::MAX / 2).is_err());
+}
+
+#[test]
+fn test_push_within_capacity() {
+    let mut deque = AltDeque::with_capacity(4);
+    assert_eq!(deque.push_back_within_capacity(2), Ok(()));
+    assert_eq!(deque.push_front_within_capacity(1), Ok(()));
+    assert_eq!(deque.push_back_within_capacity(3), Ok(()));
+    assert_eq!(deque.push_front_within_capacity(0), Ok(()));
+    assert_eq!(deque.push_front_within_capacity(-1), Err(-1));
+    assert_eq!(deque.push_back_within_capacity(4), Err(4));
+    assert_eq!(deque, [0, 1, 2, 3]);
+    assert_eq!(deque.capacity(), 4);
+
+    deque.try_reserve(1).unwrap();
+    assert_eq!(deque.push_back_within_capacity(4), Ok(()));
+    assert_eq!(deque, [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_extend_sources() {
+    for front_len in 0..=4 {
+        let mut deque = split_deque(0..4, front_len);
+        // slice iterator of Copy elements
+        deque.extend(&[4, 5]);
+        // TrustedLen sources, by value and by reference
+        deque.extend([6, 7]);
+        deque.extend([9, 8].iter().rev());
+        let other = AltDeque::from(([10], [11]));
+        deque.extend(&other);
+        deque.extend(other.into_iter().map(|x| x + 2));
+        // a source without an exact length
+        deque.extend((14..20).filter(|x| x % 2 == 0));
+        assert_eq!(deque, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 16, 18]);
+        assert_eq!(deque.front_stack_len(), front_len);
+    }
+    let deque: AltDeque<_> = AltDeque::from([1, 2]).into_iter().chain([3]).collect();
+    assert_eq!(deque, [1, 2, 3]);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn test_may_dangle() {
+    let mut deque = AltDeque::new();
+    // dropped before the deque, which is fine as long as the drop of the deque does not
+    // access its elements
+    let s = String::from("borrowed");
+    deque.push_back(&s);
+    deque.push_front(&s);
+    assert_eq!(deque.len(), 2);
+}
+
+#[test]
+fn test_insert_at_boundary() {
+    for (front_len, back_len) in [(0, 0), (0, 3), (1, 3), (3, 1), (3, 0), (2, 2)] {
+        let mut deque = split_deque_with_free(0..front_len + back_len, front_len, 16 - front_len - back_len);
+        deque.insert(front_len, 100);
+
+        let mut expected: Vec<_> = (0..front_len + back_len).collect();
+        expected.insert(front_len, 100);
+        assert_eq!(deque, expected);
+        // only the smaller stack grows
+        if front_len < back_len {
+            assert_eq!(deque.front_stack_len(), front_len + 1);
+        } else {
+            assert_eq!(deque.back_stack_len(), back_len + 1);
+        }
+    }
+}
+
+#[test]
+fn test_hash_independent_of_split() {
+    use std::hash::{Hash, Hasher};
+
+    /// Records every call, as a hasher may give different results for different sequences of
+    /// calls that write the same bytes.
+    #[derive(Default, PartialEq, Debug)]
+    struct RecordingHasher(Vec<Vec<u8>>);
+
+    impl Hasher for RecordingHasher {
+        fn finish(&self) -> u64 {
+            0
+        }
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.push(bytes.to_vec());
+        }
+    }
+
+    fn record<T: Hash>(deque: &AltDeque<T>) -> RecordingHasher {
+        let mut hasher = RecordingHasher::default();
+        deque.hash(&mut hasher);
+        hasher
+    }
+
+    let len = 1000;
+    let expected_bytes = record(&(0..len).map(|x| x as u8).collect::<AltDeque<_>>());
+    let expected_signed = record(&(0..len).map(|x| x as i8).collect::<AltDeque<_>>());
+    let expected_wide = record(&(0..len).collect::<AltDeque<_>>());
+    for front_len in [1, 100, 255, 256, 257, 999, 1000] {
+        let bytes = split_deque((0..len).map(|x| x as u8), front_len);
+        assert_eq!(record(&bytes), expected_bytes);
+
+        let signed = split_deque((0..len).map(|x| x as i8), front_len);
+        assert_eq!(record(&signed), expected_signed);
+
+        let wide = split_deque(0..len, front_len);
+        assert_eq!(record(&wide), expected_wide);
+    }
+}
+
+#[test]
+fn test_undo_redo() {
+    let mut history = UndoRedo::new();
+    assert_eq!(history.undo(), None);
+    assert_eq!(history.redo(), None);
+    (0..10).for_each(|x| assert_eq!(history.push(x), None));
+    for x in (5..10).rev() {
+        assert_eq!(history.undo().copied(), Some(x));
+    }
+    assert_eq!((history.undo_len(), history.redo_len()), (5, 5));
+    assert_eq!(history.redo(), Some(&mut 5));
+    // pushing drops everything that could be redone
+    history.push(20);
+    assert!(history.undo_iter().eq(&[0, 1, 2, 3, 4, 5, 20]));
+    assert_eq!(history.redo_len(), 0);
+
+    let mut limited = UndoRedo::with_limit(3);
+    let evicted: Vec<_> = (0..6).filter_map(|x| limited.push(x)).collect();
+    assert_eq!(evicted, [0, 1, 2]);
+    assert_eq!(limited.undo(), Some(&mut 5));
+    // actions that can be redone do not count towards the limit
+    assert_eq!(limited.push(6), None);
+    assert_eq!(limited.push(7), Some(3));
+    assert!(limited.undo_iter().eq(&[4, 6, 7]));
+    while limited.undo().is_some() {}
+    assert!(limited.redo_iter().eq(&[4, 6, 7]));
+
+    let mut empty = UndoRedo::with_limit(0);
+    assert_eq!(empty.push(1), Some(1));
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_gap_buffer() {
+    let mut buffer = GapBuffer::new();
+    "world".chars().for_each(|c| buffer.insert(c));
+    buffer.set_cursor(0);
+    buffer.extend("hello ".chars());
+    assert_eq!(buffer.cursor(), 6);
+    assert_eq!(buffer.iter().collect::<String>(), "hello world");
+    assert_eq!(buffer.as_slices().0, "hello ".chars().collect::<Vec<_>>());
+
+    buffer.set_cursor(buffer.len());
+    assert_eq!(buffer.backspace(), Some('d'));
+    assert_eq!(buffer.delete(), None);
+    buffer.set_cursor(2);
+    assert_eq!(buffer.delete(), Some('l'));
+    assert_eq!(buffer.backspace(), Some('e'));
+    assert_eq!(buffer.iter().collect::<String>(), "hlo worl");
+    assert_eq!(buffer.get(1), Some(&'l'));
+    assert_eq!(buffer.get(7), Some(&'l'));
+    assert_eq!(buffer.get(8), None);
+
+    // elements after the cursor stay in place when the buffer grows
+    let mut buffer: GapBuffer<_> = (0..4).map(|x| x.to_string()).collect();
+    buffer.set_cursor(1);
+    for x in 10..100 {
+        buffer.insert(x.to_string());
+    }
+    let expected: Vec<_> = [0].into_iter().chain(10..100).chain(1..4).map(|x| x.to_string()).collect();
+    assert!(buffer.iter().eq(&expected));
+    let clone = buffer.clone();
+    assert_eq!(clone.cursor(), buffer.cursor());
+    assert!(clone.iter().eq(&expected));
+    buffer.clear();
+    assert!(buffer.is_empty());
+}
+
+#[test]
+#[should_panic = "index out of bounds: the len is 2 but the index is 3"]
+fn test_gap_buffer_set_cursor_out_of_bounds() {
+    let mut buffer: GapBuffer<_> = [1, 2].into_iter().collect();
+    buffer.set_cursor(3);
+}
+
+#[test]
+fn test_two_lock_queue() {
+    use std::sync::Arc;
+    use std::thread;
+
+    let queue = concurrent::TwoLockQueue::new();
+    assert_eq!(queue.pop(), None);
+    (0..3).for_each(|i| queue.push(i));
+    assert_eq!(queue.pop(), Some(0));
+    // pushed while the front stack still holds 1 and 2
+    queue.push(3);
+    assert_eq!(format!("{queue:?}"), "[1, 2, 3]");
+    assert_eq!(queue.len(), 3);
+    assert_eq!((0..4).map(|_| queue.pop()).collect::<Vec<_>>(), [Some(1), Some(2), Some(3), None]);
+    assert!(queue.is_empty());
+
+    let queue = Arc::new(concurrent::TwoLockQueue::new());
+    let producers: Vec<_> = (0..4)
+        .map(|p| {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || (0..250).for_each(|i| queue.push(p * 1000 + i)))
+        })
+        .collect();
+    let consumers: Vec<_> = (0..2)
+        .map(|_| {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || {
+                let mut received = Vec::new();
+                while received.len() < 500 {
+                    received.extend(queue.pop());
+                }
+                received
+            })
+        })
+        .collect();
+    producers.into_iter().for_each(|p| p.join().unwrap());
+    let received: Vec<Vec<_>> = consumers.into_iter().map(|c| c.join().unwrap()).collect();
+    // every consumer sees the elements of every producer in order
+    for own in &received {
+        for p in 0..4 {
+            let from_p: Vec<_> = own.iter().filter(|&&x| x / 1000 == p).collect();
+            assert!(from_p.windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+    let mut all: Vec<_> = received.concat();
+    all.sort();
+    all.dedup();
+    assert_eq!(all.len(), 1000);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn test_segmented_deque() {
+    use crate::ExtendFront;
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+
+    // blocks of 16 elements to cross many block boundaries
+    type Big = [u64; 40];
+    assert_eq!(SegmentedAltDeque::<Big>::BLOCK_LEN, 16);
+    let mut deque = SegmentedAltDeque::new();
+    let mut model = VecDeque::new();
+    let mut x = 1u64;
+    for round in 0..2000 {
+        // a simple LCG decides the next operation
+        x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        match (x >> 60) % 4 {
+            0 => {
+                deque.push_front([round; 40]);
+                model.push_front([round; 40]);
+            }
+            1 => {
+                deque.push_back([round; 40]);
+                model.push_back([round; 40]);
+            }
+            2 => assert_eq!(deque.pop_front(), model.pop_front()),
+            _ => assert_eq!(deque.pop_back(), model.pop_back()),
+        }
+        assert_eq!(deque.len(), model.len());
+        assert_eq!(deque.front(), model.front());
+        assert_eq!(deque.back(), model.back());
+    }
+    assert!(deque.iter().eq(&model));
+    assert!(deque.iter().rev().eq(model.iter().rev()));
+    // the partly filled first and last block and the spare one
+    assert!(deque.block_count() <= model.len() / 16 + 3);
+
+    // the spare block is reused when pushing and popping across a block boundary
+    let mut deque: SegmentedAltDeque<Big> = (0..16).map(|i| [i; 40]).collect();
+    assert_eq!(deque.block_count(), 1);
+    for _ in 0..3 {
+        deque.push_back([0; 40]);
+        assert_eq!(deque.block_count(), 2);
+        deque.pop_back();
+        assert_eq!(deque.block_count(), 2);
+    }
+
+    let el = Rc::new(0);
+    let mut deque: SegmentedAltDeque<_> = (0..1000).map(|_| Rc::clone(&el)).collect();
+    deque.extend_front((0..1000).map(|_| Rc::clone(&el)));
+    assert_eq!(Rc::strong_count(&el), 2001);
+    let clone = deque.clone();
+    assert_eq!(clone, deque);
+    drop(clone);
+    deque.clear();
+    assert_eq!(Rc::strong_count(&el), 1);
+    assert_eq!(deque.block_count(), 1);
+    deque.extend((0..100).map(|_| Rc::clone(&el)));
+    drop(deque);
+    assert_eq!(Rc::strong_count(&el), 1);
+
+    let mut deque = SegmentedAltDeque::new();
+    deque.extend_front([1, 2, 3]);
+    deque[0] = 4;
+    assert_eq!(deque, [4, 2, 1]);
+    assert_eq!(format!("{deque:?}"), "[4, 2, 1]");
+    assert_eq!(deque.get(3), None);
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn test_mmap_deque() {
+    use crate::MmapAltDeque;
+    use std::rc::Rc;
+
+    let dir = std::env::temp_dir();
+    let prefix = format!(".altdeque-{}-", std::process::id());
+    let files = || {
+        let names = std::fs::read_dir(&dir).unwrap().map(|entry| entry.unwrap().file_name());
+        names.filter(|name| name.to_string_lossy().starts_with(&prefix)).count()
+    };
+
+    let mut deque = MmapAltDeque::new_in(&dir).unwrap();
+    assert_eq!(deque.capacity(), 0);
+    // grows several times with elements on both stacks
+    for i in 0..5000_u64 {
+        deque.push_back(i);
+        deque.push_front(i);
+    }
+    assert_eq!(deque.len(), 10_000);
+    assert!(deque.iter().eq((0..5000).rev().chain(0..5000).collect::<Vec<_>>().iter()));
+    assert_eq!(deque[4999], 0);
+    for i in 0..5000 {
+        assert_eq!(deque.pop_back(), Some(4999 - i));
+    }
+    // the front stack is split when the back stack runs dry
+    assert_eq!(deque.pop_back(), Some(0));
+    assert_eq!(deque.pop_front(), Some(4999));
+    assert_eq!(deque.len(), 4998);
+    assert!(deque.reserve(usize::MAX).is_err());
+    assert_eq!(deque.len(), 4998);
+
+    let el = Rc::new(0);
+    let mut rcs = MmapAltDeque::new_in(&dir).unwrap();
+    rcs.extend((0..100).map(|_| Rc::clone(&el)));
+    rcs.push_front(Rc::clone(&el));
+    assert_eq!(Rc::strong_count(&el), 102);
+    rcs.clear();
+    assert_eq!(Rc::strong_count(&el), 1);
+    rcs.push_back(Rc::clone(&el));
+    drop(rcs);
+    assert_eq!(Rc::strong_count(&el), 1);
+
+    let mut zsts = MmapAltDeque::new_in(&dir).unwrap();
+    zsts.extend([(); 10]);
+    assert_eq!(zsts.pop_front(), Some(()));
+    assert_eq!(zsts.len(), 9);
+    drop((deque, zsts));
+    assert_eq!(files(), 0);
+}
+
+#[test]
+fn test_cow_deque() {
+    use std::thread;
+
+    let mut deque: CowAltDeque<_> = (0..100).map(|x| x.to_string()).collect();
+    let snapshot = deque.clone();
+    assert!(CowAltDeque::ptr_eq(&deque, &snapshot));
+    assert_eq!(deque, snapshot);
+
+    // a reader thread gets the state at the time of the clone
+    let reader = thread::spawn(move || snapshot.iter().map(|x| x.len()).sum::<usize>());
+    deque.push_back("100".to_string());
+    assert_eq!(deque.pop_front().as_deref(), Some("0"));
+    assert_eq!(reader.join().unwrap(), 190);
+    assert!(deque.is_unique());
+    assert_eq!(deque.front().map(String::as_str), Some("1"));
+    assert_eq!(deque.len(), 100);
+
+    // only the first mutation of a shared deque copies it
+    let snapshot = deque.clone();
+    deque.make_mut().push_front("0".to_string());
+    let copied = deque.as_slices().0.as_ptr();
+    deque.make_mut().pop_back();
+    assert_eq!(deque.as_slices().0.as_ptr(), copied);
+    assert_ne!(deque, snapshot);
+    assert_eq!(snapshot.back().map(String::as_str), Some("100"));
+
+    // popping from an empty shared deque does not copy
+    let mut empty = CowAltDeque::<i32>::new();
+    let other = empty.clone();
+    assert_eq!(empty.pop_back(), None);
+    assert!(CowAltDeque::ptr_eq(&empty, &other));
+
+    let mut deque: CowAltDeque<_> = [1, 2].into_iter().collect();
+    let snapshot = deque.clone();
+    deque.clear();
+    deque.extend([3]);
+    assert_eq!(deque.into_inner(), [3]);
+    assert_eq!(snapshot.clone().into_inner(), [1, 2]);
+    assert_eq!(format!("{snapshot:?}"), "[1, 2]");
+}
+
+#[test]
+fn test_deque_trait() {
+    fn exercise<D: Deque<i32> + Default>() -> Vec<i32> {
+        let mut deque = D::default();
+        assert!(deque.is_empty());
+        assert_eq!(deque.back(), None);
+        for i in 0..10 {
+            deque.push_back(i);
+            deque.push_front(-i);
+        }
+        assert_eq!(deque.len(), 20);
+        assert_eq!(deque.front(), Some(&-9));
+        assert_eq!(deque.back(), Some(&9));
+        assert_eq!(deque.get(10), Some(&0));
+        assert_eq!(deque.get(20), None);
+        assert_eq!(deque.pop_front(), Some(-9));
+        assert_eq!(deque.pop_back(), Some(9));
+        assert!(deque.iter().rev().eq(Deque::iter(&deque).collect::<Vec<_>>().into_iter().rev()));
+        deque.iter().copied().collect()
+    }
+
+    let expected = exercise::<AltDeque<i32>>();
+    assert_eq!(expected, (-8..=0).chain(0..9).collect::<Vec<_>>());
+    assert_eq!(exercise::<DualStackDeque<i32>>(), expected);
+    assert_eq!(exercise::<SmallAltDeque<i32, 4>>(), expected);
+    assert_eq!(exercise::<SegmentedAltDeque<i32>>(), expected);
+    #[cfg(feature = "vec-deque")]
+    assert_eq!(exercise::<std::collections::VecDeque<i32>>(), expected);
+}
+
+#[test]
+fn test_into_flattened() {
+    let mut deque = AltDeque::with_capacity(4);
+    deque.push_back([3, 4]);
+    deque.push_front([1, 2]);
+    let capacity = deque.capacity();
+    let flattened = deque.into_flattened();
+    assert_eq!(flattened, [1, 2, 3, 4]);
+    assert_eq!(flattened.as_slices(), (&[1, 2][..], &[3, 4][..]));
+    assert_eq!(flattened.capacity(), capacity * 2);
+
+    let mut flattened = AltDeque::from([[1, 2, 3]]).into_flattened();
+    flattened.push_back(4);
+    assert_eq!(flattened, [1, 2, 3, 4]);
+
+    let flattened = AltDeque::from([[(); 3]; 2]).into_flattened();
+    assert_eq!(flattened.len(), 6);
+    let flattened = AltDeque::<[String; 0]>::from([[], []]).into_flattened();
+    assert!(flattened.is_empty());
+    assert_eq!(flattened.capacity(), 0);
+}
+
+#[test]
+fn test_pop_while() {
+    let mut deque = AltDeque::from(([1, 2], [3, 4, 5]));
+    let mut iter = deque.pop_front_while(|&x| x < 4);
+    assert_eq!(iter.next(), Some(1));
+    drop(iter);
+    assert_eq!(deque, [2, 3, 4, 5]);
+    assert_eq!(deque.pop_front_while(|&x| x < 4).collect::<Vec<_>>(), [2, 3]);
+    assert_eq!(deque, [4, 5]);
+
+    let mut deque = AltDeque::from(([1, 2, 3], [4]));
+    assert_eq!(deque.pop_back_while(|&x| x > 1).collect::<Vec<_>>(), [4, 3, 2]);
+    assert_eq!(deque, [1]);
+    assert_eq!(deque.pop_back_while(|_| true).count(), 1);
+    assert_eq!(deque.pop_front_while(|_| true).next(), None);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_rand() {
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut deque: AltDeque<_> = (0..100).collect();
+    deque.rebalance(30);
+    deque.shuffle(&mut rng);
+    assert_ne!(deque, (0..100).collect::<Vec<_>>());
+    let mut sorted = Vec::from(deque.clone());
+    sorted.sort();
+    assert_eq!(sorted, (0..100).collect::<Vec<_>>());
+
+    let mut deque = AltDeque::from(([0, 1], [2, 3]));
+    assert_eq!(deque.partial_shuffle(&mut rng, 10), 4);
+    assert_eq!(deque.partial_shuffle(&mut rng, 0), 0);
+    assert_eq!(deque.iter().sum::<i32>(), 6);
+
+    let mut counts = [0; 4];
+    for _ in 0..400 {
+        counts[*deque.choose(&mut rng).unwrap() as usize] += 1;
+    }
+    assert!(counts.iter().all(|&count| count > 50));
+    *deque.choose_mut(&mut rng).unwrap() = 10;
+    assert!(deque.contains(&10));
+    assert_eq!(AltDeque::<i32>::new().choose_mut(&mut rng), None);
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn test_tracing() {
+    use std::sync::{Arc, Mutex};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Collects the messages of all events.
+    struct Messages(Arc<Mutex<Vec<String>>>);
+
+    struct MessageVisitor<'a>(&'a mut String);
+
+    impl tracing::field::Visit for MessageVisitor<'_> {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "message" {
+                *self.0 = format!("{value:?}");
+            }
+        }
+    }
+
+    impl Subscriber for Messages {
+        fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+            metadata.target() == "altdeque"
+        }
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, event: &Event<'_>) {
+            let mut message = String::new();
+            event.record(&mut MessageVisitor(&mut message));
+            self.0.lock().unwrap().push(message);
+        }
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    tracing::subscriber::with_default(Messages(Arc::clone(&messages)), || {
+        let mut deque = AltDeque::with_capacity(4);
+        deque.extend(0..4);
+        deque.pop_front();
+        deque.push_back(4);
+        deque.push_back(5);
+        deque.make_contiguous();
+        deque.shrink_to_fit();
+    });
+    assert_eq!(
+        *messages.lock().unwrap(),
+        [
+            "split back stack to pop from the front",
+            "grew buffer",
+            "making deque contiguous",
+            "shrinking buffer",
+        ]
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    use serde::de::value::{Error, SeqDeserializer};
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+
+    let deque = AltDeque::from(([1, 2], [3]));
+    assert_tokens(
+        &deque,
+        &[Token::Seq { len: Some(3) }, Token::I32(1), Token::I32(2), Token::I32(3), Token::SeqEnd],
+    );
+
+    // the announced length is not trusted for the allocation
+    let tokens = [Token::Seq { len: Some(usize::MAX) }, Token::I32(1), Token::SeqEnd];
+    assert_de_tokens(&AltDeque::from([1]), &tokens);
+    assert_de_tokens_error::<AltDeque<u8>>(
+        &[Token::Seq { len: Some(1) }, Token::Str("a"), Token::SeqEnd],
+        "invalid type: string \"a\", expected u8",
+    );
+
+    let input = SeqDeserializer::<_, Error>::new(0..5);
+    let deque = AltDeque::<i32>::deserialize_bounded(input, 5).unwrap();
+    assert_eq!(deque, [0, 1, 2, 3, 4]);
+    assert!(deque.capacity() >= 5);
+    let input = SeqDeserializer::<_, Error>::new(0..usize::MAX);
+    let err = AltDeque::<usize>::deserialize_bounded(input, 4).unwrap_err();
+    assert_eq!(err.to_string(), "invalid length 5, expected a sequence of at most 4 elements");
+}
+
+#[cfg(feature = "bincode")]
+#[test]
+fn test_bincode() {
+    let config = bincode::config::standard();
+
+    let deque = AltDeque::from(([1_u8, 2], [3, 4]));
+    let bytes = bincode::encode_to_vec(&deque, config).unwrap();
+    assert_eq!(bytes, bincode::encode_to_vec(vec![1_u8, 2, 3, 4], config).unwrap());
+    let (decoded, read) = bincode::decode_from_slice::<AltDeque<u8>, _>(&bytes, config).unwrap();
+    assert_eq!(decoded, deque);
+    assert_eq!(read, bytes.len());
+
+    let deque = AltDeque::from((["a", "bc"], ["d"]));
+    let bytes = bincode::encode_to_vec(&deque, config).unwrap();
+    assert_eq!(bytes, bincode::encode_to_vec(vec!["a", "bc", "d"], config).unwrap());
+    let (decoded, _) = bincode::borrow_decode_from_slice::<AltDeque<&str>, _>(&bytes, config).unwrap();
+    assert_eq!(decoded, deque);
+
+    // the length is checked against the limit before allocating
+    let bytes = bincode::encode_to_vec(AltDeque::from([0_u32; 100]), config).unwrap();
+    let limited = config.with_limit::<64>();
+    assert!(bincode::decode_from_slice::<AltDeque<u32>, _>(&bytes, limited).is_err());
+}
@@ -0,0 +1,320 @@
+use std::fmt;
+
+use crate::{AltDeque, Iter};
+
+/// An undo and redo history of actions.
+///
+/// All actions are kept in one [`AltDeque`] in the order they were done, with a cursor between
+/// the actions that can be undone and the ones that can be redone. [`undo`] and [`redo`] only
+/// move the cursor across one action and take *O(1)* time in the worst case. A [`push`] drops
+/// all actions that can be redone from the back of the deque and appends the new one.
+///
+/// A history created with [`with_limit`] keeps at most that many actions that can be undone.
+/// Pushing beyond the limit evicts the oldest action from the front of the deque, which is
+/// amortized *O(1)* like every pop of an [`AltDeque`].
+///
+/// [`undo`]: UndoRedo::undo
+/// [`redo`]: UndoRedo::redo
+/// [`push`]: UndoRedo::push
+/// [`with_limit`]: UndoRedo::with_limit
+///
+/// # Examples
+///
+/// ```
+/// # use altdeque::UndoRedo;
+/// let mut text = String::new();
+/// let mut history = UndoRedo::new();
+/// for word in ["undo", "and", "redo"] {
+///     text.push_str(word);
+///     history.push(word);
+/// }
+///
+/// let word = history.undo().unwrap();
+/// text.truncate(text.len() - word.len());
+/// assert_eq!(text, "undoand");
+///
+/// let word = history.redo().unwrap();
+/// text.push_str(word);
+/// assert_eq!(text, "undoandredo");
+/// assert_eq!(history.redo(), None);
+/// ```
+pub struct UndoRedo<T> {
+    deque: AltDeque<T>,
+    // the number of actions that can be undone, they are at [0, cursor) in the deque
+    cursor: usize,
+    limit: Option<usize>,
+}
+
+impl<T> UndoRedo<T> {
+    /// Creates an empty history without a limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::UndoRedo;
+    /// let history: UndoRedo<i32> = UndoRedo::new();
+    /// assert_eq!(history.limit(), None);
+    /// ```
+    pub fn new() -> Self {
+        Self { deque: AltDeque::new(), cursor: 0, limit: None }
+    }
+
+    /// Creates an empty history that keeps at most `limit` actions that can be undone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::UndoRedo;
+    /// let mut history = UndoRedo::with_limit(2);
+    /// assert_eq!(history.push(1), None);
+    /// assert_eq!(history.push(2), None);
+    /// assert_eq!(history.push(3), Some(1));
+    /// assert_eq!(history.undo_len(), 2);
+    /// ```
+    pub fn with_limit(limit: usize) -> Self {
+        Self { deque: AltDeque::new(), cursor: 0, limit: Some(limit) }
+    }
+
+    /// Returns the maximum number of actions that can be undone, or `None` if there is no limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::UndoRedo;
+    /// let history: UndoRedo<i32> = UndoRedo::with_limit(10);
+    /// assert_eq!(history.limit(), Some(10));
+    /// ```
+    #[inline]
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Returns the number of actions that can be undone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::UndoRedo;
+    /// let mut history = UndoRedo::new();
+    /// history.push(1);
+    /// history.push(2);
+    /// history.undo();
+    /// assert_eq!(history.undo_len(), 1);
+    /// ```
+    #[inline]
+    pub fn undo_len(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the number of actions that can be redone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::UndoRedo;
+    /// let mut history = UndoRedo::new();
+    /// history.push(1);
+    /// history.push(2);
+    /// history.undo();
+    /// assert_eq!(history.redo_len(), 1);
+    /// ```
+    #[inline]
+    pub fn redo_len(&self) -> usize {
+        self.deque.len() - self.cursor
+    }
+
+    /// Returns `true` if there are no actions to undo or redo.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::UndoRedo;
+    /// let mut history = UndoRedo::new();
+    /// assert!(history.is_empty());
+    /// history.push(1);
+    /// history.undo();
+    /// assert!(!history.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.deque.is_empty()
+    }
+
+    /// Records a new action, which drops all actions that can be redone.
+    ///
+    /// If the history has a limit and is full, the oldest action is evicted and returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::UndoRedo;
+    /// let mut history = UndoRedo::new();
+    /// history.push(1);
+    /// history.push(2);
+    /// history.undo();
+    /// history.push(3);
+    /// assert_eq!(history.redo_len(), 0);
+    /// assert_eq!(history.undo(), Some(&mut 3));
+    /// assert_eq!(history.undo(), Some(&mut 1));
+    /// ```
+    pub fn push(&mut self, action: T) -> Option<T> {
+        self.deque.truncate(self.cursor);
+        self.deque.push_back(action);
+        self.cursor += 1;
+        match self.limit {
+            Some(limit) if self.cursor > limit => {
+                self.cursor -= 1;
+                self.deque.pop_front()
+            }
+            _ => None,
+        }
+    }
+
+    /// Moves the most recent action that was not undone yet to the redo side and returns it, or
+    /// `None` if there is nothing to undo.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::UndoRedo;
+    /// let mut history = UndoRedo::new();
+    /// history.push(1);
+    /// assert_eq!(history.undo(), Some(&mut 1));
+    /// assert_eq!(history.undo(), None);
+    /// ```
+    pub fn undo(&mut self) -> Option<&mut T> {
+        self.cursor = self.cursor.checked_sub(1)?;
+        self.deque.get_mut(self.cursor)
+    }
+
+    /// Moves the most recently undone action back to the undo side and returns it, or `None` if
+    /// there is nothing to redo.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::UndoRedo;
+    /// let mut history = UndoRedo::new();
+    /// history.push(1);
+    /// history.undo();
+    /// assert_eq!(history.redo(), Some(&mut 1));
+    /// assert_eq!(history.redo(), None);
+    /// ```
+    pub fn redo(&mut self) -> Option<&mut T> {
+        let action = self.deque.get_mut(self.cursor)?;
+        self.cursor += 1;
+        Some(action)
+    }
+
+    /// Returns the action that [`undo`] would return next without moving it.
+    ///
+    /// [`undo`]: UndoRedo::undo
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::UndoRedo;
+    /// let mut history = UndoRedo::new();
+    /// history.push(1);
+    /// assert_eq!(history.peek_undo(), Some(&1));
+    /// assert_eq!(history.peek_redo(), None);
+    /// ```
+    pub fn peek_undo(&self) -> Option<&T> {
+        self.cursor.checked_sub(1).and_then(|index| self.deque.get(index))
+    }
+
+    /// Returns the action that [`redo`] would return next without moving it.
+    ///
+    /// [`redo`]: UndoRedo::redo
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::UndoRedo;
+    /// let mut history = UndoRedo::new();
+    /// history.push(1);
+    /// history.undo();
+    /// assert_eq!(history.peek_redo(), Some(&1));
+    /// assert_eq!(history.peek_undo(), None);
+    /// ```
+    pub fn peek_redo(&self) -> Option<&T> {
+        self.deque.get(self.cursor)
+    }
+
+    /// Returns an iterator over the actions that can be undone, from the oldest to the most
+    /// recent one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::UndoRedo;
+    /// let mut history = UndoRedo::new();
+    /// history.push(1);
+    /// history.push(2);
+    /// history.push(3);
+    /// history.undo();
+    /// assert!(history.undo_iter().eq(&[1, 2]));
+    /// ```
+    pub fn undo_iter(&self) -> Iter<'_, T> {
+        self.deque.range(..self.cursor)
+    }
+
+    /// Returns an iterator over the actions that can be redone, from the next one to the one
+    /// that was undone first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::UndoRedo;
+    /// let mut history = UndoRedo::new();
+    /// history.push(1);
+    /// history.push(2);
+    /// history.push(3);
+    /// history.undo();
+    /// history.undo();
+    /// assert!(history.redo_iter().eq(&[2, 3]));
+    /// ```
+    pub fn redo_iter(&self) -> Iter<'_, T> {
+        self.deque.range(self.cursor..)
+    }
+
+    /// Removes all actions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use altdeque::UndoRedo;
+    /// let mut history = UndoRedo::new();
+    /// history.push(1);
+    /// history.clear();
+    /// assert!(history.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.deque.clear();
+        self.cursor = 0;
+    }
+}
+
+impl<T: Clone> Clone for UndoRedo<T> {
+    fn clone(&self) -> Self {
+        Self { deque: self.deque.clone(), cursor: self.cursor, limit: self.limit }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for UndoRedo<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UndoRedo")
+            .field("undo", &self.deque.range(..self.cursor))
+            .field("redo", &self.deque.range(self.cursor..))
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl<T> Default for UndoRedo<T> {
+    /// Creates an empty history without a limit.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
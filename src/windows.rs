@@ -0,0 +1,130 @@
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+#[cfg(feature = "nightly")]
+use std::iter::TrustedLen;
+use std::ops::Range;
+
+use crate::chunks::to_array;
+
+/// An iterator over all contiguous windows of `size` elements of an `AltDeque`, starting at
+/// the front.
+///
+/// Every window is returned as a pair of slices, like [`as_slices`] returns the whole deque.
+/// Only the windows that span the boundary between the two internal stacks have two non-empty
+/// parts, all other windows are in the first slice and the second one is empty. If the deque
+/// is shorter than `size`, there are no windows.
+///
+/// This struct is created by the [`windows`] method on [`AltDeque`].
+///
+/// [`AltDeque`]: crate::AltDeque
+/// [`as_slices`]: crate::AltDeque::as_slices
+/// [`windows`]: crate::AltDeque::windows
+#[derive(Clone, Debug)]
+pub struct Windows<'a, T> {
+    front: &'a [T],
+    back: &'a [T],
+    size: usize,
+    // The start indices of the windows that are not yielded yet.
+    starts: Range<usize>,
+}
+
+impl<'a, T> Windows<'a, T> {
+    pub(crate) fn new(front: &'a [T], back: &'a [T], size: usize) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+        let count = (front.len() + back.len() + 1).saturating_sub(size);
+        Self { front, back, size, starts: 0..count }
+    }
+
+    fn window(&self, start: usize) -> (&'a [T], &'a [T]) {
+        let end = start + self.size;
+        let front_len = self.front.len();
+        if end <= front_len {
+            (&self.front[start..end], &[])
+        } else if start >= front_len {
+            (&self.back[start - front_len..end - front_len], &[])
+        } else {
+            (&self.front[start..], &self.back[..end - front_len])
+        }
+    }
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = (&'a [T], &'a [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.starts.next().map(|start| self.window(start))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.starts.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.starts.nth(n).map(|start| self.window(start))
+    }
+}
+
+impl<T> DoubleEndedIterator for Windows<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.starts.next_back().map(|start| self.window(start))
+    }
+}
+
+impl<T> ExactSizeIterator for Windows<'_, T> {}
+
+impl<T> FusedIterator for Windows<'_, T> {}
+
+// SAFETY: size_hint returns the exact number of remaining items
+#[cfg(feature = "nightly")]
+unsafe impl<T> TrustedLen for Windows<'_, T> {}
+
+/// An iterator over all contiguous windows of `N` elements of an `AltDeque` as arrays,
+/// starting at the front.
+///
+/// Windows that lie in one of the two internal stacks are borrowed, the `N - 1` windows that
+/// span the boundary between them are copied into arrays.
+///
+/// This struct is created by the [`array_windows`] method on [`AltDeque`].
+///
+/// [`AltDeque`]: crate::AltDeque
+/// [`array_windows`]: crate::AltDeque::array_windows
+#[derive(Clone, Debug)]
+pub struct ArrayWindows<'a, T, const N: usize> {
+    inner: Windows<'a, T>,
+}
+
+impl<'a, T, const N: usize> ArrayWindows<'a, T, N> {
+    pub(crate) fn new(front: &'a [T], back: &'a [T]) -> Self {
+        Self { inner: Windows::new(front, back, N) }
+    }
+}
+
+impl<'a, T: Copy, const N: usize> Iterator for ArrayWindows<'a, T, N> {
+    type Item = Cow<'a, [T; N]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(to_array)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n).map(to_array)
+    }
+}
+
+impl<T: Copy, const N: usize> DoubleEndedIterator for ArrayWindows<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(to_array)
+    }
+}
+
+impl<T: Copy, const N: usize> ExactSizeIterator for ArrayWindows<'_, T, N> {}
+
+impl<T: Copy, const N: usize> FusedIterator for ArrayWindows<'_, T, N> {}
+
+// SAFETY: size_hint returns the exact number of remaining items
+#[cfg(feature = "nightly")]
+unsafe impl<T: Copy, const N: usize> TrustedLen for ArrayWindows<'_, T, N> {}